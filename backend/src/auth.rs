@@ -0,0 +1,161 @@
+use crate::error::{WatchError, WatchResult};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+// =========================================================
+// Admin 鉴权
+// =========================================================
+
+/// 一条 Admin 密钥及其标签 (用于日志中标识调用方)
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AdminKey {
+    pub label: String,
+    pub secret: String,
+}
+
+/// 解析 Admin 密钥配置
+///
+/// 支持两种格式：
+/// - JSON 对象 `{"ci": "secret-a", "alice": "secret-b"}`，键为标签
+/// - 逗号分隔列表 `secret-a,secret-b`，标签依次为 `key1`、`key2`…
+///
+/// 空白项会被忽略，吊销某个密钥只需删除对应条目
+pub(crate) fn parse_admin_keys(raw: &str) -> Vec<AdminKey> {
+    let raw = raw.trim();
+    if raw.starts_with('{') {
+        return serde_json::from_str::<BTreeMap<String, String>>(raw)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, secret)| !secret.is_empty())
+            .map(|(label, secret)| AdminKey { label, secret })
+            .collect();
+    }
+
+    raw.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .enumerate()
+        .map(|(i, secret)| AdminKey {
+            label: format!("key{}", i + 1),
+            secret: secret.to_string(),
+        })
+        .collect()
+}
+
+/// 常量时间比较两个字符串
+///
+/// 先分别计算 SHA-256 摘要再逐字节比较，比较长度固定为 32 字节，
+/// 不会因输入长度不同（包括缺失请求头时的空字符串）而提前返回
+pub(crate) fn constant_time_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (Sha256::digest(a.as_bytes()), Sha256::digest(b.as_bytes()));
+    a.iter()
+        .zip(b.iter())
+        .fold(0, |acc, (&x, &y)| acc | (x ^ y))
+        == 0
+}
+
+/// 校验请求头中的密钥，成功时返回匹配条目的标签
+///
+/// 始终与全部条目逐一比较，不因提前命中而缩短耗时；
+/// 未配置任何密钥时一律拒绝，并与密钥错误区分开以便排查部署问题
+pub(crate) fn verify_admin<'a>(provided: &str, keys: &'a [AdminKey]) -> WatchResult<&'a str> {
+    if keys.is_empty() {
+        return Err(WatchError::unauthorized("Admin secret is not configured").in_op("auth.config"));
+    }
+
+    let mut matched = None;
+    for key in keys {
+        if constant_time_eq(provided, &key.secret) && matched.is_none() {
+            matched = Some(key.label.as_str());
+        }
+    }
+    matched.ok_or_else(|| WatchError::unauthorized("Invalid Secret").in_op("auth.verify"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::WatchErrorStatus;
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq("secret", "secret"));
+        assert!(!constant_time_eq("secret", "secreT"));
+        assert!(!constant_time_eq("", "secret"));
+        assert!(constant_time_eq("", ""));
+    }
+
+    #[test]
+    fn test_parse_single_secret() {
+        let keys = parse_admin_keys("my_super_secure_password");
+        assert_eq!(
+            keys,
+            vec![AdminKey {
+                label: "key1".into(),
+                secret: "my_super_secure_password".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_comma_separated_secrets() {
+        let keys = parse_admin_keys(" a , b,,c ");
+        let labels: Vec<_> = keys.iter().map(|k| k.label.as_str()).collect();
+        let secrets: Vec<_> = keys.iter().map(|k| k.secret.as_str()).collect();
+        assert_eq!(labels, ["key1", "key2", "key3"]);
+        assert_eq!(secrets, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_parse_json_map_secrets() {
+        let keys = parse_admin_keys(r#"{"ci": "secret-a", "alice": "secret-b", "empty": ""}"#);
+        assert_eq!(keys.len(), 2);
+        assert_eq!(verify_admin("secret-a", &keys).unwrap(), "ci");
+        assert_eq!(verify_admin("secret-b", &keys).unwrap(), "alice");
+    }
+
+    #[test]
+    fn test_verify_admin_equal() {
+        let keys = parse_admin_keys("my_super_secure_password");
+        assert_eq!(
+            verify_admin("my_super_secure_password", &keys).unwrap(),
+            "key1"
+        );
+    }
+
+    #[test]
+    fn test_verify_admin_unequal() {
+        let keys = parse_admin_keys("my_super_secure_password");
+        let err = verify_admin("wrong", &keys).unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::Unauthorized);
+        assert!(err.to_string().contains("Invalid Secret"));
+
+        // 缺失请求头等价于空字符串
+        let err = verify_admin("", &keys).unwrap_err();
+        assert!(err.to_string().contains("Invalid Secret"));
+    }
+
+    #[test]
+    fn test_verify_admin_multiple_keys_and_revoked() {
+        let keys = parse_admin_keys(r#"{"ci": "secret-a", "alice": "secret-b"}"#);
+        assert_eq!(verify_admin("secret-a", &keys).unwrap(), "ci");
+        assert_eq!(verify_admin("secret-b", &keys).unwrap(), "alice");
+
+        // 吊销 alice：从配置中移除对应条目
+        let keys = parse_admin_keys(r#"{"ci": "secret-a"}"#);
+        assert_eq!(verify_admin("secret-a", &keys).unwrap(), "ci");
+        let err = verify_admin("secret-b", &keys).unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::Unauthorized);
+    }
+
+    #[test]
+    fn test_verify_admin_empty_secret_is_rejected() {
+        let keys = parse_admin_keys("");
+        let err = verify_admin("", &keys).unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::Unauthorized);
+        assert!(err.to_string().contains("not configured"));
+
+        let err = verify_admin("anything", &parse_admin_keys(" , ")).unwrap_err();
+        assert!(err.to_string().contains("not configured"));
+    }
+}
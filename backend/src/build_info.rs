@@ -0,0 +1,51 @@
+use verwatch_shared::protocol::BuildInfo;
+
+// =========================================================
+// 构建信息
+// =========================================================
+
+/// 构建时注入 Git Commit 的环境变量 (例如 `git rev-parse --short HEAD` 的结果)
+const GIT_SHA_VAR: Option<&str> = option_env!("VERWATCH_GIT_SHA");
+/// 构建时注入构建时间的环境变量 (例如 `date -u +%Y-%m-%dT%H:%M:%SZ` 的结果)
+const BUILT_AT_VAR: Option<&str> = option_env!("VERWATCH_BUILT_AT");
+
+/// 当前 Worker 的构建信息，全部在编译期确定
+///
+/// 未注入 (或注入空值) 的字段为 `None`
+pub(crate) fn build_info() -> BuildInfo {
+    BuildInfo {
+        name: env!("CARGO_PKG_NAME").to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        git_sha: non_empty(GIT_SHA_VAR),
+        built_at: non_empty(BUILT_AT_VAR),
+    }
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_info_reports_package_version() {
+        let info = build_info();
+        assert_eq!(info.name, "verwatch-backend");
+        assert_eq!(info.version, "0.1.0");
+
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["version"], "0.1.0");
+    }
+
+    #[test]
+    fn test_blank_injected_values_are_omitted() {
+        assert_eq!(non_empty(None), None);
+        assert_eq!(non_empty(Some("  ")), None);
+        assert_eq!(non_empty(Some("abc1234\n")), Some("abc1234".to_string()));
+    }
+}
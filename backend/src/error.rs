@@ -25,6 +25,10 @@ pub enum WatchErrorStatus {
     ExternalApi,
     /// 409: 资源冲突 (如尝试创建已存在的 ID)
     Conflict,
+    /// 429: 请求过于频繁 (触发限流)
+    RateLimited,
+    /// 413: 请求体超过大小限制
+    PayloadTooLarge,
 }
 
 impl WatchErrorStatus {
@@ -34,6 +38,8 @@ impl WatchErrorStatus {
             WatchErrorStatus::Unauthorized => 401,
             WatchErrorStatus::NotFound => 404,
             WatchErrorStatus::Conflict => 409,
+            WatchErrorStatus::PayloadTooLarge => 413,
+            WatchErrorStatus::RateLimited => 429,
             WatchErrorStatus::Store => 500,
             WatchErrorStatus::ExternalApi => 502,
         }
@@ -46,6 +52,8 @@ impl WatchErrorStatus {
             WatchErrorStatus::Unauthorized => "UNAUTHORIZED",
             WatchErrorStatus::NotFound => "RESOURCE_NOT_FOUND",
             WatchErrorStatus::Conflict => "RESOURCE_CONFLICT",
+            WatchErrorStatus::RateLimited => "RATE_LIMITED",
+            WatchErrorStatus::PayloadTooLarge => "PAYLOAD_TOO_LARGE",
             WatchErrorStatus::Store => "INTERNAL_STORE_ERROR",
             WatchErrorStatus::ExternalApi => "UPSTREAM_ERROR",
         }
@@ -144,6 +152,14 @@ impl WatchError {
         Self::new(WatchErrorStatus::Conflict, message)
     }
 
+    pub fn rate_limited(message: impl Into<String>) -> Self {
+        Self::new(WatchErrorStatus::RateLimited, message)
+    }
+
+    pub fn payload_too_large(message: impl Into<String>) -> Self {
+        Self::new(WatchErrorStatus::PayloadTooLarge, message)
+    }
+
     // --- Context builders (Builder Pattern) ---
 
     /// 添加操作追踪（无额外细节）
@@ -158,6 +174,24 @@ impl WatchError {
         self
     }
 
+    /// 从消息与追踪细节中抹去敏感值 (如写在 URL 里的 Token)
+    ///
+    /// 原始错误源可能同样包含该值且无法改写，因此一并丢弃
+    pub fn redact(mut self, secret: &str) -> Self {
+        const REDACTED: &str = "<redacted>";
+        if secret.is_empty() {
+            return self;
+        }
+        self.message = self.message.replace(secret, REDACTED);
+        for span in &mut self.spans {
+            if let Some(detail) = &mut span.detail {
+                *detail = detail.replace(secret, REDACTED);
+            }
+        }
+        self.source = None;
+        self
+    }
+
     /// 设置原始错误源
     pub fn with_source<E: std::error::Error + Send + Sync + 'static>(mut self, source: E) -> Self {
         self.source = Some(Box::new(source));
@@ -185,6 +219,16 @@ impl WatchError {
     pub fn spans(&self) -> &[ErrorSpan] {
         &self.spans
     }
+
+    /// 详细格式：在 `Display` 之后沿 `source` 链逐层追加 `caused by: ...`
+    ///
+    /// 仅用于服务端日志，返回给客户端的内容仍使用 `Display`
+    pub fn display_chain(&self) -> String {
+        match &self.source {
+            Some(source) => format!("{} | caused by: {}", self, source_chain(source.as_ref())),
+            None => self.to_string(),
+        }
+    }
 }
 
 // =========================================================
@@ -222,6 +266,17 @@ impl std::error::Error for WatchError {
 
 pub type WatchResult<T> = std::result::Result<T, WatchError>;
 
+/// 将错误及其 source 链格式化为 `a | caused by: b | caused by: c`
+fn source_chain(e: &(dyn std::error::Error + 'static)) -> String {
+    let mut out = e.to_string();
+    let mut next = e.source();
+    while let Some(inner) = next {
+        out.push_str(&format!(" | caused by: {}", inner));
+        next = inner.source();
+    }
+    out
+}
+
 // =========================================================
 // 传输用错误类型
 // =========================================================
@@ -246,6 +301,9 @@ pub struct ErrorResponse {
     /// 结构化的操作追踪栈
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub spans: Vec<ErrorSpan>,
+    /// 原始错误源的文本 (多层 source 以 ` | caused by: ` 连接)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_message: Option<String>,
 }
 
 impl ErrorResponse {
@@ -254,6 +312,7 @@ impl ErrorResponse {
             status,
             message: message.into(),
             spans: Vec::new(),
+            source_message: None,
         }
     }
 
@@ -272,12 +331,26 @@ impl ErrorResponse {
 // 类型转换实现
 // =========================================================
 
+/// 跨 RPC 边界恢复的错误源，仅保留原始错误的文本
+#[derive(Debug)]
+struct RemoteSource(String);
+
+impl fmt::Display for RemoteSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for RemoteSource {}
+
 impl From<WatchError> for ErrorResponse {
     fn from(e: WatchError) -> Self {
+        let source_message = e.source.as_deref().map(|source| source_chain(source));
         Self {
             status: e.status,
             message: e.message,
             spans: e.spans,
+            source_message,
         }
     }
 }
@@ -287,7 +360,10 @@ impl From<ErrorResponse> for WatchError {
         Self {
             status: e.status,
             message: e.message,
-            source: None, // source 不可序列化，跨边界传输时丢失
+            // source 本身不可序列化，仅以文本形式跨边界传输
+            source: e
+                .source_message
+                .map(|m| Box::new(RemoteSource(m)) as Box<dyn std::error::Error + Send + Sync>),
             spans: e.spans,
         }
     }
@@ -317,3 +393,121 @@ impl From<JsValue> for WatchError {
         WatchError::store(msg)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_code_and_error_code_mapping() {
+        let cases = [
+            (WatchErrorStatus::InvalidInput, 400, "INVALID_INPUT"),
+            (WatchErrorStatus::Serialization, 400, "JSON_PARSE_ERROR"),
+            (WatchErrorStatus::Unauthorized, 401, "UNAUTHORIZED"),
+            (WatchErrorStatus::NotFound, 404, "RESOURCE_NOT_FOUND"),
+            (WatchErrorStatus::Conflict, 409, "RESOURCE_CONFLICT"),
+            (WatchErrorStatus::RateLimited, 429, "RATE_LIMITED"),
+            (WatchErrorStatus::PayloadTooLarge, 413, "PAYLOAD_TOO_LARGE"),
+            (WatchErrorStatus::Store, 500, "INTERNAL_STORE_ERROR"),
+            (WatchErrorStatus::ExternalApi, 502, "UPSTREAM_ERROR"),
+        ];
+        for (status, code, name) in cases {
+            assert_eq!(status.status_code(), code);
+            assert_eq!(status.error_code(), name);
+        }
+    }
+
+    #[test]
+    fn test_redact_removes_secret_from_message_and_spans() {
+        let err = WatchError::external_api("failed: https://host/botSECRET/x")
+            .in_op_with("http.fetch", "https://host/botSECRET/x")
+            .in_op("outer")
+            .redact("SECRET");
+
+        assert_eq!(err.message(), "failed: https://host/bot<redacted>/x");
+        assert_eq!(
+            err.spans()[0].detail.as_deref(),
+            Some("https://host/bot<redacted>/x")
+        );
+        assert!(!err.to_string().contains("SECRET"));
+    }
+
+    #[derive(Debug)]
+    struct Layer(&'static str, Option<Box<Layer>>);
+
+    impl fmt::Display for Layer {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    impl std::error::Error for Layer {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            self.1.as_deref().map(|e| e as _)
+        }
+    }
+
+    #[test]
+    fn test_display_chain_walks_source() {
+        let root = Layer("connection reset", None);
+        let err = WatchError::store("storage.get failed")
+            .with_source(Layer("io error", Some(Box::new(root))))
+            .in_op("storage.get");
+
+        // Display 保持不变
+        assert_eq!(
+            err.to_string(),
+            "[INTERNAL_STORE_ERROR] storage.get failed | trace: storage.get"
+        );
+        assert_eq!(
+            err.display_chain(),
+            "[INTERNAL_STORE_ERROR] storage.get failed | trace: storage.get \
+             | caused by: io error | caused by: connection reset"
+        );
+    }
+
+    #[test]
+    fn test_error_response_round_trip_preserves_source() {
+        let err = WatchError::store("put failed")
+            .with_source(Layer(
+                "io error",
+                Some(Box::new(Layer("connection reset", None))),
+            ))
+            .in_op("storage.put");
+        let expected = err.display_chain();
+
+        let json = serde_json::to_string(&ErrorResponse::from(err)).unwrap();
+        let resp: ErrorResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            resp.source_message.as_deref(),
+            Some("io error | caused by: connection reset")
+        );
+
+        let restored = WatchError::from(resp);
+        assert_eq!(restored.status, WatchErrorStatus::Store);
+        assert_eq!(restored.display_chain(), expected);
+    }
+
+    #[test]
+    fn test_error_response_without_source_message_is_compatible() {
+        let resp: ErrorResponse =
+            serde_json::from_str(r#"{"status":"NotFound","message":"missing"}"#).unwrap();
+        assert!(resp.source_message.is_none());
+        let err = WatchError::from(resp);
+        assert!(std::error::Error::source(&err).is_none());
+    }
+
+    #[test]
+    fn test_display_chain_without_source_equals_display() {
+        let err = WatchError::not_found("missing");
+        assert_eq!(err.display_chain(), err.to_string());
+    }
+
+    #[test]
+    fn test_rate_limited_is_client_error() {
+        let err = WatchError::rate_limited("slow down");
+        assert_eq!(err.status_code(), 429);
+        assert!(err.status_code() < 500);
+        assert_eq!(err.to_string(), "[RATE_LIMITED] slow down");
+    }
+}
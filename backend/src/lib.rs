@@ -1,23 +1,44 @@
 use worker::*;
 
+mod auth;
+mod build_info;
 pub mod error;
 pub mod logic;
+mod metrics;
+mod openapi;
 mod project;
 mod repository;
 
 pub(crate) mod utils {
+    pub mod budget;
+    pub mod compress;
     pub mod concurrent;
+    pub mod gitea;
     pub mod github;
+    pub mod gitlab;
+    pub mod logging;
+    pub mod notify;
+    pub mod provider;
+    pub mod rate_limit;
     pub mod request;
     pub mod rpc;
 }
 
-use error::WatchError;
+use auth::{AdminKey, constant_time_eq, parse_admin_keys, verify_admin};
+use error::{ErrorResponse, RPC_ERROR_HEADER, WatchError};
 use logic::AdminLogic;
 use repository::DoProjectRegistry;
+use utils::compress::{self, DEFAULT_COMPRESS_THRESHOLD};
+use utils::logging::{self, LogLevel};
+use utils::rate_limit::RateLimit;
 use verwatch_shared::{
-    CreateProjectRequest, DeleteTarget, HEADER_AUTH_KEY,
-    protocol::{PopProjectRequest, SwitchMonitorRequest, TriggerCheckRequest},
+    CreateProjectRequest, Date, DeleteTarget, DurationSecs, HEADER_AUTH_KEY, ProjectFilter,
+    fnv1a_64,
+    protocol::{
+        ConfigBackup, DeleteAllRequest, ForceDispatchRequest, HealthStatus, PopProjectRequest,
+        RescheduleRequest, Role, SessionInfo, SetEnabledRequest, SwitchAllRequest,
+        SwitchMonitorRequest, TriggerCheckRequest, UpdateProjectRequest,
+    },
 };
 
 // =========================================================
@@ -25,6 +46,21 @@ use verwatch_shared::{
 // =========================================================
 const DEFAULT_REGISTRY_BINDING: &str = "PROJECT_REGISTRY";
 const DEFAULT_SECRET_VAR_NAME: &str = "ADMIN_SECRET";
+const DEFAULT_FEED_SECRET_VAR_NAME: &str = "FEED_SECRET";
+const DEFAULT_VIEWER_SECRET_VAR_NAME: &str = "VIEWER_SECRET";
+/// 使用 Feed 只读令牌访问时，日志与限流中使用的标签
+const FEED_KEY_LABEL: &str = "feed";
+/// 使用 Viewer 只读密钥访问时，日志与限流中使用的标签
+const VIEWER_KEY_LABEL: &str = "viewer";
+/// 列表接口中报告读取失败项目的响应头 (逗号分隔的 unique_key)
+const HEADER_FAILED_KEYS: &str = "X-Failed-Keys";
+/// 分页查询的默认与最大每页数量
+const DEFAULT_PAGE_LIMIT: usize = 50;
+const MAX_PAGE_LIMIT: usize = 100;
+/// 创建接口请求体的默认大小上限 (字节)
+const DEFAULT_MAX_BODY_BYTES: usize = 64 * 1024;
+/// 限流开启时默认每分钟补充的令牌数
+const DEFAULT_RATE_LIMIT_PER_MINUTE: u32 = 60;
 
 // =========================================================
 // 宏定义 (包含日志和响应处理)
@@ -35,19 +71,49 @@ macro_rules! log_error { ($($t:tt)*) => (worker::console_error!($($t)*)) }
 #[cfg(not(target_arch = "wasm32"))]
 macro_rules! log_error { ($($t:tt)*) => (eprintln!($($t)*)) }
 
+// warn 受 `LOG_LEVEL` 控制
+#[cfg(target_arch = "wasm32")]
+macro_rules! log_warn {
+    ($($t:tt)*) => {
+        if logging::enabled(LogLevel::Warn) {
+            worker::console_warn!($($t)*)
+        }
+    };
+}
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! log_warn {
+    ($($t:tt)*) => {
+        if logging::enabled(LogLevel::Warn) {
+            eprintln!($($t)*)
+        }
+    };
+}
+
 // 辅助函数：将 WatchError 映射为 Worker Response
+// Body 为 JSON 格式的 ErrorResponse，与 DO 间 RPC 的错误响应一致，并同样设置 `X-Rpc-Error`
 fn map_error_to_response(e: WatchError) -> worker::Response {
-    let status = e.status_code();
-    let msg = e.to_string();
+    let body = error_response_body(&e);
+    match Response::from_json(&body) {
+        Ok(mut resp) => {
+            let _ = resp.headers_mut().set(RPC_ERROR_HEADER, "true");
+            resp.with_status(body.status_code())
+        }
+        Err(_) => Response::error("Internal Server Error", 500).unwrap(),
+    }
+}
 
-    // 对于 5xx 错误，记录日志以便排查
-    if status >= 500 {
-        log_error!("Internal Error [{}]: {}", e.error_code(), msg);
-        return Response::error("Internal Server Error", status).unwrap();
+/// 计算返回给客户端的结构化错误 (不含原始错误源)
+fn error_response_body(e: &WatchError) -> ErrorResponse {
+    // 对于 5xx 错误，记录日志以便排查，只返回笼统的消息
+    if e.status_code() >= 500 {
+        log_error!("Internal Error [{}]: {}", e.error_code(), e.display_chain());
+        return ErrorResponse::new(e.status, "Internal Server Error");
     }
 
-    // 对于 4xx 错误，直接返回具体错误信息给客户端
-    Response::error(msg, status).unwrap()
+    // 对于 4xx 错误 (包括 413 / 429)，返回具体的错误信息与操作追踪
+    let mut body = ErrorResponse::new(e.status, e.message());
+    body.spans = e.spans().to_vec();
+    body
 }
 
 /// 由于 Worker 需要 `fn(Request, RouteContext<()>) -> Result<Response>`
@@ -70,74 +136,517 @@ macro_rules! console_handler {
 
 struct RuntimeConfig {
     registry_binding: String,
-    admin_secret_name: String,
+    /// 由 Admin Secret 解析出的密钥列表 (支持逗号分隔或 JSON 标签映射)
+    admin_keys: Vec<AdminKey>,
+    /// Prometheus 抓取使用的 Bearer 令牌 (未配置时 /metrics 仅接受 Admin 密钥)
+    metrics_token: Option<String>,
+    /// 只读的 Feed 令牌 (未配置时 Feed 仅接受 Admin 密钥)
+    feed_key: Option<AdminKey>,
+    /// 只读的 Viewer 密钥，可访问列表、详情、历史、统计与 Feed，不能执行变更操作
+    viewer_key: Option<AdminKey>,
+    /// 按鉴权标签限流 (未配置 RATE_LIMIT_BURST 时不限流)
+    rate_limit: Option<RateLimit>,
+    /// 创建接口请求体的大小上限 (字节)
+    max_body_bytes: usize,
+    /// 批量创建/导入时错开首次检查的间隔
+    batch_stagger: DurationSecs,
+    /// 允许跨域访问的 Origin 列表，未配置时为 `None` (允许任意 Origin)
+    cors_origins: Option<Vec<String>>,
+    /// 日志级别 (`LOG_LEVEL`)
+    log_level: LogLevel,
+    /// 新建项目使用哈希后的短 key (`HASHED_KEYS`)
+    hashed_keys: bool,
 }
 
 impl RuntimeConfig {
     fn new(env: &Env) -> Self {
+        let admin_secret_name = env
+            .var("ADMIN_SECRET_NAME")
+            .map(|v| v.to_string())
+            .unwrap_or_else(|_| DEFAULT_SECRET_VAR_NAME.to_string());
+        let admin_secret = env
+            .secret(&admin_secret_name)
+            .map(|s| s.to_string())
+            .unwrap_or_default();
+
         Self {
             registry_binding: env
                 .var("REGISTRY_BINDING")
                 .map(|v| v.to_string())
                 .unwrap_or_else(|_| DEFAULT_REGISTRY_BINDING.to_string()),
-            admin_secret_name: env
-                .var("ADMIN_SECRET_NAME")
-                .map(|v| v.to_string())
-                .unwrap_or_else(|_| DEFAULT_SECRET_VAR_NAME.to_string()),
+            admin_keys: parse_admin_keys(&admin_secret),
+            feed_key: Self::optional_key(
+                env,
+                "FEED_SECRET_NAME",
+                DEFAULT_FEED_SECRET_VAR_NAME,
+                FEED_KEY_LABEL,
+            ),
+            viewer_key: Self::optional_key(
+                env,
+                "VIEWER_SECRET_NAME",
+                DEFAULT_VIEWER_SECRET_VAR_NAME,
+                VIEWER_KEY_LABEL,
+            ),
+            metrics_token: env
+                .secret("METRICS_TOKEN")
+                .map(|s| s.to_string().trim().to_string())
+                .ok()
+                .filter(|s| !s.is_empty()),
+            rate_limit: Self::parse_rate_limit(env),
+            max_body_bytes: env
+                .var("MAX_BODY_BYTES")
+                .ok()
+                .and_then(|v| v.to_string().trim().parse::<usize>().ok())
+                .filter(|&n| n > 0)
+                .unwrap_or(DEFAULT_MAX_BODY_BYTES),
+            batch_stagger: env
+                .var("BATCH_STAGGER_SECS")
+                .ok()
+                .and_then(|v| v.to_string().trim().parse::<u64>().ok())
+                .map(DurationSecs::from_secs)
+                .unwrap_or(logic::DEFAULT_BATCH_STAGGER),
+            cors_origins: env
+                .var("CORS_ORIGINS")
+                .ok()
+                .map(|v| parse_cors_origins(&v.to_string()))
+                .filter(|origins| !origins.is_empty()),
+            log_level: LogLevel::from_var(
+                env.var("LOG_LEVEL").ok().map(|v| v.to_string()).as_deref(),
+            ),
+            hashed_keys: env.var("HASHED_KEYS").is_ok_and(|v| {
+                matches!(
+                    v.to_string().trim().to_ascii_lowercase().as_str(),
+                    "true" | "1" | "yes" | "on"
+                )
+            }),
+        }
+    }
+
+    /// 读取可选的单个密钥：Secret 名称由 `name_var` 变量指定，未设置 Secret 时返回 None
+    fn optional_key(
+        env: &Env,
+        name_var: &str,
+        default_name: &str,
+        label: &str,
+    ) -> Option<AdminKey> {
+        let name = env
+            .var(name_var)
+            .map(|v| v.to_string())
+            .unwrap_or_else(|_| default_name.to_string());
+        env.secret(&name)
+            .map(|s| s.to_string())
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+            .map(|secret| AdminKey {
+                label: label.to_string(),
+                secret: secret.trim().to_string(),
+            })
+    }
+
+    /// 只读接口接受的密钥：Admin 密钥与 Viewer 密钥
+    fn read_keys(&self) -> Vec<AdminKey> {
+        let mut keys = self.admin_keys.clone();
+        keys.extend(self.viewer_key.clone());
+        keys
+    }
+
+    /// 按请求头中的密钥判断调用方角色 (Admin 密钥优先)
+    fn role_for(&self, provided: &str) -> error::WatchResult<Role> {
+        if verify_admin(provided, &self.admin_keys).is_ok() {
+            return Ok(Role::Admin);
         }
+        verify_admin(provided, &self.read_keys()).map(|_| Role::Viewer)
     }
+
+    fn parse_rate_limit(env: &Env) -> Option<RateLimit> {
+        let var = |name: &str| {
+            env.var(name)
+                .ok()
+                .and_then(|v| v.to_string().trim().parse::<u32>().ok())
+        };
+        let capacity = var("RATE_LIMIT_BURST").filter(|&n| n > 0)?;
+        Some(RateLimit {
+            capacity,
+            refill_per_minute: var("RATE_LIMIT_PER_MINUTE")
+                .unwrap_or(DEFAULT_RATE_LIMIT_PER_MINUTE),
+        })
+    }
+}
+
+/// 校验 Admin 鉴权并按密钥标签限流，成功时返回匹配密钥的标签
+async fn ensure_admin_auth(
+    req: &Request,
+    env: &Env,
+    config: &RuntimeConfig,
+) -> error::WatchResult<String> {
+    ensure_auth(req, env, config, &config.admin_keys).await
+}
+
+/// 校验只读鉴权：接受 Admin 密钥或 Viewer 密钥
+async fn ensure_read_auth(
+    req: &Request,
+    env: &Env,
+    config: &RuntimeConfig,
+) -> error::WatchResult<String> {
+    ensure_auth(req, env, config, &config.read_keys()).await
 }
 
-fn constant_time_eq(a: &str, b: &str) -> bool {
-    if a.len() != b.len() {
-        return false;
+/// 校验 Feed 鉴权：接受 Feed 只读令牌、Viewer 密钥或任一 Admin 密钥
+async fn ensure_feed_auth(
+    req: &Request,
+    env: &Env,
+    config: &RuntimeConfig,
+) -> error::WatchResult<String> {
+    let mut keys = config.read_keys();
+    keys.extend(config.feed_key.clone());
+    ensure_auth(req, env, config, &keys).await
+}
+
+/// 校验指标抓取鉴权：`Authorization: Bearer <METRICS_TOKEN>` 或任一 Admin 密钥
+async fn ensure_metrics_auth(
+    req: &Request,
+    env: &Env,
+    config: &RuntimeConfig,
+) -> error::WatchResult<()> {
+    if let Some(token) = &config.metrics_token {
+        let authorization = req
+            .headers()
+            .get("Authorization")
+            .map_err(|e| WatchError::invalid_input(e.to_string()).in_op("auth.header"))?;
+        if bearer_token(authorization.as_deref()).is_some_and(|t| constant_time_eq(t, token)) {
+            return Ok(());
+        }
     }
-    a.as_bytes()
-        .iter()
-        .zip(b.as_bytes())
-        .fold(0, |acc, (&x, &y)| acc | (x ^ y))
-        == 0
+    ensure_admin_auth(req, env, config).await.map(|_| ())
 }
 
-fn ensure_admin_auth(req: &Request, env: &Env, config: &RuntimeConfig) -> error::WatchResult<()> {
+/// 从 `Authorization` 请求头中取出 Bearer 令牌
+fn bearer_token(authorization: Option<&str>) -> Option<&str> {
+    let (scheme, token) = authorization?.trim().split_once(' ')?;
+    scheme
+        .eq_ignore_ascii_case("bearer")
+        .then(|| token.trim())
+        .filter(|t| !t.is_empty())
+}
+
+/// 按给定的密钥列表校验请求头并限流，成功时返回匹配密钥的标签
+async fn ensure_auth(
+    req: &Request,
+    env: &Env,
+    config: &RuntimeConfig,
+    keys: &[AdminKey],
+) -> error::WatchResult<String> {
     let auth_header = req
         .headers()
         .get(HEADER_AUTH_KEY)
         .map_err(|e| WatchError::invalid_input(e.to_string()).in_op("auth.header"))?
         .unwrap_or_default();
-    let secret = env
-        .secret(&config.admin_secret_name)
-        .map(|s| s.to_string())
-        .unwrap_or_default();
 
-    if secret.is_empty() || !constant_time_eq(&auth_header, &secret) {
-        return Err(WatchError::unauthorized("Invalid Secret").in_op("auth.verify"));
+    let label = verify_admin(&auth_header, keys)?.to_string();
+
+    if let Some(limit) = &config.rate_limit {
+        let registry = DoProjectRegistry::new(env, &config.registry_binding)
+            .map_err(|e| WatchError::store(e.to_string()))?;
+        AdminLogic::new(&registry)
+            .check_rate_limit(&label, limit)
+            .await?;
+    }
+    Ok(label)
+}
+
+/// 列表响应的弱 ETag，取序列化后 Body 的 FNV-1a 哈希
+fn weak_etag(body: &str) -> String {
+    format!("W/\"{:016x}\"", fnv1a_64(body.as_bytes()))
+}
+
+/// 构建 JSON 响应：客户端支持且响应体达到阈值时按 `Accept-Encoding` 压缩
+///
+/// 已压缩的 Body 需要关闭运行时的自动编码，避免被重复压缩
+fn json_response(req: &Request, body: String) -> error::WatchResult<Response> {
+    let accept_encoding = req
+        .headers()
+        .get("Accept-Encoding")
+        .map_err(|e| WatchError::from(e).in_op("response.header"))?;
+    let encoding = compress::should_compress(
+        accept_encoding.as_deref(),
+        body.len(),
+        DEFAULT_COMPRESS_THRESHOLD,
+    );
+    let mut resp = match encoding {
+        Some(encoding) => {
+            let compressed = compress::compress(body.as_bytes(), encoding)?;
+            let mut resp = Response::from_bytes(compressed)
+                .map_err(|e| WatchError::from(e).in_op("response.body"))?
+                .with_encode_body(EncodeBody::Manual);
+            resp.headers_mut()
+                .set("Content-Encoding", encoding.as_str())
+                .map_err(|e| WatchError::from(e).in_op("response.header"))?;
+            resp
+        }
+        None => Response::ok(body).map_err(|e| WatchError::from(e).in_op("response.body"))?,
+    };
+    let headers = resp.headers_mut();
+    headers
+        .set("Content-Type", "application/json")
+        .and_then(|_| headers.append("Vary", "Accept-Encoding"))
+        .map_err(|e| WatchError::from(e).in_op("response.header"))?;
+    Ok(resp)
+}
+
+/// 判断 `If-None-Match` 是否命中当前 ETag (按弱比较，支持逗号分隔的列表与 `*`)
+fn not_modified(if_none_match: Option<&str>, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let etag = opaque(etag);
+    if_none_match.is_some_and(|header| {
+        header
+            .split(',')
+            .any(|tag| tag.trim() == "*" || opaque(tag) == etag)
+    })
+}
+
+/// 读取 URL 查询参数 (不存在时返回 None)
+fn query_param(req: &Request, name: &str) -> error::WatchResult<Option<String>> {
+    let url = req
+        .url()
+        .map_err(|e| WatchError::invalid_input(e.to_string()).in_op("request.url"))?;
+    Ok(url
+        .query_pairs()
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| v.into_owned()))
+}
+
+/// 读取并解析 JSON 请求体，超过 `limit` 字节时在解析前返回 413
+async fn read_json_limited<T: serde::de::DeserializeOwned>(
+    req: &mut Request,
+    limit: usize,
+) -> error::WatchResult<T> {
+    let text = req
+        .text()
+        .await
+        .map_err(|e| WatchError::invalid_input(e.to_string()).in_op("request.body"))?;
+    ensure_body_size(text.len(), limit)?;
+    serde_json::from_str(&text)
+        .map_err(|e| WatchError::serialization(format!("Invalid JSON Body: {}", e)))
+}
+
+fn ensure_body_size(len: usize, limit: usize) -> error::WatchResult<()> {
+    if len > limit {
+        return Err(WatchError::payload_too_large(format!(
+            "Request body is {} bytes, limit is {} bytes",
+            len, limit
+        ))
+        .in_op("request.body"));
     }
     Ok(())
 }
 
+/// 解析逗号分隔的 Origin 列表，忽略空项与末尾的 `/`
+fn parse_cors_origins(raw: &str) -> Vec<String> {
+    raw.split(',')
+        .map(|o| o.trim().trim_end_matches('/'))
+        .filter(|o| !o.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// 计算响应中的 `Access-Control-Allow-Origin`
+/// 未配置白名单时为 `*`；否则仅在请求 Origin 命中白名单时回显该 Origin，未命中返回 `None`
+fn allowed_origin(allowlist: Option<&[String]>, origin: Option<&str>) -> Option<String> {
+    let Some(allowlist) = allowlist else {
+        return Some("*".to_string());
+    };
+    if allowlist.iter().any(|o| o == "*") {
+        return Some("*".to_string());
+    }
+    let origin = origin?;
+    allowlist
+        .iter()
+        .any(|o| o.eq_ignore_ascii_case(origin))
+        .then(|| origin.to_string())
+}
+
 // =========================================================
 // API Controllers (适配层)
 // =========================================================
 
+/// 健康检查 (无需鉴权)
+/// 子系统失败时仍返回 200，并在 Body 中标明失败的子系统
+///
+/// 携带 `X-Auth-Key` 时会先校验密钥 (Admin 或 Viewer)，错误时返回 401，供前端"测试连接"使用
+async fn health_check(req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    let provided = req
+        .headers()
+        .get(HEADER_AUTH_KEY)
+        .map_err(|e| WatchError::invalid_input(e.to_string()).in_op("auth.header"))?;
+    if provided.is_some_and(|key| !key.is_empty()) {
+        ensure_read_auth(&req, &ctx.env, &cfg).await?;
+    }
+
+    let status = match DoProjectRegistry::new(&ctx.env, &cfg.registry_binding) {
+        Ok(registry) => AdminLogic::new(&registry).health_check().await,
+        Err(e) => {
+            let e = e.in_op("health.registry");
+            log_error!("Health Check Failed [{}]: {}", e.error_code(), e);
+            HealthStatus {
+                ok: false,
+                registry: false,
+                checked_at: Date::now_timestamp(),
+            }
+        }
+    };
+
+    Response::from_json(&status).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+/// 构建信息 (无需鉴权)，用于确认当前部署的版本
+async fn version(_req: Request, _ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    Response::from_json(&build_info::build_info())
+        .map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+/// OpenAPI 文档，与健康检查一样无需鉴权
+async fn openapi_json(_req: Request, _ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    Response::from_json(&openapi::document()).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
 async fn list_projects(req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
     let cfg = RuntimeConfig::new(&ctx.env);
-    ensure_admin_auth(&req, &ctx.env, &cfg)?;
+    ensure_read_auth(&req, &ctx.env, &cfg).await?;
 
     let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
         .map_err(|e| WatchError::store(e.to_string()))?;
 
+    let paused = match query_param(&req, "paused")?.as_deref() {
+        None | Some("") => None,
+        Some("true") => Some(true),
+        Some("false") => Some(false),
+        Some(other) => {
+            return Err(WatchError::invalid_input(format!(
+                "Invalid query parameter 'paused': {}",
+                other
+            ))
+            .in_op("list.query"));
+        }
+    };
+    let filter = ProjectFilter {
+        q: query_param(&req, "q")?,
+        paused,
+    };
+
     let logic = AdminLogic::new(&registry);
-    let result = logic.list_projects().await?;
 
-    Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
+    // 提供 limit 或 cursor 时返回分页结果，否则保持返回完整数组
+    let cursor = query_param(&req, "cursor")?.filter(|c| !c.is_empty());
+    let limit = match query_param(&req, "limit")?.as_deref() {
+        None | Some("") => None,
+        Some(raw) => match raw.parse::<usize>() {
+            Ok(n) if (1..=MAX_PAGE_LIMIT).contains(&n) => Some(n),
+            _ => {
+                return Err(WatchError::invalid_input(format!(
+                    "Invalid query parameter 'limit': {} (expected 1-{})",
+                    raw, MAX_PAGE_LIMIT
+                ))
+                .in_op("list.query"));
+            }
+        },
+    };
+
+    if limit.is_some() || cursor.is_some() {
+        let page = logic
+            .list_projects_paged(
+                &filter,
+                cursor.as_deref(),
+                limit.unwrap_or(DEFAULT_PAGE_LIMIT),
+            )
+            .await?;
+        let body =
+            serde_json::to_string(&page).map_err(|e| WatchError::serialization(e.to_string()))?;
+        let mut resp = json_response(&req, body)?;
+        report_failed_keys(&mut resp, &page.failed_keys)?;
+        return Ok(resp);
+    }
+
+    // 保持返回完整数组，读取失败的项目通过响应头报告
+    let listing = logic.list_projects_lenient(&filter).await?;
+    let body = serde_json::to_string(&listing.projects)
+        .map_err(|e| WatchError::serialization(e.to_string()))?;
+
+    // 列表未变化时返回 304，前端复用上一次的结果
+    let etag = weak_etag(&body);
+    let if_none_match = req
+        .headers()
+        .get("If-None-Match")
+        .map_err(|e| WatchError::from(e).in_op("list.header"))?;
+    let mut resp = if not_modified(if_none_match.as_deref(), &etag) {
+        Response::empty()
+            .map(|r| r.with_status(304))
+            .map_err(|e| WatchError::from(e).in_op("list.response"))?
+    } else {
+        json_response(&req, body)?
+    };
+    resp.headers_mut()
+        .set("ETag", &etag)
+        .map_err(|e| WatchError::from(e).in_op("list.header"))?;
+    report_failed_keys(&mut resp, &listing.failed_keys)?;
+    Ok(resp)
+}
+
+/// 记录读取失败的项目，并通过 `X-Failed-Keys` 响应头报告给调用方
+fn report_failed_keys(resp: &mut Response, failed_keys: &[String]) -> error::WatchResult<()> {
+    if failed_keys.is_empty() {
+        return Ok(());
+    }
+    log_warn!("List skipped unreadable configs: {:?}", failed_keys);
+    resp.headers_mut()
+        .set(HEADER_FAILED_KEYS, &failed_keys.join(","))
+        .map_err(|e| WatchError::from(e).in_op("list.header"))
 }
 
 async fn create_project(mut req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
     let cfg = RuntimeConfig::new(&ctx.env);
-    ensure_admin_auth(&req, &ctx.env, &cfg)?;
+    let actor = ensure_admin_auth(&req, &ctx.env, &cfg).await?;
+
+    let req_data: CreateProjectRequest = read_json_limited(&mut req, cfg.max_body_bytes).await?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
 
-    let req_data: CreateProjectRequest = req
+    let logic = AdminLogic::new(&registry)
+        .with_actor(actor)
+        .with_hashed_keys(cfg.hashed_keys);
+    let result = logic.create_project(req_data).await?;
+
+    Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn create_projects_batch(
+    mut req: Request,
+    ctx: RouteContext<()>,
+) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    let actor = ensure_admin_auth(&req, &ctx.env, &cfg).await?;
+
+    let req_data: Vec<CreateProjectRequest> =
+        read_json_limited(&mut req, cfg.max_body_bytes).await?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry)
+        .with_actor(actor)
+        .with_batch_stagger(cfg.batch_stagger)
+        .with_hashed_keys(cfg.hashed_keys);
+    let result = logic.create_projects_batch(req_data).await;
+
+    Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn update_project(mut req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    let actor = ensure_admin_auth(&req, &ctx.env, &cfg).await?;
+
+    let req_data: UpdateProjectRequest = req
         .json()
         .await
         .map_err(|e| WatchError::serialization(format!("Invalid JSON Body: {}", e)))?;
@@ -145,15 +654,15 @@ async fn create_project(mut req: Request, ctx: RouteContext<()>) -> error::Watch
     let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
         .map_err(|e| WatchError::store(e.to_string()))?;
 
-    let logic = AdminLogic::new(&registry);
-    let result = logic.create_project(req_data).await?;
+    let logic = AdminLogic::new(&registry).with_actor(actor);
+    let result = logic.update_project(req_data).await?;
 
     Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
 }
 
 async fn delete_project(mut req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
     let cfg = RuntimeConfig::new(&ctx.env);
-    ensure_admin_auth(&req, &ctx.env, &cfg)?;
+    let actor = ensure_admin_auth(&req, &ctx.env, &cfg).await?;
 
     let target: DeleteTarget = req
         .json()
@@ -163,7 +672,7 @@ async fn delete_project(mut req: Request, ctx: RouteContext<()>) -> error::Watch
     let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
         .map_err(|e| WatchError::store(e.to_string()))?;
 
-    let logic = AdminLogic::new(&registry);
+    let logic = AdminLogic::new(&registry).with_actor(actor);
     let result = logic.delete_project(target).await?;
 
     match result {
@@ -176,7 +685,7 @@ async fn delete_project(mut req: Request, ctx: RouteContext<()>) -> error::Watch
 
 async fn pop_project(mut req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
     let cfg = RuntimeConfig::new(&ctx.env);
-    ensure_admin_auth(&req, &ctx.env, &cfg)?;
+    let actor = ensure_admin_auth(&req, &ctx.env, &cfg).await?;
 
     let req_data: PopProjectRequest = req
         .json()
@@ -187,7 +696,7 @@ async fn pop_project(mut req: Request, ctx: RouteContext<()>) -> error::WatchRes
     let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
         .map_err(|e| WatchError::store(e.to_string()))?;
 
-    let logic = AdminLogic::new(&registry);
+    let logic = AdminLogic::new(&registry).with_actor(actor);
     let result = logic.pop_project(target).await?;
 
     Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
@@ -195,7 +704,7 @@ async fn pop_project(mut req: Request, ctx: RouteContext<()>) -> error::WatchRes
 
 async fn switch_monitor(mut req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
     let cfg = RuntimeConfig::new(&ctx.env);
-    ensure_admin_auth(&req, &ctx.env, &cfg)?;
+    let actor = ensure_admin_auth(&req, &ctx.env, &cfg).await?;
 
     let cmd: SwitchMonitorRequest = req
         .json()
@@ -205,15 +714,105 @@ async fn switch_monitor(mut req: Request, ctx: RouteContext<()>) -> error::Watch
     let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
         .map_err(|e| WatchError::store(e.to_string()))?;
 
-    let logic = AdminLogic::new(&registry);
+    let logic = AdminLogic::new(&registry).with_actor(actor);
     let result = logic.switch_monitor(cmd.unique_key, cmd.paused).await?;
 
     Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
 }
 
+async fn delete_all(mut req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    let actor = ensure_admin_auth(&req, &ctx.env, &cfg).await?;
+
+    let cmd: DeleteAllRequest = req
+        .json()
+        .await
+        .map_err(|e| WatchError::serialization(format!("Invalid JSON Body: {}", e)))?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry).with_actor(actor);
+    let result = logic.delete_all(&cmd.confirm).await?;
+
+    Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn switch_all(mut req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    let actor = ensure_admin_auth(&req, &ctx.env, &cfg).await?;
+
+    let cmd: SwitchAllRequest = req
+        .json()
+        .await
+        .map_err(|e| WatchError::serialization(format!("Invalid JSON Body: {}", e)))?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry).with_actor(actor);
+    let result = logic.switch_all(cmd.paused).await?;
+
+    Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn set_enabled(mut req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    let actor = ensure_admin_auth(&req, &ctx.env, &cfg).await?;
+
+    let cmd: SetEnabledRequest = req
+        .json()
+        .await
+        .map_err(|e| WatchError::serialization(format!("Invalid JSON Body: {}", e)))?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry).with_actor(actor);
+    let result = logic.set_enabled(cmd.unique_key, cmd.enabled).await?;
+
+    Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn get_history(req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    ensure_read_auth(&req, &ctx.env, &cfg).await?;
+
+    let unique_key = query_param(&req, "unique_key")?.ok_or_else(|| {
+        WatchError::invalid_input("Missing query parameter 'unique_key'").in_op("history.query")
+    })?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry);
+    let result = logic.get_history(unique_key).await?;
+
+    Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn get_project_one(req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    ensure_read_auth(&req, &ctx.env, &cfg).await?;
+
+    let id = query_param(&req, "id")?
+        .filter(|id| !id.is_empty())
+        .ok_or_else(|| {
+            WatchError::invalid_input("Missing query parameter 'id'").in_op("get.query")
+        })?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry);
+    let result = logic.get_project(id).await?;
+
+    Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
 async fn trigger_check(mut req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
     let cfg = RuntimeConfig::new(&ctx.env);
-    ensure_admin_auth(&req, &ctx.env, &cfg)?;
+    let actor = ensure_admin_auth(&req, &ctx.env, &cfg).await?;
 
     let cmd: TriggerCheckRequest = req
         .json()
@@ -223,12 +822,201 @@ async fn trigger_check(mut req: Request, ctx: RouteContext<()>) -> error::WatchR
     let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
         .map_err(|e| WatchError::store(e.to_string()))?;
 
-    let logic = AdminLogic::new(&registry);
+    let logic = AdminLogic::new(&registry).with_actor(actor);
     let result = logic.trigger_check(cmd.unique_key).await?;
 
     Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
 }
 
+async fn force_dispatch(mut req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    let actor = ensure_admin_auth(&req, &ctx.env, &cfg).await?;
+
+    let cmd: ForceDispatchRequest = req
+        .json()
+        .await
+        .map_err(|e| WatchError::serialization(format!("Invalid JSON Body: {}", e)))?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry).with_actor(actor);
+    let result = logic.force_dispatch(cmd.unique_key).await?;
+
+    Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn list_projects_detailed(
+    req: Request,
+    ctx: RouteContext<()>,
+) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    ensure_read_auth(&req, &ctx.env, &cfg).await?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry);
+    let result = logic.list_projects_detailed().await?;
+
+    Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn get_feed(req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    ensure_feed_auth(&req, &ctx.env, &cfg).await?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry);
+    let result = logic.feed().await?;
+
+    let body =
+        serde_json::to_string(&result).map_err(|e| WatchError::serialization(e.to_string()))?;
+    json_response(&req, body)
+}
+
+async fn export_projects(req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    ensure_admin_auth(&req, &ctx.env, &cfg).await?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry);
+    let result = logic.export_projects().await?;
+
+    Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn import_projects(mut req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    let actor = ensure_admin_auth(&req, &ctx.env, &cfg).await?;
+
+    let backup: ConfigBackup = req
+        .json()
+        .await
+        .map_err(|e| WatchError::serialization(format!("Invalid JSON Body: {}", e)))?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry)
+        .with_actor(actor)
+        .with_batch_stagger(cfg.batch_stagger)
+        .with_hashed_keys(cfg.hashed_keys);
+    let result = logic.import_projects(backup).await?;
+
+    Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn reschedule(mut req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    let actor = ensure_admin_auth(&req, &ctx.env, &cfg).await?;
+
+    let cmd: RescheduleRequest = req
+        .json()
+        .await
+        .map_err(|e| WatchError::serialization(format!("Invalid JSON Body: {}", e)))?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry).with_actor(actor);
+    let result = logic.reschedule(cmd.unique_key, cmd.next_check_at).await?;
+
+    Response::from_json(&result).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn audit(req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    ensure_admin_auth(&req, &ctx.env, &cfg).await?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry);
+    let rearmed = logic.audit().await?;
+
+    Response::from_json(&rearmed).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn get_audit_log(req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    ensure_admin_auth(&req, &ctx.env, &cfg).await?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry);
+    let events = logic.audit_log().await?;
+
+    Response::from_json(&events).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn get_dead_letters(req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    ensure_admin_auth(&req, &ctx.env, &cfg).await?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry);
+    let entries = logic.dead_letters().await?;
+
+    Response::from_json(&entries).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn project_stats(req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    ensure_read_auth(&req, &ctx.env, &cfg).await?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry);
+    let stats = logic.stats(Date::now_timestamp()).await?;
+
+    Response::from_json(&stats).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+/// 返回当前密钥对应的角色，供前端决定是否展示变更操作
+async fn whoami(req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    ensure_read_auth(&req, &ctx.env, &cfg).await?;
+
+    let provided = req
+        .headers()
+        .get(HEADER_AUTH_KEY)
+        .map_err(|e| WatchError::invalid_input(e.to_string()).in_op("auth.header"))?
+        .unwrap_or_default();
+    let session = SessionInfo {
+        role: cfg.role_for(&provided)?,
+    };
+
+    Response::from_json(&session).map_err(|e| WatchError::serialization(e.to_string()))
+}
+
+async fn metrics(req: Request, ctx: RouteContext<()>) -> error::WatchResult<Response> {
+    let cfg = RuntimeConfig::new(&ctx.env);
+    ensure_metrics_auth(&req, &ctx.env, &cfg).await?;
+
+    let registry = DoProjectRegistry::new(&ctx.env, &cfg.registry_binding)
+        .map_err(|e| WatchError::store(e.to_string()))?;
+
+    let logic = AdminLogic::new(&registry);
+    let body = logic.metrics(Date::now_timestamp()).await?;
+
+    Response::ok(body)
+        .and_then(|mut r| {
+            r.headers_mut()
+                .set("Content-Type", metrics::METRICS_CONTENT_TYPE)?;
+            Ok(r)
+        })
+        .map_err(|e| WatchError::from(e).in_op("metrics.response"))
+}
+
 // =========================================================
 // Entry Points
 // =========================================================
@@ -237,36 +1025,278 @@ async fn trigger_check(mut req: Request, ctx: RouteContext<()>) -> error::WatchR
 pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
     console_error_panic_hook::set_once();
 
+    let cfg = RuntimeConfig::new(&env);
+    logging::set_level(cfg.log_level);
+    let origin = req.headers().get("Origin")?;
+    let allow_origin = allowed_origin(cfg.cors_origins.as_deref(), origin.as_deref());
     let cors = Cors::new()
-        .with_origins(vec!["*"])
+        .with_origins(allow_origin)
         .with_methods(vec![
             Method::Get,
             Method::Post,
+            Method::Put,
             Method::Delete,
             Method::Options,
         ])
-        .with_allowed_headers(vec!["Content-Type", HEADER_AUTH_KEY]);
+        .with_allowed_headers(vec!["Content-Type", "If-None-Match", HEADER_AUTH_KEY])
+        .with_exposed_headers(vec![HEADER_FAILED_KEYS, "ETag", RPC_ERROR_HEADER]);
 
+    console_handler!(health_check_handler, health_check, "health");
+    console_handler!(version_handler, version, "version");
+    console_handler!(openapi_json_handler, openapi_json, "openapi");
     console_handler!(list_projects_handler, list_projects, "project.list");
     console_handler!(create_project_handler, create_project, "project.create");
+    console_handler!(
+        create_projects_batch_handler,
+        create_projects_batch,
+        "project.batch_create"
+    );
+    console_handler!(update_project_handler, update_project, "project.update");
     console_handler!(delete_project_handler, delete_project, "project.delete");
     console_handler!(pop_project_handler, pop_project, "project.pop");
+    console_handler!(delete_all_handler, delete_all, "project.delete_all");
     console_handler!(switch_monitor_handler, switch_monitor, "project.switch");
+    console_handler!(switch_all_handler, switch_all, "project.switch_all");
+    console_handler!(set_enabled_handler, set_enabled, "project.enabled");
     console_handler!(trigger_check_handler, trigger_check, "project.trigger");
+    console_handler!(
+        force_dispatch_handler,
+        force_dispatch,
+        "project.force_dispatch"
+    );
+    console_handler!(reschedule_handler, reschedule, "project.reschedule");
+    console_handler!(audit_handler, audit, "project.audit");
+    console_handler!(project_stats_handler, project_stats, "project.stats");
+    console_handler!(get_audit_log_handler, get_audit_log, "audit.list");
+    console_handler!(
+        get_dead_letters_handler,
+        get_dead_letters,
+        "deadletter.list"
+    );
+    console_handler!(whoami_handler, whoami, "auth.whoami");
+    console_handler!(get_feed_handler, get_feed, "feed.get");
+    console_handler!(
+        list_projects_detailed_handler,
+        list_projects_detailed,
+        "project.list_detailed"
+    );
+    console_handler!(metrics_handler, metrics, "metrics");
+    console_handler!(get_history_handler, get_history, "project.history");
+    console_handler!(get_project_one_handler, get_project_one, "project.get");
+    console_handler!(export_projects_handler, export_projects, "project.export");
+    console_handler!(import_projects_handler, import_projects, "project.import");
 
     let router = Router::new();
     router
+        .get_async("/api/health", health_check_handler)
+        .get_async("/api/version", version_handler)
+        .get_async(openapi::OPENAPI_PATH, openapi_json_handler)
         .get_async("/api/projects", list_projects_handler)
         .post_async("/api/projects", create_project_handler)
+        .post_async("/api/projects/batch", create_projects_batch_handler)
+        .put_async("/api/projects", update_project_handler)
         .delete_async("/api/projects", delete_project_handler)
         .delete_async("/api/projects/pop", pop_project_handler)
+        .delete_async("/api/projects/all", delete_all_handler)
         .post_async("/api/projects/switch", switch_monitor_handler)
+        .post_async("/api/projects/switch-all", switch_all_handler)
+        .post_async("/api/projects/enabled", set_enabled_handler)
         .post_async("/api/projects/trigger", trigger_check_handler)
+        .post_async("/api/projects/force-dispatch", force_dispatch_handler)
+        .post_async("/api/projects/reschedule", reschedule_handler)
+        .post_async("/api/projects/audit", audit_handler)
+        .get_async("/api/projects/stats", project_stats_handler)
+        .get_async("/api/projects/history", get_history_handler)
+        .get_async("/api/projects/one", get_project_one_handler)
+        .get_async("/api/projects/detailed", list_projects_detailed_handler)
+        .get_async("/api/export", export_projects_handler)
+        .post_async("/api/import", import_projects_handler)
+        .get_async("/api/audit", get_audit_log_handler)
+        .get_async("/api/deadletter", get_dead_letters_handler)
+        .get_async("/api/whoami", whoami_handler)
+        .get_async("/api/feed", get_feed_handler)
+        .get_async("/metrics", metrics_handler)
+        .options_async("/api/health", |_, _| async { Response::empty() })
+        .options_async("/api/version", |_, _| async { Response::empty() })
+        .options_async(openapi::OPENAPI_PATH, |_, _| async { Response::empty() })
         .options_async("/api/projects", |_, _| async { Response::empty() })
+        .options_async("/api/projects/batch", |_, _| async { Response::empty() })
         .options_async("/api/projects/pop", |_, _| async { Response::empty() })
+        .options_async("/api/projects/all", |_, _| async { Response::empty() })
         .options_async("/api/projects/switch", |_, _| async { Response::empty() })
+        .options_async("/api/projects/switch-all", |_, _| async {
+            Response::empty()
+        })
+        .options_async("/api/projects/enabled", |_, _| async { Response::empty() })
         .options_async("/api/projects/trigger", |_, _| async { Response::empty() })
+        .options_async("/api/projects/force-dispatch", |_, _| async {
+            Response::empty()
+        })
+        .options_async("/api/projects/reschedule", |_, _| async {
+            Response::empty()
+        })
+        .options_async("/api/projects/audit", |_, _| async { Response::empty() })
+        .options_async("/api/projects/stats", |_, _| async { Response::empty() })
+        .options_async("/api/projects/history", |_, _| async { Response::empty() })
+        .options_async("/api/projects/one", |_, _| async { Response::empty() })
+        .options_async("/api/projects/detailed", |_, _| async { Response::empty() })
+        .options_async("/api/export", |_, _| async { Response::empty() })
+        .options_async("/api/import", |_, _| async { Response::empty() })
+        .options_async("/api/audit", |_, _| async { Response::empty() })
+        .options_async("/api/deadletter", |_, _| async { Response::empty() })
+        .options_async("/api/whoami", |_, _| async { Response::empty() })
+        .options_async("/api/feed", |_, _| async { Response::empty() })
         .run(req, env)
         .await?
         .with_cors(&cors)
+        .and_then(|mut resp| {
+            // 按请求回显 Origin 时，响应随 Origin 变化，需告知缓存
+            if cfg.cors_origins.is_some() {
+                resp.headers_mut().append("Vary", "Origin")?;
+            }
+            Ok(resp)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::WatchErrorStatus;
+
+    #[test]
+    fn test_oversized_body_maps_to_413() {
+        let err = ensure_body_size(DEFAULT_MAX_BODY_BYTES + 1, DEFAULT_MAX_BODY_BYTES).unwrap_err();
+        let body = error_response_body(&err);
+        assert_eq!(body.status_code(), 413);
+        assert_eq!(body.status, WatchErrorStatus::PayloadTooLarge);
+        assert!(body.message.contains("limit is 65536 bytes"));
+    }
+
+    #[test]
+    fn test_body_within_limit_is_accepted() {
+        assert!(ensure_body_size(0, DEFAULT_MAX_BODY_BYTES).is_ok());
+        assert!(ensure_body_size(DEFAULT_MAX_BODY_BYTES, DEFAULT_MAX_BODY_BYTES).is_ok());
+    }
+
+    #[test]
+    fn test_cors_origin_matching() {
+        let allowlist = parse_cors_origins(" https://a.example.com/ ,, https://b.example.com");
+        assert_eq!(
+            allowlist,
+            ["https://a.example.com", "https://b.example.com"]
+        );
+
+        let allowed = |origin| allowed_origin(Some(&allowlist), origin);
+        assert_eq!(
+            allowed(Some("https://b.example.com")).as_deref(),
+            Some("https://b.example.com")
+        );
+        assert_eq!(
+            allowed(Some("HTTPS://A.example.com")).as_deref(),
+            Some("HTTPS://A.example.com")
+        );
+        assert_eq!(allowed(Some("https://evil.example.com")), None);
+        assert_eq!(allowed(None), None);
+
+        // 未配置白名单时保持兼容，允许任意 Origin
+        assert_eq!(allowed_origin(None, None).as_deref(), Some("*"));
+        let wildcard = parse_cors_origins("*");
+        assert_eq!(
+            allowed_origin(Some(&wildcard), Some("https://x.example.com")).as_deref(),
+            Some("*")
+        );
+    }
+
+    #[test]
+    fn test_bearer_token_parsing() {
+        assert_eq!(bearer_token(Some("Bearer abc")), Some("abc"));
+        assert_eq!(bearer_token(Some("bearer  abc ")), Some("abc"));
+        assert_eq!(bearer_token(Some("Basic abc")), None);
+        assert_eq!(bearer_token(Some("Bearer ")), None);
+        assert_eq!(bearer_token(None), None);
+    }
+
+    fn config_with_viewer() -> RuntimeConfig {
+        RuntimeConfig {
+            registry_binding: DEFAULT_REGISTRY_BINDING.to_string(),
+            admin_keys: parse_admin_keys("admin-secret"),
+            metrics_token: None,
+            feed_key: None,
+            viewer_key: Some(AdminKey {
+                label: VIEWER_KEY_LABEL.to_string(),
+                secret: "viewer-secret".to_string(),
+            }),
+            rate_limit: None,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            batch_stagger: logic::DEFAULT_BATCH_STAGGER,
+            cors_origins: None,
+            log_level: logging::DEFAULT_LOG_LEVEL,
+            hashed_keys: false,
+        }
+    }
+
+    #[test]
+    fn test_viewer_can_read() {
+        let cfg = config_with_viewer();
+        // 列表等只读接口使用 read_keys
+        assert_eq!(
+            verify_admin("viewer-secret", &cfg.read_keys()).unwrap(),
+            VIEWER_KEY_LABEL
+        );
+        assert_eq!(
+            verify_admin("admin-secret", &cfg.read_keys()).unwrap(),
+            "key1"
+        );
+        assert_eq!(cfg.role_for("viewer-secret").unwrap(), Role::Viewer);
+        assert_eq!(cfg.role_for("admin-secret").unwrap(), Role::Admin);
+        assert!(cfg.role_for("wrong").is_err());
+    }
+
+    #[test]
+    fn test_viewer_cannot_mutate() {
+        let cfg = config_with_viewer();
+        // 删除等变更接口只接受 admin_keys
+        let err = verify_admin("viewer-secret", &cfg.admin_keys).unwrap_err();
+        assert_eq!(error_response_body(&err).status_code(), 401);
+    }
+
+    #[test]
+    fn test_weak_etag_is_stable() {
+        let body = r#"[{"unique_key":"a"}]"#;
+        assert_eq!(weak_etag(body), weak_etag(body));
+        assert!(weak_etag(body).starts_with("W/\""));
+        assert_ne!(weak_etag(body), weak_etag("[]"));
+    }
+
+    #[test]
+    fn test_matching_if_none_match_is_not_modified() {
+        let etag = weak_etag("[]");
+        assert!(not_modified(Some(&etag), &etag));
+        // 弱比较忽略 W/ 前缀，并支持列表与通配符
+        let strong = etag.trim_start_matches("W/");
+        assert!(not_modified(Some(strong), &etag));
+        assert!(not_modified(Some(&format!("\"other\", {}", etag)), &etag));
+        assert!(not_modified(Some("*"), &etag));
+
+        assert!(!not_modified(None, &etag));
+        assert!(!not_modified(Some(&weak_etag("[1]")), &etag));
+    }
+
+    #[test]
+    fn test_server_errors_hide_details() {
+        let body = error_response_body(&WatchError::store("kv exploded").in_op("registry.list"));
+        assert_eq!(body.status_code(), 500);
+        assert_eq!(body.message, "Internal Server Error");
+        assert!(body.spans.is_empty());
+    }
+
+    #[test]
+    fn test_client_errors_are_structured_json() {
+        let err = WatchError::conflict("Project 'a' already exists").in_op("admin.create");
+        let json = serde_json::to_value(error_response_body(&err)).unwrap();
+        assert_eq!(json["status"], "Conflict");
+        assert_eq!(json["message"], "Project 'a' already exists");
+        assert_eq!(json["spans"][0]["operation"], "admin.create");
+        assert!(json.get("source_message").is_none());
+    }
 }
@@ -1,6 +1,60 @@
-use crate::error::{WatchError, WatchResult};
+use crate::error::{ErrorResponse, WatchError, WatchErrorStatus, WatchResult};
+use crate::project::protocol::UpdateConfigCmd;
 use crate::repository::Registry;
-use verwatch_shared::{CreateProjectRequest, DeleteTarget, ProjectConfig};
+use crate::utils::rate_limit::RateLimit;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+use verwatch_shared::validate::validate_create_request;
+use verwatch_shared::{
+    AuditEvent, CheckEvent, CheckOutcome, CreateProjectRequest, Date, DeadLetter, DeleteTarget,
+    DurationSecs, FieldChange, ProjectConfig, ProjectFilter, ProjectListing, ProjectPage,
+    ProjectWithState, Timestamp,
+    protocol::{
+        BACKUP_FORMAT_VERSION, ConfigBackup, DELETE_ALL_CONFIRMATION, FeedEntry, HealthStatus,
+        ImportReport, ProjectStats, SwitchAllReport, UpdateProjectRequest, UpdateProjectResponse,
+    },
+};
+
+#[cfg(target_arch = "wasm32")]
+macro_rules! log_error {
+    ($($t:tt)*) => (worker::console_error!($($t)*))
+}
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! log_error {
+    ($($t:tt)*) => (eprintln!($($t)*))
+}
+
+/// 未指定操作者时审计日志中记录的标签
+const UNKNOWN_ACTOR: &str = "unknown";
+
+/// 统计中“即将检查”的时间窗口
+const STATS_DUE_WINDOW: Duration = Duration::from_secs(5 * 60);
+
+/// 批量创建/导入时相邻项目首次检查的默认间隔
+pub const DEFAULT_BATCH_STAGGER: DurationSecs = DurationSecs::from_secs(10);
+
+/// 哈希 key 的前缀，便于与可读 key 区分
+const HASHED_KEY_PREFIX: &str = "h-";
+
+/// 由可读 key (`owner/repo->owner/repo`) 派生的短 key：前缀加 SHA-256 前 16 字节的十六进制
+///
+/// 同一可读 key 总是得到相同结果，可读 key 本身仍可由配置中的仓库信息得到
+pub fn hashed_unique_key(human_key: &str) -> String {
+    let digest = Sha256::digest(human_key.as_bytes());
+    let hex: String = digest[..16].iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}{}", HASHED_KEY_PREFIX, hex)
+}
+
+/// 校验创建请求，将全部校验错误合并为一个 InvalidInput 错误
+fn validate_request(req: &CreateProjectRequest) -> WatchResult<()> {
+    validate_create_request(req).map_err(|errors| {
+        let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        WatchError::invalid_input(messages.join("; "))
+    })
+}
+
+/// 健康检查时查询的 key，只用于确认 Registry 存储可读
+const HEALTH_PROBE_KEY: &str = "__health__";
 
 /// 管理端业务逻辑控制器
 ///
@@ -10,19 +64,140 @@ use verwatch_shared::{CreateProjectRequest, DeleteTarget, ProjectConfig};
 /// 3. 易测试：可以轻松注入 MockRegistry 进行单元测试。
 pub struct AdminLogic<'a, R: Registry> {
     registry: &'a R,
+    /// 当前请求的鉴权标签，写入审计日志
+    actor: String,
+    /// 批量创建/导入时为未指定 initial_delay 的项目错开首次检查的间隔
+    batch_stagger: DurationSecs,
+    /// 新建项目是否使用哈希后的短 key
+    hashed_keys: bool,
 }
 
 impl<'a, R: Registry> AdminLogic<'a, R> {
     pub fn new(registry: &'a R) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            actor: UNKNOWN_ACTOR.to_string(),
+            batch_stagger: DEFAULT_BATCH_STAGGER,
+            hashed_keys: false,
+        }
+    }
+
+    /// 指定执行变更操作的鉴权标签
+    pub fn with_actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = actor.into();
+        self
+    }
+
+    /// 指定批量创建/导入时错开首次检查的间隔 (0 表示不错开)
+    pub fn with_batch_stagger(mut self, stagger: DurationSecs) -> Self {
+        self.batch_stagger = stagger;
+        self
+    }
+
+    /// 新建项目使用哈希后的短 key (已有项目的 key 不变)
+    pub fn with_hashed_keys(mut self, hashed_keys: bool) -> Self {
+        self.hashed_keys = hashed_keys;
+        self
+    }
+
+    /// 为批次中第 `index` 个项目分配错开的首次检查延迟
+    /// 调用方已指定 initial_delay (非 0) 时保持不变
+    fn staggered(&self, mut req: CreateProjectRequest, index: usize) -> CreateProjectRequest {
+        if req.initial_delay.as_secs() == 0 {
+            req.initial_delay =
+                DurationSecs::from_secs(self.batch_stagger.as_secs().saturating_mul(index as u64));
+        }
+        req
+    }
+
+    /// 记录一条审计日志
+    /// 变更已经生效，写入失败只记录错误而不影响操作结果
+    async fn record(&self, action: &str, target: &str) {
+        self.record_changes(action, target, Vec::new()).await;
+    }
+
+    /// 记录审计日志，并附带实际发生变化的字段
+    async fn record_changes(&self, action: &str, target: &str, changes: Vec<FieldChange>) {
+        let event = AuditEvent {
+            at: Date::now_timestamp(),
+            actor: self.actor.clone(),
+            action: action.to_string(),
+            target: target.to_string(),
+            changes,
+        };
+        if let Err(e) = self.registry.append_audit_event(&event).await {
+            log_error!("Audit Log Failed [{} {}]: {}", action, target, e);
+        }
+    }
+
+    /// 获取管理操作审计日志 (按时间顺序，最新的在最后)
+    pub async fn audit_log(&self) -> WatchResult<Vec<AuditEvent>> {
+        self.registry
+            .audit_log()
+            .await
+            .map_err(|e| e.in_op("admin.audit_log"))
     }
 
-    /// 列出所有项目
-    pub async fn list_projects(&self) -> WatchResult<Vec<ProjectConfig>> {
+    /// 获取注册失败记录 (按时间顺序，最新的在最后)
+    pub async fn dead_letters(&self) -> WatchResult<Vec<DeadLetter>> {
         self.registry
+            .dead_letters()
+            .await
+            .map_err(|e| e.in_op("admin.deadletter"))
+    }
+
+    /// 健康检查
+    /// 通过一次 Registry 存储读取确认 Registry DO 可达，不访问任何 Monitor；
+    /// 失败时不返回错误而是标记对应子系统
+    pub async fn health_check(&self) -> HealthStatus {
+        // 该接口无需鉴权，不能让匿名请求按项目数量放大子请求
+        let registry = self.registry.is_registered(HEALTH_PROBE_KEY).await.is_ok();
+        HealthStatus {
+            ok: registry,
+            registry,
+            checked_at: Date::now_timestamp(),
+        }
+    }
+
+    /// 列出项目 (按过滤条件筛选)
+    pub async fn list_projects(&self, filter: &ProjectFilter) -> WatchResult<Vec<ProjectConfig>> {
+        let projects = self
+            .registry
             .list()
             .await
-            .map_err(|e| e.in_op("admin.list"))
+            .map_err(|e| e.in_op("admin.list"))?;
+        Ok(projects.into_iter().filter(|c| filter.matches(c)).collect())
+    }
+
+    /// 列出项目，同时返回读取失败的 key (按过滤条件筛选)
+    pub async fn list_projects_lenient(
+        &self,
+        filter: &ProjectFilter,
+    ) -> WatchResult<ProjectListing> {
+        let mut listing = self
+            .registry
+            .list_lenient(false)
+            .await
+            .map_err(|e| e.in_op("admin.list"))?;
+        listing.projects.retain(|c| filter.matches(c));
+        Ok(listing)
+    }
+
+    /// 分页列出项目 (按过滤条件筛选)
+    /// 过滤在每页内进行，因此单页结果可能少于 `limit`，应以 `next_cursor` 判断是否结束
+    pub async fn list_projects_paged(
+        &self,
+        filter: &ProjectFilter,
+        cursor: Option<&str>,
+        limit: usize,
+    ) -> WatchResult<ProjectPage> {
+        let mut page = self
+            .registry
+            .list_page(cursor, limit)
+            .await
+            .map_err(|e| e.in_op("admin.list_paged"))?;
+        page.items.retain(|c| filter.matches(c));
+        Ok(page)
     }
 
     /// 创建项目
@@ -30,26 +205,31 @@ impl<'a, R: Registry> AdminLogic<'a, R> {
     /// 2. 构建 ProjectConfig
     /// 3. 通过 Registry 注册 (Registry 内部会调用 Monitor.setup)
     pub async fn create_project(&self, req: CreateProjectRequest) -> WatchResult<ProjectConfig> {
-        // 业务校验：防止空仓库名
-        if req.base_config.upstream_repo.trim().is_empty() {
-            return Err(WatchError::invalid_input("Upstream repo cannot be empty")
-                .in_op("admin.create.validate"));
-        }
+        // 业务校验 (与前端共用同一套规则)
+        validate_request(&req).map_err(|e| e.in_op("admin.create.validate"))?;
 
-        let config = ProjectConfig::new(req);
+        let mut config = ProjectConfig::new(req);
+        let human_key = config.display_key();
+        let hashed_key = hashed_unique_key(&human_key);
+        if self.hashed_keys {
+            config.unique_key = hashed_key.clone();
+        }
         let unique_key = config.unique_key.clone();
 
-        // 检查是否已存在
-        if self
-            .registry
-            .is_registered(&unique_key)
-            .await
-            .map_err(|e| e.in_op_with("admin.create.check", &unique_key))?
-        {
-            return Err(
-                WatchError::conflict(format!("Project '{}' already exists", unique_key))
-                    .in_op("admin.create"),
-            );
+        // 检查是否已存在 (两种 key 都检查，避免切换模式前后重复创建同一项目)
+        for key in [&human_key, &hashed_key] {
+            if self
+                .registry
+                .is_registered(key)
+                .await
+                .map_err(|e| e.in_op_with("admin.create.check", key))?
+            {
+                return Err(WatchError::conflict(format!(
+                    "Project '{}' already exists",
+                    human_key
+                ))
+                .in_op("admin.create"));
+            }
         }
 
         // 注册 (内部调用 Monitor.setup)
@@ -57,53 +237,405 @@ impl<'a, R: Registry> AdminLogic<'a, R> {
             .register(&config)
             .await
             .map_err(|e| e.in_op_with("admin.create.register", &unique_key))?;
+        self.record("create", &unique_key).await;
 
         Ok(config)
     }
 
+    /// 批量创建项目
+    /// 逐个复用单项创建的校验与冲突检查，单项失败不会中断整个批次，
+    /// 结果与输入按顺序一一对应；未指定 initial_delay 的项目按序号错开首次检查
+    pub async fn create_projects_batch(
+        &self,
+        reqs: Vec<CreateProjectRequest>,
+    ) -> Vec<Result<ProjectConfig, ErrorResponse>> {
+        let mut results = Vec::with_capacity(reqs.len());
+        // 顺序执行，保证批次内重复项能被冲突检查识别
+        for (index, req) in reqs.into_iter().enumerate() {
+            let result = self
+                .create_project(self.staggered(req, index))
+                .await
+                .map_err(|e| e.in_op_with("admin.batch", index.to_string()).into());
+            results.push(result);
+        }
+        results
+    }
+
+    /// 更新项目
+    /// 1. 通过 unique_key 查找现有配置 (包括已禁用的项目)
+    /// 2. 校验应用可变字段 (time_config, comparison_mode, dispatch_token_secret) 后的配置
+    /// 3. 由 Monitor 在 DO 内更新这些字段，不改变监控状态、调度与已存储的版本状态
+    pub async fn update_project(
+        &self,
+        req: UpdateProjectRequest,
+    ) -> WatchResult<UpdateProjectResponse> {
+        let unique_key = req.unique_key;
+        let not_found = || {
+            WatchError::not_found(format!("Project '{}' not found", unique_key))
+                .in_op("admin.update")
+        };
+        let update = UpdateConfigCmd {
+            time_config: req.time_config,
+            comparison_mode: req.comparison_mode,
+            dispatch_token_secret: req.dispatch_token_secret,
+        };
+
+        let mut config = self
+            .registry
+            .get_config(&unique_key)
+            .await
+            .map_err(|e| e.in_op_with("admin.update.get", &unique_key))?
+            .ok_or_else(not_found)?;
+        update.apply(&mut config);
+        validate_request(&config.request)
+            .map_err(|e| e.in_op_with("admin.update.validate", &unique_key))?;
+
+        let response = self
+            .registry
+            .update_config(&unique_key, &update)
+            .await
+            .map_err(|e| e.in_op_with("admin.update", &unique_key))?
+            .ok_or_else(not_found)?;
+        self.record_changes("update", &unique_key, response.changes.clone())
+            .await;
+
+        Ok(response)
+    }
+
     /// 删除项目
     /// 通过 Registry 注销 (Registry 内部会调用 Monitor.stop)
     pub async fn delete_project(&self, target: DeleteTarget) -> WatchResult<bool> {
-        self.registry
+        let deleted = self
+            .registry
             .unregister(&target.id)
             .await
-            .map_err(|e| e.in_op_with("admin.delete", &target.id))
+            .map_err(|e| e.in_op_with("admin.delete", &target.id))?;
+        if deleted {
+            self.record("delete", &target.id).await;
+        }
+        Ok(deleted)
+    }
+
+    /// 删除全部项目 (含已禁用与读取配置失败的项目)，返回实际删除的数量
+    ///
+    /// `confirm` 必须为 [`DELETE_ALL_CONFIRMATION`]，防止误操作
+    pub async fn delete_all(&self, confirm: &str) -> WatchResult<usize> {
+        if confirm != DELETE_ALL_CONFIRMATION {
+            return Err(WatchError::invalid_input(format!(
+                "Confirmation must be '{}'",
+                DELETE_ALL_CONFIRMATION
+            ))
+            .in_op("admin.delete_all"));
+        }
+
+        let listing = self
+            .registry
+            .list_lenient(true)
+            .await
+            .map_err(|e| e.in_op("admin.delete_all.list"))?;
+        let mut keys: Vec<String> = listing
+            .projects
+            .into_iter()
+            .map(|c| c.unique_key)
+            .chain(listing.failed_keys)
+            .collect();
+        keys.sort();
+        keys.dedup();
+
+        // 逐个注销 (Registry 内部会调用 Monitor.stop)
+        let mut deleted = 0;
+        for key in &keys {
+            if self
+                .registry
+                .unregister(key)
+                .await
+                .map_err(|e| e.in_op_with("admin.delete_all", key))?
+            {
+                deleted += 1;
+            }
+        }
+        self.record("delete_all", "*").await;
+        Ok(deleted)
     }
 
     /// 弹出项目 (获取并删除)
     pub async fn pop_project(&self, target: DeleteTarget) -> WatchResult<Option<ProjectConfig>> {
-        // 先获取
-        let projects = self
+        // 先获取 (直接读取单个配置，`list` 会跳过已禁用的项目)
+        let config = self
             .registry
-            .list()
+            .get_config(&target.id)
             .await
-            .map_err(|e| e.in_op_with("admin.pop.list", &target.id))?;
-        let config = projects.into_iter().find(|c| c.unique_key == target.id);
+            .map_err(|e| e.in_op_with("admin.pop.get", &target.id))?;
 
         if let Some(ref c) = config {
             self.registry
                 .unregister(&c.unique_key)
                 .await
                 .map_err(|e| e.in_op_with("admin.pop.unregister", &c.unique_key))?;
+            self.record("delete", &c.unique_key).await;
         }
 
         Ok(config)
     }
 
+    /// 获取单个项目配置，不存在时返回 404
+    pub async fn get_project(&self, unique_key: String) -> WatchResult<ProjectConfig> {
+        self.registry
+            .get_config(&unique_key)
+            .await
+            .map_err(|e| e.in_op_with("admin.get", &unique_key))?
+            .ok_or_else(|| {
+                WatchError::not_found(format!("Project '{}' not found", unique_key))
+                    .in_op("admin.get")
+            })
+    }
+
     /// 切换监控状态
     pub async fn switch_monitor(&self, unique_key: String, paused: bool) -> WatchResult<bool> {
-        self.registry
+        let switched = self
+            .registry
             .switch_monitor(&unique_key, paused)
             .await
-            .map_err(|e| e.in_op_with("admin.switch", &unique_key))
+            .map_err(|e| e.in_op_with("admin.switch", &unique_key))?;
+        if switched {
+            let action = if paused { "pause" } else { "resume" };
+            self.record(action, &unique_key).await;
+        }
+        Ok(switched)
+    }
+
+    /// 批量暂停或恢复所有项目
+    pub async fn switch_all(&self, paused: bool) -> WatchResult<SwitchAllReport> {
+        let report = self
+            .registry
+            .switch_all(paused)
+            .await
+            .map_err(|e| e.in_op("admin.switch_all"))?;
+        let action = if paused { "pause_all" } else { "resume_all" };
+        self.record(action, "*").await;
+        Ok(report)
+    }
+
+    /// 启用或禁用项目
+    pub async fn set_enabled(&self, unique_key: String, enabled: bool) -> WatchResult<bool> {
+        let applied = self
+            .registry
+            .set_enabled(&unique_key, enabled)
+            .await
+            .map_err(|e| e.in_op_with("admin.set_enabled", &unique_key))?;
+        if applied {
+            let action = if enabled { "enable" } else { "disable" };
+            self.record(action, &unique_key).await;
+        }
+        Ok(applied)
+    }
+
+    /// 导出所有项目 (包括已禁用的)，按 unique_key 排序
+    pub async fn export_projects(&self) -> WatchResult<ConfigBackup> {
+        let listing = self
+            .registry
+            .list_lenient(true)
+            .await
+            .map_err(|e| e.in_op("admin.export"))?;
+        if !listing.failed_keys.is_empty() {
+            return Err(WatchError::store(format!(
+                "Cannot export unreadable projects: {}",
+                listing.failed_keys.join(", ")
+            ))
+            .in_op("admin.export"));
+        }
+        let mut projects = listing.projects;
+        projects.sort_by(|a, b| a.unique_key.cmp(&b.unique_key));
+        Ok(ConfigBackup {
+            version: BACKUP_FORMAT_VERSION,
+            projects,
+        })
+    }
+
+    /// 从备份导入项目
+    ///
+    /// 通过 `create_project` 重新创建，已存在的 key 记为冲突并跳过；
+    /// 备份中暂停或禁用的项目在创建后恢复对应状态；
+    /// 与批量创建一样，未指定 initial_delay 的项目按序号错开首次检查
+    pub async fn import_projects(&self, backup: ConfigBackup) -> WatchResult<ImportReport> {
+        if backup.version != BACKUP_FORMAT_VERSION {
+            return Err(WatchError::invalid_input(format!(
+                "Unsupported backup version: {} (expected {})",
+                backup.version, BACKUP_FORMAT_VERSION
+            ))
+            .in_op("admin.import"));
+        }
+
+        let mut report = ImportReport::default();
+        for (index, project) in backup.projects.into_iter().enumerate() {
+            let key = project.unique_key.clone();
+            let created = match self
+                .create_project(self.staggered(project.request, index))
+                .await
+            {
+                Ok(created) => created,
+                Err(e) if e.status == WatchErrorStatus::Conflict => {
+                    report.conflicts.push(key);
+                    continue;
+                }
+                Err(e) => {
+                    report.failed.push((key, e.to_string()));
+                    continue;
+                }
+            };
+
+            let restored = async {
+                if project.state.is_paused() {
+                    self.switch_monitor(created.unique_key.clone(), true)
+                        .await?;
+                }
+                if !project.enabled {
+                    self.set_enabled(created.unique_key.clone(), false).await?;
+                }
+                WatchResult::Ok(())
+            }
+            .await;
+            match restored {
+                Ok(()) => report.imported.push(created.unique_key),
+                Err(e) => report.failed.push((created.unique_key, e.to_string())),
+            }
+        }
+        Ok(report)
+    }
+
+    /// 获取检查历史 (按时间顺序，最新的在最后)
+    pub async fn get_history(&self, unique_key: String) -> WatchResult<Vec<CheckEvent>> {
+        self.registry
+            .get_history(&unique_key)
+            .await
+            .map_err(|e| e.in_op_with("admin.history", &unique_key))?
+            .ok_or_else(|| {
+                WatchError::not_found(format!("Project '{}' not found", unique_key))
+                    .in_op("admin.history")
+            })
     }
 
     /// 手动触发检查
     pub async fn trigger_check(&self, unique_key: String) -> WatchResult<bool> {
-        self.registry
+        let triggered = self
+            .registry
             .trigger_check(&unique_key)
             .await
-            .map_err(|e| e.in_op_with("admin.trigger", &unique_key))
+            .map_err(|e| e.in_op_with("admin.trigger", &unique_key))?;
+        if triggered {
+            self.record("trigger", &unique_key).await;
+        }
+        Ok(triggered)
+    }
+
+    /// 强制触发 Dispatch (不比较版本，不修改版本状态)，返回触发的 Tag，项目不存在时返回 404
+    pub async fn force_dispatch(&self, unique_key: String) -> WatchResult<String> {
+        let tag = self
+            .registry
+            .force_dispatch(&unique_key)
+            .await
+            .map_err(|e| e.in_op_with("admin.force_dispatch", &unique_key))?
+            .ok_or_else(|| {
+                WatchError::not_found(format!("Project '{}' not found", unique_key))
+                    .in_op("admin.force_dispatch")
+            })?;
+        self.record("force_dispatch", &unique_key).await;
+        Ok(tag)
+    }
+
+    /// 重新安排下一次检查时间 (项目未注册时返回 false)
+    pub async fn reschedule(
+        &self,
+        unique_key: String,
+        next_check_at: Timestamp,
+    ) -> WatchResult<bool> {
+        let rescheduled = self
+            .registry
+            .reschedule(&unique_key, next_check_at)
+            .await
+            .map_err(|e| e.in_op_with("admin.reschedule", &unique_key))?;
+        if rescheduled {
+            self.record("reschedule", &unique_key).await;
+        }
+        Ok(rescheduled)
+    }
+
+    /// 对所有项目执行自愈检查，返回重新设置了 Alarm 的项目
+    pub async fn audit(&self) -> WatchResult<Vec<String>> {
+        self.registry
+            .audit()
+            .await
+            .map_err(|e| e.in_op("admin.audit"))
+    }
+
+    /// 版本订阅：所有启用项目已记录的最新版本
+    pub async fn feed(&self) -> WatchResult<Vec<FeedEntry>> {
+        self.registry
+            .feed()
+            .await
+            .map_err(|e| e.in_op("admin.feed"))
+    }
+
+    /// 项目列表及各项目已记录的版本状态
+    /// 与 `list_projects` 不同，需要逐个请求各项目的 Monitor
+    pub async fn list_projects_detailed(&self) -> WatchResult<Vec<ProjectWithState>> {
+        self.registry
+            .list_detailed()
+            .await
+            .map_err(|e| e.in_op("admin.list_detailed"))
+    }
+
+    /// 汇总统计
+    /// 只依赖一次 list 调用，数据均来自配置中的状态字段，不额外请求各项目的 Monitor
+    pub async fn stats(&self, now: Timestamp) -> WatchResult<ProjectStats> {
+        let projects = self
+            .registry
+            .list()
+            .await
+            .map_err(|e| e.in_op("admin.stats"))?;
+        let due_before = now + STATS_DUE_WINDOW;
+        Ok(projects
+            .iter()
+            .fold(ProjectStats::default(), |mut stats, config| {
+                stats.total += 1;
+                match config.next_check_at() {
+                    Some(next_check_at) => {
+                        stats.running += 1;
+                        if next_check_at <= due_before {
+                            stats.due_within_5m += 1;
+                        }
+                    }
+                    None => stats.paused += 1,
+                }
+                if config.last_outcome == Some(CheckOutcome::Error) {
+                    stats.last_error_count += 1;
+                }
+                stats
+            }))
+    }
+
+    /// Prometheus 文本格式指标，与统计接口一样只依赖一次 list 调用
+    pub async fn metrics(&self, now: Timestamp) -> WatchResult<String> {
+        let projects = self
+            .registry
+            .list()
+            .await
+            .map_err(|e| e.in_op("admin.metrics"))?;
+        Ok(crate::metrics::render(&projects, now))
+    }
+
+    /// 按鉴权标签限流，令牌耗尽时返回 429
+    pub async fn check_rate_limit(&self, label: &str, limit: &RateLimit) -> WatchResult<()> {
+        let allowed = self
+            .registry
+            .consume_rate_token(label, limit)
+            .await
+            .map_err(|e| e.in_op_with("admin.rate_limit", label))?;
+        if !allowed {
+            return Err(WatchError::rate_limited("Rate limit exceeded")
+                .in_op_with("admin.rate_limit", label));
+        }
+        Ok(())
     }
 }
 
@@ -113,8 +645,10 @@ impl<'a, R: Registry> AdminLogic<'a, R> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{error::WatchErrorStatus, repository::tests::MockRegistry};
-    use verwatch_shared::{BaseConfig, ComparisonMode, DurationSecs, TimeConfig};
+    use crate::repository::tests::MockRegistry;
+    use verwatch_shared::{
+        BaseConfig, ComparisonMode, DurationSecs, MonitorState, Provider, TimeConfig,
+    };
 
     fn make_request(upstream_repo: &str) -> CreateProjectRequest {
         CreateProjectRequest {
@@ -128,6 +662,7 @@ mod tests {
             comparison_mode: ComparisonMode::PublishedAt,
             dispatch_token_secret: None,
             initial_delay: DurationSecs::from_secs(60),
+            ..Default::default()
         }
     }
 
@@ -146,6 +681,55 @@ mod tests {
         assert_eq!(stored[0].unique_key, result.unique_key);
     }
 
+    #[test]
+    fn test_hashed_unique_key_is_deterministic() {
+        let human = make_request("rust").base_config.generate_unique_key();
+        let key = hashed_unique_key(&human);
+        assert_eq!(key, hashed_unique_key(&human));
+        assert!(key.starts_with(HASHED_KEY_PREFIX));
+        assert_eq!(key.len(), HASHED_KEY_PREFIX.len() + 32);
+        assert_ne!(
+            key,
+            hashed_unique_key(&make_request("cargo").base_config.generate_unique_key())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_with_hashed_keys_and_lookup() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry).with_hashed_keys(true);
+
+        let created = logic.create_project(make_request("rust")).await.unwrap();
+        assert_eq!(
+            created.unique_key,
+            hashed_unique_key("rust-lang/rust->me/mirror")
+        );
+        // 可读 key 仍可由配置得到
+        assert_eq!(created.display_key(), "rust-lang/rust->me/mirror");
+
+        // 按哈希 key 查询与操作
+        let found = logic.get_project(created.unique_key.clone()).await.unwrap();
+        assert_eq!(found.display_key(), created.display_key());
+        assert!(
+            logic
+                .switch_monitor(created.unique_key.clone(), true)
+                .await
+                .unwrap()
+        );
+
+        // 同一项目无论哪种模式都不能重复创建
+        let err = logic
+            .create_project(make_request("rust"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::Conflict);
+        let err = AdminLogic::new(&registry)
+            .create_project(make_request("rust"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::Conflict);
+    }
+
     #[tokio::test]
     async fn test_create_project_validation() {
         let registry = MockRegistry::new();
@@ -228,6 +812,28 @@ mod tests {
         assert!(list.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_pop_disabled_project() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let config = logic.create_project(make_request("rust")).await.unwrap();
+        logic
+            .set_enabled(config.unique_key.clone(), false)
+            .await
+            .unwrap();
+
+        let popped = logic
+            .pop_project(DeleteTarget {
+                id: config.unique_key.clone(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(popped.unwrap().unique_key, config.unique_key);
+        assert!(!registry.is_registered(&config.unique_key).await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_switch_monitor() {
         let registry = MockRegistry::new();
@@ -249,6 +855,54 @@ mod tests {
         assert!(!list[0].state.is_paused());
     }
 
+    #[tokio::test]
+    async fn test_create_project_rejects_invalid_gitea_base_url() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let mut req = make_request("forgejo");
+        req.provider = Provider::Gitea {
+            base_url: "codeberg.org".into(),
+        };
+        let err = logic.create_project(req).await.unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::InvalidInput);
+
+        let mut req = make_request("forgejo");
+        req.provider = Provider::Gitea {
+            base_url: "https://codeberg.org".into(),
+        };
+        assert!(logic.create_project(req).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_set_enabled_excludes_from_list() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let config = logic.create_project(make_request("rust")).await.unwrap();
+        assert!(config.enabled);
+
+        assert!(
+            logic
+                .set_enabled(config.unique_key.clone(), false)
+                .await
+                .unwrap()
+        );
+        let filter = ProjectFilter::default();
+        assert!(logic.list_projects(&filter).await.unwrap().is_empty());
+
+        assert!(
+            logic
+                .set_enabled(config.unique_key.clone(), true)
+                .await
+                .unwrap()
+        );
+        assert_eq!(logic.list_projects(&filter).await.unwrap().len(), 1);
+
+        // 不存在的项目
+        assert!(!logic.set_enabled("missing".into(), false).await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_trigger_check() {
         let registry = MockRegistry::new();
@@ -264,4 +918,906 @@ mod tests {
             .unwrap();
         assert!(triggered);
     }
+
+    #[tokio::test]
+    async fn test_force_dispatch() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+        let config = logic.create_project(make_request("rust")).await.unwrap();
+        registry
+            .versions
+            .borrow_mut()
+            .insert(config.unique_key.clone(), "v2.0.0".into());
+
+        let tag = logic
+            .force_dispatch(config.unique_key.clone())
+            .await
+            .unwrap();
+        assert_eq!(tag, "v2.0.0");
+        assert_eq!(registry.versions.borrow()[&config.unique_key], "v2.0.0");
+
+        let err = logic.force_dispatch("missing".into()).await.unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_lenient_reports_failed_keys() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+        let config = logic.create_project(make_request("rust")).await.unwrap();
+        registry.corrupt_keys.borrow_mut().push("broken".into());
+
+        let listing = logic
+            .list_projects_lenient(&ProjectFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(listing.projects.len(), 1);
+        assert_eq!(listing.projects[0].unique_key, config.unique_key);
+        assert_eq!(listing.failed_keys, ["broken"]);
+    }
+
+    #[tokio::test]
+    async fn test_export_import_round_trip() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+        for repo in ["rust", "cargo", "clippy"] {
+            logic.create_project(make_request(repo)).await.unwrap();
+        }
+        let disabled = make_request("clippy").base_config.generate_unique_key();
+        logic.set_enabled(disabled.clone(), false).await.unwrap();
+
+        let backup = logic.export_projects().await.unwrap();
+        assert_eq!(backup.version, BACKUP_FORMAT_VERSION);
+        assert_eq!(backup.projects.len(), 3);
+
+        // 清空后导入
+        let json = serde_json::to_string(&backup).unwrap();
+        registry.monitors.borrow_mut().clear();
+        let report = logic
+            .import_projects(serde_json::from_str(&json).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(report.imported.len(), 3);
+        assert!(report.conflicts.is_empty());
+        assert!(report.failed.is_empty());
+
+        let restored = logic.export_projects().await.unwrap();
+        let summary = |b: &ConfigBackup| -> Vec<(String, bool)> {
+            b.projects
+                .iter()
+                .map(|p| (p.unique_key.clone(), p.enabled))
+                .collect()
+        };
+        assert_eq!(summary(&restored), summary(&backup));
+        assert!(!registry.monitors.borrow()[&disabled].enabled);
+    }
+
+    #[tokio::test]
+    async fn test_import_reports_conflicts_and_rejects_unknown_version() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+        let existing = logic.create_project(make_request("rust")).await.unwrap();
+
+        let report = logic
+            .import_projects(ConfigBackup {
+                version: BACKUP_FORMAT_VERSION,
+                projects: vec![existing.clone(), ProjectConfig::new(make_request(""))],
+            })
+            .await
+            .unwrap();
+        assert!(report.imported.is_empty());
+        assert_eq!(report.conflicts, vec![existing.unique_key.clone()]);
+        assert_eq!(report.failed.len(), 1);
+
+        let err = logic
+            .import_projects(ConfigBackup {
+                version: BACKUP_FORMAT_VERSION + 1,
+                projects: vec![],
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn test_reschedule() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+        let config = logic.create_project(make_request("rust")).await.unwrap();
+        let key = config.unique_key.clone();
+
+        // 暂停中的项目无法重新安排
+        registry.monitors.borrow_mut().get_mut(&key).unwrap().state = MonitorState::Paused;
+        let err = logic
+            .reschedule(key.clone(), Timestamp::new(5_000))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::Conflict);
+
+        registry.monitors.borrow_mut().get_mut(&key).unwrap().state =
+            MonitorState::running(Timestamp::new(0));
+        assert!(
+            logic
+                .reschedule(key.clone(), Timestamp::new(5_000))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            registry.monitors.borrow()[&key].state.next_check_at(),
+            Some(Timestamp::new(5_000))
+        );
+
+        assert!(
+            !logic
+                .reschedule("missing".into(), Timestamp::new(5_000))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_audit_only_rearms_running_projects() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+        let running = logic.create_project(make_request("rust")).await.unwrap();
+        let paused = logic.create_project(make_request("go")).await.unwrap();
+        registry
+            .monitors
+            .borrow_mut()
+            .get_mut(&running.unique_key)
+            .unwrap()
+            .state = MonitorState::running(Timestamp::new(0));
+        registry
+            .lost_alarms
+            .borrow_mut()
+            .extend([running.unique_key.clone(), paused.unique_key.clone()]);
+
+        assert_eq!(logic.audit().await.unwrap(), vec![running.unique_key]);
+        assert!(logic.audit().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mutations_are_audited_in_order() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry).with_actor("ci");
+
+        let config = logic.create_project(make_request("rust")).await.unwrap();
+        assert!(
+            logic
+                .delete_project(DeleteTarget {
+                    id: config.unique_key.clone(),
+                })
+                .await
+                .unwrap()
+        );
+        // 未生效的变更不记录
+        assert!(
+            !logic
+                .delete_project(DeleteTarget {
+                    id: config.unique_key.clone(),
+                })
+                .await
+                .unwrap()
+        );
+
+        let events = logic.audit_log().await.unwrap();
+        let actions: Vec<_> = events
+            .iter()
+            .map(|e| (e.actor.as_str(), e.action.as_str(), e.target.as_str()))
+            .collect();
+        assert_eq!(
+            actions,
+            [
+                ("ci", "create", config.unique_key.as_str()),
+                ("ci", "delete", config.unique_key.as_str()),
+            ]
+        );
+        assert!(events[0].at <= events[1].at);
+    }
+
+    #[tokio::test]
+    async fn test_stats_counts_mixed_projects() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+        let now = Timestamp::new(1_000_000_000);
+
+        let insert = |repo: &str, state: MonitorState, outcome: Option<CheckOutcome>| {
+            let mut config = ProjectConfig::new(make_request(repo));
+            config.state = state;
+            config.last_outcome = outcome;
+            registry
+                .monitors
+                .borrow_mut()
+                .insert(config.unique_key.clone(), config);
+        };
+        // 已过期 (仍算作即将检查)，且上次失败
+        insert(
+            "rust",
+            MonitorState::running(now),
+            Some(CheckOutcome::Error),
+        );
+        // 1 分钟后到期
+        insert(
+            "go",
+            MonitorState::running(now + Duration::from_secs(60)),
+            None,
+        );
+        // 1 小时后到期
+        insert(
+            "zig",
+            MonitorState::running(now + Duration::from_secs(3600)),
+            Some(CheckOutcome::Success),
+        );
+        // 暂停，且上次失败
+        insert("deno", MonitorState::Paused, Some(CheckOutcome::Error));
+        // 禁用的项目不计入
+        let mut disabled = ProjectConfig::new(make_request("bun"));
+        disabled.enabled = false;
+        disabled.state = MonitorState::running(now);
+        registry
+            .monitors
+            .borrow_mut()
+            .insert(disabled.unique_key.clone(), disabled);
+
+        assert_eq!(
+            logic.stats(now).await.unwrap(),
+            ProjectStats {
+                total: 4,
+                running: 3,
+                paused: 1,
+                due_within_5m: 2,
+                last_error_count: 2,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_detailed_includes_version_state() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+        let checked = logic.create_project(make_request("cargo")).await.unwrap();
+        let fresh = logic.create_project(make_request("rust")).await.unwrap();
+
+        registry
+            .versions
+            .borrow_mut()
+            .insert(checked.unique_key.clone(), "1.80.0".into());
+        let event = CheckEvent::now(CheckOutcome::Success, "Dispatched 1.80.0");
+        let checked_at = event.at;
+        registry
+            .histories
+            .borrow_mut()
+            .insert(checked.unique_key.clone(), vec![event]);
+
+        let items = logic.list_projects_detailed().await.unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].config.unique_key, checked.unique_key);
+        assert_eq!(items[0].current_version.as_deref(), Some("1.80.0"));
+        assert_eq!(items[0].checked_at, Some(checked_at));
+        assert_eq!(items[1].config.unique_key, fresh.unique_key);
+        assert_eq!(items[1].current_version, None);
+        assert_eq!(items[1].checked_at, None);
+    }
+
+    #[tokio::test]
+    async fn test_feed_reflects_stored_version_states() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+        let checked = logic.create_project(make_request("cargo")).await.unwrap();
+        logic.create_project(make_request("rust")).await.unwrap();
+        let disabled = logic.create_project(make_request("rustup")).await.unwrap();
+        logic
+            .set_enabled(disabled.unique_key.clone(), false)
+            .await
+            .unwrap();
+
+        registry
+            .versions
+            .borrow_mut()
+            .insert(checked.unique_key.clone(), "1.80.0".into());
+        let event = CheckEvent::now(CheckOutcome::Success, "Dispatched 1.80.0");
+        let checked_at = event.at;
+        registry
+            .histories
+            .borrow_mut()
+            .insert(checked.unique_key.clone(), vec![event]);
+
+        let feed = logic.feed().await.unwrap();
+        assert_eq!(
+            feed,
+            vec![
+                FeedEntry {
+                    upstream: "rust-lang/cargo".into(),
+                    current_version: Some("1.80.0".into()),
+                    checked_at: Some(checked_at),
+                },
+                FeedEntry {
+                    upstream: "rust-lang/rust".into(),
+                    current_version: None,
+                    checked_at: None,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_project_preserves_version_state() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let config = logic.create_project(make_request("rust")).await.unwrap();
+        registry
+            .versions
+            .borrow_mut()
+            .insert(config.unique_key.clone(), "v1.0.0".into());
+
+        let updated = logic
+            .update_project(UpdateProjectRequest {
+                unique_key: config.unique_key.clone(),
+                time_config: Some(TimeConfig {
+                    check_interval: DurationSecs::from_hours(6),
+                    retry_interval: DurationSecs::from_secs(120),
+                    ..TimeConfig::default()
+                }),
+                comparison_mode: Some(ComparisonMode::UpdatedAt),
+                dispatch_token_secret: Some("OTHER_PAT".into()),
+            })
+            .await
+            .unwrap()
+            .config;
+
+        // 可变字段已更新
+        assert_eq!(
+            updated.request.time_config.check_interval,
+            DurationSecs::from_hours(6)
+        );
+        assert_eq!(updated.request.comparison_mode, ComparisonMode::UpdatedAt);
+        assert_eq!(
+            updated.request.dispatch_token_secret.as_deref(),
+            Some("OTHER_PAT")
+        );
+        // 基础配置与 unique_key 不变
+        assert_eq!(updated.unique_key, config.unique_key);
+        assert_eq!(registry.list().await.unwrap().len(), 1);
+
+        // 版本状态保留
+        assert_eq!(
+            registry
+                .versions
+                .borrow()
+                .get(&config.unique_key)
+                .map(String::as_str),
+            Some("v1.0.0")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_project_disabled_keeps_state() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let config = logic.create_project(make_request("rust")).await.unwrap();
+        logic
+            .set_enabled(config.unique_key.clone(), false)
+            .await
+            .unwrap();
+        let next_check_at = Timestamp::new(42_000);
+        registry
+            .monitors
+            .borrow_mut()
+            .get_mut(&config.unique_key)
+            .unwrap()
+            .state = MonitorState::running(next_check_at);
+
+        let updated = logic
+            .update_project(UpdateProjectRequest {
+                unique_key: config.unique_key.clone(),
+                time_config: None,
+                comparison_mode: Some(ComparisonMode::UpdatedAt),
+                dispatch_token_secret: None,
+            })
+            .await
+            .unwrap()
+            .config;
+
+        // 已禁用的项目同样可以更新，启用状态与调度保持不变
+        assert!(!updated.enabled);
+        assert_eq!(updated.request.comparison_mode, ComparisonMode::UpdatedAt);
+        assert_eq!(updated.next_check_at(), Some(next_check_at));
+    }
+
+    #[tokio::test]
+    async fn test_update_project_partial_keeps_other_fields() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let mut req = make_request("rust");
+        req.dispatch_token_secret = Some("OLD_PAT".into());
+        let config = logic.create_project(req).await.unwrap();
+
+        let updated = logic
+            .update_project(UpdateProjectRequest {
+                unique_key: config.unique_key.clone(),
+                time_config: None,
+                comparison_mode: None,
+                dispatch_token_secret: Some("  ".into()),
+            })
+            .await
+            .unwrap()
+            .config;
+
+        // 空字符串清除覆盖的 Secret 名称，其余字段不变
+        assert_eq!(updated.request.dispatch_token_secret, None);
+        assert_eq!(updated.request.comparison_mode, ComparisonMode::PublishedAt);
+        assert_eq!(
+            updated.request.time_config.check_interval,
+            TimeConfig::default().check_interval
+        );
+        assert!(updated.state.is_paused());
+    }
+
+    #[tokio::test]
+    async fn test_update_project_reports_and_audits_changes() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let config = logic.create_project(make_request("rust")).await.unwrap();
+        let mut time_config = config.request.time_config.clone();
+        time_config.check_interval = DurationSecs::from_hours(6);
+
+        let response = logic
+            .update_project(UpdateProjectRequest {
+                unique_key: config.unique_key.clone(),
+                time_config: Some(time_config),
+                // 与当前值相同，不算变化
+                comparison_mode: Some(config.request.comparison_mode),
+                dispatch_token_secret: None,
+            })
+            .await
+            .unwrap();
+
+        let expected = vec![FieldChange {
+            field: "check_interval".into(),
+            old: config.request.time_config.check_interval.to_string(),
+            new: "6h".into(),
+        }];
+        assert_eq!(response.changes, expected);
+
+        let events = logic.audit_log().await.unwrap();
+        let update = events.iter().find(|e| e.action == "update").unwrap();
+        assert_eq!(update.changes, expected);
+    }
+
+    #[tokio::test]
+    async fn test_update_project_not_found() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let result = logic
+            .update_project(UpdateProjectRequest {
+                unique_key: "missing".into(),
+                time_config: None,
+                comparison_mode: None,
+                dispatch_token_secret: None,
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(WatchError {
+                status: WatchErrorStatus::NotFound,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_projects_batch_partial_failure() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let results = logic
+            .create_projects_batch(vec![
+                make_request("rust"),
+                make_request(""),
+                make_request("cargo"),
+            ])
+            .await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0]
+                .as_ref()
+                .unwrap()
+                .request
+                .base_config
+                .upstream_repo,
+            "rust"
+        );
+        assert_eq!(
+            results[1].as_ref().unwrap_err().status,
+            WatchErrorStatus::InvalidInput
+        );
+        assert_eq!(
+            results[2]
+                .as_ref()
+                .unwrap()
+                .request
+                .base_config
+                .upstream_repo,
+            "cargo"
+        );
+
+        // 失败项不影响其余项的注册
+        assert_eq!(registry.list().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_create_projects_batch_duplicate_conflicts() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let results = logic
+            .create_projects_batch(vec![make_request("rust"), make_request("rust")])
+            .await;
+
+        assert!(results[0].is_ok());
+        assert_eq!(
+            results[1].as_ref().unwrap_err().status,
+            WatchErrorStatus::Conflict
+        );
+        assert_eq!(registry.list().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_projects_batch_staggers_initial_delay() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry).with_batch_stagger(DurationSecs::from_secs(30));
+
+        let mut reqs: Vec<_> = ["rust", "cargo", "clippy"]
+            .into_iter()
+            .map(|repo| CreateProjectRequest {
+                initial_delay: DurationSecs::from_secs(0),
+                ..make_request(repo)
+            })
+            .collect();
+        // 调用方指定的延迟保持不变
+        reqs.push(make_request("miri"));
+
+        let delays: Vec<u64> = logic
+            .create_projects_batch(reqs)
+            .await
+            .into_iter()
+            .map(|r| r.unwrap().request.initial_delay.as_secs())
+            .collect();
+        assert_eq!(delays, [0, 30, 60, 60]);
+    }
+
+    #[tokio::test]
+    async fn test_import_staggers_initial_delay() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let projects = ["rust", "cargo", "clippy"]
+            .into_iter()
+            .map(|repo| {
+                ProjectConfig::new(CreateProjectRequest {
+                    initial_delay: DurationSecs::from_secs(0),
+                    ..make_request(repo)
+                })
+            })
+            .collect();
+        let report = logic
+            .import_projects(ConfigBackup {
+                version: BACKUP_FORMAT_VERSION,
+                projects,
+            })
+            .await
+            .unwrap();
+        assert_eq!(report.imported.len(), 3);
+
+        let mut delays: Vec<(String, u64)> = registry
+            .list()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| {
+                (
+                    c.request.base_config.upstream_repo,
+                    c.request.initial_delay.as_secs(),
+                )
+            })
+            .collect();
+        delays.sort_by_key(|(_, delay)| *delay);
+        let step = DEFAULT_BATCH_STAGGER.as_secs();
+        assert_eq!(
+            delays,
+            [
+                ("rust".to_string(), 0),
+                ("cargo".to_string(), step),
+                ("clippy".to_string(), 2 * step),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_check_ok() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let status = logic.health_check().await;
+        assert!(status.ok);
+        assert!(status.registry);
+        assert!(status.checked_at.as_millis() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_history() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let config = logic.create_project(make_request("rust")).await.unwrap();
+        let event = CheckEvent::now(verwatch_shared::CheckOutcome::NoChange, "Up to date");
+        registry
+            .histories
+            .borrow_mut()
+            .insert(config.unique_key.clone(), vec![event.clone()]);
+
+        let history = logic.get_history(config.unique_key).await.unwrap();
+        assert_eq!(history, vec![event]);
+
+        // 未注册的项目返回 NotFound
+        let result = logic.get_history("missing".into()).await;
+        assert!(matches!(
+            result,
+            Err(WatchError {
+                status: WatchErrorStatus::NotFound,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_delete_all_removes_every_project() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let a = logic.create_project(make_request("rust")).await.unwrap();
+        let b = logic.create_project(make_request("cargo")).await.unwrap();
+        // 已禁用的项目同样会被删除
+        logic
+            .set_enabled(b.unique_key.clone(), false)
+            .await
+            .unwrap();
+
+        let deleted = logic.delete_all(DELETE_ALL_CONFIRMATION).await.unwrap();
+        assert_eq!(deleted, 2);
+        assert!(registry.monitors.borrow().is_empty());
+        for key in [a.unique_key, b.unique_key] {
+            assert!(!registry.is_registered(&key).await.unwrap());
+        }
+
+        // 再次执行时没有可删除的项目
+        assert_eq!(logic.delete_all(DELETE_ALL_CONFIRMATION).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_delete_all_requires_confirmation() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+        logic.create_project(make_request("rust")).await.unwrap();
+
+        for confirm in ["", "delete-all", "yes"] {
+            let err = logic.delete_all(confirm).await.unwrap_err();
+            assert_eq!(err.status, WatchErrorStatus::InvalidInput);
+        }
+        assert_eq!(registry.monitors.borrow().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_switch_all_resumes_only_paused_projects() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let a = logic.create_project(make_request("rust")).await.unwrap();
+        let b = logic.create_project(make_request("cargo")).await.unwrap();
+        logic
+            .switch_monitor(a.unique_key.clone(), true)
+            .await
+            .unwrap();
+        logic
+            .switch_monitor(b.unique_key.clone(), false)
+            .await
+            .unwrap();
+
+        let report = logic.switch_all(false).await.unwrap();
+        assert!(report.failed_keys.is_empty());
+        let applied: std::collections::HashMap<_, _> = report.results.into_iter().collect();
+        assert!(applied[&a.unique_key]);
+        assert!(!applied[&b.unique_key]);
+        assert!(
+            registry
+                .monitors
+                .borrow()
+                .values()
+                .all(|c| !c.state.is_paused())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_project_found() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let created = logic.create_project(make_request("rust")).await.unwrap();
+        logic.create_project(make_request("cargo")).await.unwrap();
+
+        let project = logic.get_project(created.unique_key.clone()).await.unwrap();
+        assert_eq!(project.unique_key, created.unique_key);
+        assert_eq!(project.request.base_config.upstream_repo, "rust");
+    }
+
+    #[tokio::test]
+    async fn test_get_project_not_found() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let err = logic.get_project("missing".into()).await.unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::NotFound);
+        assert_eq!(err.status_code(), 404);
+    }
+
+    #[tokio::test]
+    async fn test_create_project_invalid_tag_pattern() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        let mut req = make_request("rust");
+        req.tag_pattern = Some("^v(\\d+".into());
+        let result = logic.create_project(req).await;
+        assert!(matches!(
+            result,
+            Err(WatchError {
+                status: WatchErrorStatus::InvalidInput,
+                ..
+            })
+        ));
+        assert!(registry.list().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_project_rejects_zero_interval() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+        let config = logic.create_project(make_request("rust")).await.unwrap();
+
+        let err = logic
+            .update_project(UpdateProjectRequest {
+                unique_key: config.unique_key.clone(),
+                time_config: Some(TimeConfig {
+                    check_interval: DurationSecs::from_secs(0),
+                    ..TimeConfig::default()
+                }),
+                comparison_mode: None,
+                dispatch_token_secret: None,
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::InvalidInput);
+        assert!(err.to_string().contains("check_interval"));
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_filter_by_substring() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        logic.create_project(make_request("rust")).await.unwrap();
+        logic.create_project(make_request("Cargo")).await.unwrap();
+
+        let all = logic
+            .list_projects(&ProjectFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 2);
+
+        // 不区分大小写匹配上游
+        let filter = ProjectFilter {
+            q: Some("rust-lang/CAR".into()),
+            ..Default::default()
+        };
+        let result = logic.list_projects(&filter).await.unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].request.base_config.upstream_repo, "Cargo");
+
+        // 匹配下游
+        let filter = ProjectFilter {
+            q: Some("me/mirror".into()),
+            ..Default::default()
+        };
+        assert_eq!(logic.list_projects(&filter).await.unwrap().len(), 2);
+
+        let filter = ProjectFilter {
+            q: Some("nothing".into()),
+            ..Default::default()
+        };
+        assert!(logic.list_projects(&filter).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_paged_follows_cursor() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+        for repo in ["a", "b", "c"] {
+            logic.create_project(make_request(repo)).await.unwrap();
+        }
+
+        let filter = ProjectFilter::default();
+        let first = logic.list_projects_paged(&filter, None, 2).await.unwrap();
+        assert_eq!(first.items.len(), 2);
+        let cursor = first.next_cursor.expect("should have next page");
+
+        let second = logic
+            .list_projects_paged(&filter, Some(&cursor), 2)
+            .await
+            .unwrap();
+        assert_eq!(second.items.len(), 1);
+        assert!(second.next_cursor.is_none());
+
+        let mut all: Vec<_> = first
+            .items
+            .iter()
+            .chain(&second.items)
+            .map(|c| c.request.base_config.upstream_repo.clone())
+            .collect();
+        all.sort();
+        assert_eq!(all, ["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_filter_by_paused() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+
+        logic.create_project(make_request("rust")).await.unwrap();
+        let running = logic.create_project(make_request("cargo")).await.unwrap();
+        logic
+            .switch_monitor(running.unique_key.clone(), false)
+            .await
+            .unwrap();
+
+        let paused = ProjectFilter {
+            paused: Some(true),
+            ..Default::default()
+        };
+        let result = logic.list_projects(&paused).await.unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].request.base_config.upstream_repo, "rust");
+
+        let not_paused = ProjectFilter {
+            q: Some("cargo".into()),
+            paused: Some(false),
+        };
+        let result = logic.list_projects(&not_paused).await.unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].unique_key, running.unique_key);
+    }
+
+    #[tokio::test]
+    async fn test_check_rate_limit_returns_429_when_exhausted() {
+        let registry = MockRegistry::new();
+        let logic = AdminLogic::new(&registry);
+        let limit = RateLimit {
+            capacity: 2,
+            refill_per_minute: 1,
+        };
+
+        logic.check_rate_limit("ci", &limit).await.unwrap();
+        logic.check_rate_limit("ci", &limit).await.unwrap();
+        let err = logic.check_rate_limit("ci", &limit).await.unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::RateLimited);
+        assert_eq!(err.status_code(), 429);
+
+        // 不同标签使用独立的令牌桶
+        logic.check_rate_limit("alice", &limit).await.unwrap();
+    }
 }
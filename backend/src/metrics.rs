@@ -0,0 +1,176 @@
+use std::fmt::Write;
+use verwatch_shared::{ProjectConfig, Timestamp};
+
+// =========================================================
+// Prometheus 文本格式指标
+// =========================================================
+
+/// 指标响应的 Content-Type (Prometheus text exposition format 0.0.4)
+pub(crate) const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// 将项目列表渲染为 Prometheus 文本格式
+///
+/// 只使用配置中的状态字段，与统计接口一样不额外请求各项目的 Monitor；
+/// `verwatch_next_check_seconds` 仅包含运行中的项目，已过期时为负数
+pub(crate) fn render(projects: &[ProjectConfig], now: Timestamp) -> String {
+    let paused = projects.iter().filter(|c| !c.is_running()).count();
+    let failed: u64 = projects.iter().map(|c| c.failed_checks).sum();
+
+    let mut out = String::new();
+    write_header(
+        &mut out,
+        "verwatch_projects_total",
+        "gauge",
+        "Number of enabled projects",
+    );
+    let _ = writeln!(out, "verwatch_projects_total {}", projects.len());
+
+    write_header(
+        &mut out,
+        "verwatch_projects_paused",
+        "gauge",
+        "Number of enabled projects that are paused",
+    );
+    let _ = writeln!(out, "verwatch_projects_paused {}", paused);
+
+    write_header(
+        &mut out,
+        "verwatch_checks_failed",
+        "gauge",
+        "Failed checks recorded by the currently enabled projects",
+    );
+    let _ = writeln!(out, "verwatch_checks_failed {}", failed);
+
+    write_header(
+        &mut out,
+        "verwatch_next_check_seconds",
+        "gauge",
+        "Seconds until the next scheduled check (negative when overdue)",
+    );
+    let mut running: Vec<(&str, Timestamp)> = projects
+        .iter()
+        .filter_map(|c| Some((c.unique_key.as_str(), c.next_check_at()?)))
+        .collect();
+    running.sort_by_key(|(key, _)| *key);
+    for (key, next_check_at) in running {
+        let _ = writeln!(
+            out,
+            "verwatch_next_check_seconds{{project=\"{}\"}} {}",
+            escape_label(key),
+            next_check_at.signed_diff_secs(now)
+        );
+    }
+
+    out
+}
+
+fn write_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+/// 按文本格式转义标签值中的反斜杠、双引号与换行
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use verwatch_shared::{BaseConfig, CreateProjectRequest, MonitorState};
+
+    fn project(repo: &str, state: MonitorState, failed_checks: u64) -> ProjectConfig {
+        let mut config = ProjectConfig::new(CreateProjectRequest {
+            base_config: BaseConfig {
+                upstream_owner: "owner".into(),
+                upstream_repo: repo.into(),
+                my_owner: "me".into(),
+                my_repo: "fork".into(),
+            },
+            ..Default::default()
+        });
+        config.state = state;
+        config.failed_checks = failed_checks;
+        config
+    }
+
+    /// 解析非注释行为 (指标名含标签, 数值)
+    fn samples(text: &str) -> Vec<(String, f64)> {
+        text.lines()
+            .filter(|line| !line.starts_with('#'))
+            .map(|line| {
+                let (name, value) = line.rsplit_once(' ').expect("key/value line");
+                (name.to_string(), value.parse().expect("numeric value"))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_render_contains_expected_metrics() {
+        let now = Timestamp::new(1_000_000);
+        let projects = vec![
+            project("a", MonitorState::running(Timestamp::new(1_060_000)), 2),
+            project("b", MonitorState::running(Timestamp::new(970_000)), 0),
+            project("c", MonitorState::Paused, 3),
+        ];
+
+        let text = render(&projects, now);
+        for name in [
+            "verwatch_projects_total",
+            "verwatch_projects_paused",
+            "verwatch_checks_failed",
+            "verwatch_next_check_seconds",
+        ] {
+            assert!(
+                text.contains(&format!("# TYPE {} ", name)),
+                "missing {}",
+                name
+            );
+        }
+
+        let samples = samples(&text);
+        assert!(samples.contains(&("verwatch_projects_total".into(), 3.0)));
+        assert!(samples.contains(&("verwatch_projects_paused".into(), 1.0)));
+        assert!(samples.contains(&("verwatch_checks_failed".into(), 5.0)));
+        // 失败次数是当前已启用项目的汇总，禁用/删除项目后会下降，不能声明为 counter
+        assert!(text.contains("# TYPE verwatch_checks_failed gauge"));
+        assert!(samples.contains(&(
+            format!(
+                "verwatch_next_check_seconds{{project=\"{}\"}}",
+                projects[0].unique_key
+            ),
+            60.0
+        )));
+        assert!(samples.contains(&(
+            format!(
+                "verwatch_next_check_seconds{{project=\"{}\"}}",
+                projects[1].unique_key
+            ),
+            -30.0
+        )));
+        // 暂停的项目没有下一次检查时间
+        assert_eq!(
+            samples
+                .iter()
+                .filter(|(name, _)| name.starts_with("verwatch_next_check_seconds"))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_render_empty_fleet() {
+        let samples = samples(&render(&[], Timestamp::new(0)));
+        assert_eq!(samples.len(), 3);
+        assert!(samples.iter().all(|(_, value)| *value == 0.0));
+    }
+
+    #[test]
+    fn test_escape_label() {
+        assert_eq!(escape_label(r#"a"b\c"#), r#"a\"b\\c"#);
+        assert_eq!(escape_label("a\nb"), "a\\nb");
+    }
+}
@@ -0,0 +1,648 @@
+use serde_json::{Map, Value, json};
+use verwatch_shared::protocol::{
+    ApiRequest, AuditRequest, ConfigBackup, DELETE_ALL_CONFIRMATION, DeleteAllRequest,
+    ExportRequest, ForceDispatchRequest, GetAuditLogRequest, GetDeadLetterRequest, GetFeedRequest,
+    GetHistoryRequest, GetProjectRequest, HealthCheckRequest, HttpMethod,
+    ListDetailedProjectsRequest, ListProjectsRequest, PopProjectRequest, ProjectStatsRequest,
+    RescheduleRequest, SetEnabledRequest, SwitchAllRequest, SwitchMonitorRequest,
+    TriggerCheckRequest, UpdateProjectRequest, VersionRequest, WhoAmIRequest,
+};
+use verwatch_shared::{CreateProjectRequest, DeleteTarget, HEADER_AUTH_KEY};
+
+// =========================================================
+// OpenAPI 描述文档
+// =========================================================
+
+/// 一个 API 操作的描述
+///
+/// 路径与方法尽量取自 `shared` 中对应请求类型的 `ApiRequest` 常量，
+/// 保证文档与前后端使用的定义一致
+struct Operation {
+    method: HttpMethod,
+    path: &'static str,
+    summary: &'static str,
+    /// 是否需要 `X-Auth-Key`
+    auth: bool,
+    /// 查询参数 (名称, 是否必填)
+    query: &'static [(&'static str, bool)],
+    /// 请求体 Schema
+    body: Option<Value>,
+    /// 成功响应 Schema
+    response: Value,
+}
+
+impl Operation {
+    fn of<T: ApiRequest>(summary: &'static str) -> Self {
+        Self {
+            method: T::METHOD,
+            path: T::PATH,
+            summary,
+            auth: true,
+            query: &[],
+            body: None,
+            response: json!({}),
+        }
+    }
+
+    fn public(mut self) -> Self {
+        self.auth = false;
+        self
+    }
+
+    fn query(mut self, query: &'static [(&'static str, bool)]) -> Self {
+        self.query = query;
+        self
+    }
+
+    fn body(mut self, schema: Value) -> Self {
+        self.body = Some(schema);
+        self
+    }
+
+    fn response(mut self, schema: Value) -> Self {
+        self.response = schema;
+        self
+    }
+
+    fn to_json(&self) -> Value {
+        let mut op = json!({
+            "summary": self.summary,
+            "responses": {
+                "200": {
+                    "description": "OK",
+                    "content": { "application/json": { "schema": self.response } }
+                },
+                "4XX": {
+                    "description": "Client error",
+                    "content": { "application/json": { "schema": schema_ref("ErrorResponse") } }
+                },
+                "5XX": {
+                    "description": "Server error (details are only logged)",
+                    "content": { "application/json": { "schema": schema_ref("ErrorResponse") } }
+                }
+            }
+        });
+        if self.auth {
+            op["security"] = json!([{ "AdminKey": [] }]);
+        }
+        if !self.query.is_empty() {
+            op["parameters"] = self
+                .query
+                .iter()
+                .map(|(name, required)| {
+                    json!({
+                        "name": name,
+                        "in": "query",
+                        "required": required,
+                        "schema": { "type": "string" }
+                    })
+                })
+                .collect();
+        }
+        if let Some(body) = &self.body {
+            op["requestBody"] = json!({
+                "required": true,
+                "content": { "application/json": { "schema": body } }
+            });
+        }
+        op
+    }
+}
+
+fn method_key(method: HttpMethod) -> &'static str {
+    match method {
+        HttpMethod::Get => "get",
+        HttpMethod::Post => "post",
+        HttpMethod::Put => "put",
+        HttpMethod::Delete => "delete",
+        HttpMethod::Patch => "patch",
+    }
+}
+
+fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{}", name) })
+}
+
+fn array_of(item: Value) -> Value {
+    json!({ "type": "array", "items": item })
+}
+
+fn object(required: &[&str], properties: Value) -> Value {
+    json!({ "type": "object", "required": required, "properties": properties })
+}
+
+/// 所有公开的 API 操作
+fn operations() -> Vec<Operation> {
+    vec![
+        Operation::of::<HealthCheckRequest>(
+            "Health / readiness check (an optional X-Auth-Key is verified, 401 when invalid)",
+        )
+        .public()
+        .response(schema_ref("HealthStatus")),
+        Operation::of::<VersionRequest>("Build information of the deployed Worker")
+            .public()
+            .response(schema_ref("BuildInfo")),
+        Operation::of::<ListProjectsRequest>("List projects")
+            .query(&[
+                ("q", false),
+                ("paused", false),
+                ("limit", false),
+                ("cursor", false),
+            ])
+            .response(json!({
+                "description": "Plain array, or a ProjectPage when `limit` / `cursor` is given",
+                "oneOf": [array_of(schema_ref("ProjectConfig")), schema_ref("ProjectPage")]
+            })),
+        Operation::of::<CreateProjectRequest>("Create a project")
+            .body(schema_ref("CreateProjectRequest"))
+            .response(schema_ref("ProjectConfig")),
+        Operation {
+            path: "/api/projects/batch",
+            ..Operation::of::<CreateProjectRequest>("Create several projects")
+        }
+        .body(array_of(schema_ref("CreateProjectRequest")))
+        .response(array_of(json!({
+            "oneOf": [
+                object(&["Ok"], json!({ "Ok": schema_ref("ProjectConfig") })),
+                object(&["Err"], json!({ "Err": schema_ref("ErrorResponse") }))
+            ]
+        }))),
+        Operation::of::<UpdateProjectRequest>("Update the mutable fields of a project")
+            .body(schema_ref("UpdateProjectRequest"))
+            .response(json!({
+                "description": "The updated ProjectConfig with the changed fields",
+                "allOf": [
+                    schema_ref("ProjectConfig"),
+                    object(&["changes"], json!({ "changes": array_of(schema_ref("FieldChange")) }))
+                ]
+            })),
+        Operation::of::<DeleteTarget>("Delete a project")
+            .body(object(&["id"], json!({ "id": { "type": "string" } })))
+            .response(json!({ "type": "boolean" })),
+        Operation::of::<PopProjectRequest>("Delete a project and return its config")
+            .body(object(&["id"], json!({ "id": { "type": "string" } })))
+            .response(json!({ "oneOf": [schema_ref("ProjectConfig"), { "type": "null" }] })),
+        Operation::of::<DeleteAllRequest>("Delete every project (requires confirmation)")
+            .body(object(
+                &["confirm"],
+                json!({ "confirm": { "type": "string", "const": DELETE_ALL_CONFIRMATION } }),
+            ))
+            .response(json!({ "type": "integer", "minimum": 0 })),
+        Operation::of::<ListDetailedProjectsRequest>(
+            "List enabled projects with their recorded version state",
+        )
+        .response(array_of(schema_ref("ProjectWithState"))),
+        Operation::of::<GetProjectRequest>("Get a single project")
+            .query(&[("id", true)])
+            .response(schema_ref("ProjectConfig")),
+        Operation::of::<SwitchMonitorRequest>("Pause or resume a project")
+            .body(object(
+                &["unique_key", "paused"],
+                json!({
+                    "unique_key": { "type": "string" },
+                    "paused": { "type": "boolean" }
+                }),
+            ))
+            .response(json!({ "type": "boolean" })),
+        Operation::of::<SwitchAllRequest>("Pause or resume every project")
+            .body(object(
+                &["paused"],
+                json!({ "paused": { "type": "boolean" } }),
+            ))
+            .response(schema_ref("SwitchAllReport")),
+        Operation::of::<SetEnabledRequest>("Enable or disable a project")
+            .body(object(
+                &["unique_key", "enabled"],
+                json!({
+                    "unique_key": { "type": "string" },
+                    "enabled": { "type": "boolean" }
+                }),
+            ))
+            .response(json!({ "type": "boolean" })),
+        Operation::of::<TriggerCheckRequest>("Run a check right away")
+            .body(object(
+                &["unique_key"],
+                json!({ "unique_key": { "type": "string" } }),
+            ))
+            .response(json!({ "type": "boolean" })),
+        Operation::of::<ForceDispatchRequest>(
+            "Dispatch the latest release without comparing versions",
+        )
+        .body(object(
+            &["unique_key"],
+            json!({ "unique_key": { "type": "string" } }),
+        ))
+        .response(json!({ "type": "string" })),
+        Operation::of::<RescheduleRequest>("Move the next check of a running project")
+            .body(object(
+                &["unique_key", "next_check_at"],
+                json!({
+                    "unique_key": { "type": "string" },
+                    "next_check_at": schema_ref("Timestamp")
+                }),
+            ))
+            .response(json!({ "type": "boolean" })),
+        Operation::of::<AuditRequest>("Re-arm lost alarms of running projects")
+            .response(array_of(json!({ "type": "string" }))),
+        Operation::of::<ProjectStatsRequest>("Aggregate counters over enabled projects")
+            .response(schema_ref("ProjectStats")),
+        Operation::of::<GetHistoryRequest>("Recent check history (newest last)")
+            .query(&[("unique_key", true)])
+            .response(array_of(schema_ref("CheckEvent"))),
+        Operation::of::<ExportRequest>("Export all projects").response(schema_ref("ConfigBackup")),
+        Operation::of::<ConfigBackup>("Import projects from a backup")
+            .body(schema_ref("ConfigBackup"))
+            .response(schema_ref("ImportReport")),
+        Operation::of::<GetAuditLogRequest>("Audit trail of admin mutations (oldest first)")
+            .response(array_of(schema_ref("AuditEvent"))),
+        Operation::of::<GetDeadLetterRequest>(
+            "Registrations that failed during monitor setup (oldest first)",
+        )
+        .response(array_of(schema_ref("DeadLetter"))),
+        Operation::of::<GetFeedRequest>(
+            "Latest recorded version of every enabled project (accepts the feed token)",
+        )
+        .response(array_of(schema_ref("FeedEntry"))),
+        Operation::of::<WhoAmIRequest>("Role of the presented key (accepts the viewer key)")
+            .response(schema_ref("SessionInfo")),
+        Operation {
+            path: OPENAPI_PATH,
+            ..Operation::of::<HealthCheckRequest>("This document")
+        }
+        .response(json!({ "type": "object" })),
+    ]
+}
+
+/// 与 `shared` 中数据结构对应的 Schema
+fn schemas() -> Value {
+    let duration = json!({ "type": "integer", "minimum": 0, "description": "Seconds" });
+    json!({
+        "Timestamp": { "type": "integer", "description": "Unix time in milliseconds" },
+        "BaseConfig": object(
+            &["upstream_owner", "upstream_repo", "my_owner", "my_repo"],
+            json!({
+                "upstream_owner": { "type": "string" },
+                "upstream_repo": { "type": "string" },
+                "my_owner": { "type": "string" },
+                "my_repo": { "type": "string" }
+            }),
+        ),
+        "TimeConfig": object(
+            &["check_interval", "retry_interval"],
+            json!({
+                "check_interval": duration,
+                "retry_interval": duration,
+                "jitter_secs": duration
+            }),
+        ),
+        "ComparisonMode": { "type": "string", "enum": ["published_at", "updated_at", "created_at"] },
+        "CreateProjectRequest": object(
+            &["base_config", "time_config", "initial_delay", "comparison_mode"],
+            json!({
+                "base_config": schema_ref("BaseConfig"),
+                "time_config": schema_ref("TimeConfig"),
+                "initial_delay": duration,
+                "dispatch_token_secret": { "type": ["string", "null"] },
+                "comparison_mode": schema_ref("ComparisonMode"),
+                "provider": {
+                    "oneOf": [
+                        { "type": "string", "enum": ["github", "gitlab"] },
+                        object(
+                            &["gitea"],
+                            json!({
+                                "gitea": object(
+                                    &["base_url"],
+                                    json!({ "base_url": { "type": "string" } }),
+                                )
+                            }),
+                        )
+                    ]
+                },
+                "source_kind": { "type": "string", "enum": ["releases", "tags"] },
+                "tag_pattern": { "type": "string" },
+                "version_constraint": { "type": "string" },
+                "strip_tag_prefix": { "type": "boolean" },
+                "notify_webhook": { "type": "string" },
+                "slack_webhook": { "type": "string" },
+                "telegram": object(
+                    &["bot_token_secret", "chat_id"],
+                    json!({
+                        "bot_token_secret": { "type": "string" },
+                        "chat_id": { "type": "string" }
+                    }),
+                ),
+                "webhook": object(
+                    &["url", "template"],
+                    json!({
+                        "url": { "type": "string" },
+                        "template": { "type": "string" }
+                    }),
+                ),
+                "dry_run": { "type": "boolean" },
+                "initialize_baseline": { "type": "boolean" },
+                "quiet_hours": {
+                    "type": "array",
+                    "items": { "type": "integer", "minimum": 0, "maximum": 23 },
+                    "minItems": 2,
+                    "maxItems": 2
+                }
+            }),
+        ),
+        "UpdateProjectRequest": object(
+            &["unique_key"],
+            json!({
+                "unique_key": { "type": "string" },
+                "time_config": schema_ref("TimeConfig"),
+                "comparison_mode": schema_ref("ComparisonMode"),
+                "dispatch_token_secret": { "type": "string" }
+            }),
+        ),
+        "ProjectConfig": {
+            "description": "CreateProjectRequest fields are flattened into this object",
+            "allOf": [
+                schema_ref("CreateProjectRequest"),
+                object(
+                    &["unique_key", "state"],
+                    json!({
+                        "unique_key": { "type": "string" },
+                        "state": schema_ref("MonitorState"),
+                        "enabled": { "type": "boolean" },
+                        "last_dispatch_at": schema_ref("Timestamp"),
+                        "last_outcome": { "type": "string", "enum": ["success", "no_change", "error"] },
+                        "failed_checks": { "type": "integer", "minimum": 0 }
+                    }),
+                )
+            ]
+        },
+        "MonitorState": object(
+            &["status"],
+            json!({
+                "status": { "type": "string", "enum": ["paused", "running"] },
+                "next_check_at": schema_ref("Timestamp")
+            }),
+        ),
+        "ProjectPage": object(
+            &["items"],
+            json!({
+                "items": array_of(schema_ref("ProjectConfig")),
+                "next_cursor": { "type": "string" },
+                "failed_keys": array_of(json!({ "type": "string" }))
+            }),
+        ),
+        "ErrorResponse": object(
+            &["status", "message"],
+            json!({
+                "status": { "type": "string" },
+                "message": { "type": "string" },
+                "spans": array_of(object(
+                    &["operation"],
+                    json!({
+                        "operation": { "type": "string" },
+                        "detail": { "type": "string" }
+                    }),
+                ))
+            }),
+        ),
+        "CheckEvent": object(
+            &["at", "outcome", "detail"],
+            json!({
+                "at": schema_ref("Timestamp"),
+                "outcome": { "type": "string", "enum": ["success", "no_change", "error"] },
+                "detail": { "type": "string" }
+            }),
+        ),
+        "AuditEvent": object(
+            &["at", "actor", "action", "target"],
+            json!({
+                "at": schema_ref("Timestamp"),
+                "actor": { "type": "string", "description": "Label of the admin key" },
+                "action": { "type": "string" },
+                "target": { "type": "string" },
+                "changes": array_of(schema_ref("FieldChange"))
+            }),
+        ),
+        "FieldChange": object(
+            &["field", "old", "new"],
+            json!({
+                "field": { "type": "string" },
+                "old": { "type": "string" },
+                "new": { "type": "string" }
+            }),
+        ),
+        "DeadLetter": object(
+            &["at", "unique_key", "error"],
+            json!({
+                "at": schema_ref("Timestamp"),
+                "unique_key": { "type": "string" },
+                "error": { "type": "string", "description": "Error message including its cause chain" }
+            }),
+        ),
+        "ProjectWithState": object(
+            &["config"],
+            json!({
+                "config": schema_ref("ProjectConfig"),
+                "current_version": { "type": ["string", "null"] },
+                "checked_at": {
+                    "oneOf": [schema_ref("Timestamp"), { "type": "null" }],
+                    "description": "Time of the most recent check"
+                }
+            }),
+        ),
+        "FeedEntry": object(
+            &["upstream", "current_version", "checked_at"],
+            json!({
+                "upstream": { "type": "string", "description": "owner/repo" },
+                "current_version": { "type": ["string", "null"] },
+                "checked_at": {
+                    "oneOf": [schema_ref("Timestamp"), { "type": "null" }],
+                    "description": "Time of the most recent check"
+                }
+            }),
+        ),
+        "HealthStatus": object(
+            &["ok", "registry", "checked_at"],
+            json!({
+                "ok": { "type": "boolean" },
+                "registry": { "type": "boolean" },
+                "checked_at": schema_ref("Timestamp")
+            }),
+        ),
+        "BuildInfo": object(
+            &["name", "version"],
+            json!({
+                "name": { "type": "string" },
+                "version": { "type": "string" },
+                "git_sha": { "type": ["string", "null"] },
+                "built_at": { "type": ["string", "null"] }
+            }),
+        ),
+        "SessionInfo": object(
+            &["role"],
+            json!({
+                "role": { "type": "string", "enum": ["admin", "viewer"] }
+            }),
+        ),
+        "ProjectStats": object(
+            &["total", "running", "paused", "due_within_5m", "last_error_count"],
+            json!({
+                "total": { "type": "integer" },
+                "running": { "type": "integer" },
+                "paused": { "type": "integer" },
+                "due_within_5m": { "type": "integer" },
+                "last_error_count": { "type": "integer" }
+            }),
+        ),
+        "ConfigBackup": object(
+            &["version", "projects"],
+            json!({
+                "version": { "type": "integer" },
+                "projects": array_of(schema_ref("ProjectConfig"))
+            }),
+        ),
+        "SwitchAllReport": object(
+            &["results"],
+            json!({
+                "results": array_of(json!({
+                    "type": "array",
+                    "prefixItems": [{ "type": "string" }, { "type": "boolean" }]
+                })),
+                "failed_keys": array_of(json!({ "type": "string" }))
+            }),
+        ),
+        "ImportReport": object(
+            &["imported", "conflicts", "failed"],
+            json!({
+                "imported": array_of(json!({ "type": "string" })),
+                "conflicts": array_of(json!({ "type": "string" })),
+                "failed": array_of(array_of(json!({ "type": "string" })))
+            }),
+        )
+    })
+}
+
+/// OpenAPI 文档的访问路径
+pub(crate) const OPENAPI_PATH: &str = "/api/openapi.json";
+
+/// 生成 OpenAPI 3 文档
+pub(crate) fn document() -> Value {
+    let mut paths = Map::new();
+    for op in operations() {
+        let entry = paths
+            .entry(op.path)
+            .or_insert_with(|| Value::Object(Map::new()));
+        entry[method_key(op.method)] = op.to_json();
+    }
+
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "VerWatch Admin API",
+            "version": env!("CARGO_PKG_VERSION")
+        },
+        "paths": paths,
+        "components": {
+            "schemas": schemas(),
+            "securitySchemes": {
+                "AdminKey": { "type": "apiKey", "in": "header", "name": HEADER_AUTH_KEY }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect_refs(value: &Value, out: &mut Vec<String>) {
+        match value {
+            Value::Object(map) => {
+                if let Some(Value::String(r)) = map.get("$ref") {
+                    out.push(r.clone());
+                }
+                map.values().for_each(|v| collect_refs(v, out));
+            }
+            Value::Array(items) => items.iter().for_each(|v| collect_refs(v, out)),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_document_is_valid_json() {
+        let text = serde_json::to_string(&document()).unwrap();
+        let parsed: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(parsed["openapi"], "3.1.0");
+        assert_eq!(
+            parsed["components"]["securitySchemes"]["AdminKey"]["name"],
+            "X-Auth-Key"
+        );
+    }
+
+    #[test]
+    fn test_document_lists_all_routes() {
+        let doc = document();
+        let routes = [
+            ("get", "/api/health"),
+            ("get", "/api/version"),
+            ("get", "/api/projects"),
+            ("post", "/api/projects"),
+            ("post", "/api/projects/batch"),
+            ("put", "/api/projects"),
+            ("delete", "/api/projects"),
+            ("delete", "/api/projects/pop"),
+            ("delete", "/api/projects/all"),
+            ("get", "/api/projects/one"),
+            ("get", "/api/projects/detailed"),
+            ("post", "/api/projects/switch"),
+            ("post", "/api/projects/switch-all"),
+            ("post", "/api/projects/enabled"),
+            ("post", "/api/projects/trigger"),
+            ("post", "/api/projects/force-dispatch"),
+            ("post", "/api/projects/reschedule"),
+            ("post", "/api/projects/audit"),
+            ("get", "/api/projects/stats"),
+            ("get", "/api/projects/history"),
+            ("get", "/api/export"),
+            ("post", "/api/import"),
+            ("get", "/api/audit"),
+            ("get", "/api/deadletter"),
+            ("get", "/api/whoami"),
+            ("get", "/api/feed"),
+            ("get", "/api/openapi.json"),
+        ];
+        for (method, path) in routes {
+            assert!(
+                doc["paths"][path][method].is_object(),
+                "missing {} {}",
+                method,
+                path
+            );
+        }
+        let documented: usize = doc["paths"]
+            .as_object()
+            .unwrap()
+            .values()
+            .map(|p| p.as_object().unwrap().len())
+            .sum();
+        assert_eq!(documented, routes.len());
+
+        // 健康检查与文档本身无需鉴权
+        assert!(doc["paths"]["/api/health"]["get"]["security"].is_null());
+        assert!(doc["paths"]["/api/projects"]["get"]["security"].is_array());
+    }
+
+    #[test]
+    fn test_all_schema_refs_resolve() {
+        let doc = document();
+        let mut refs = Vec::new();
+        collect_refs(&doc, &mut refs);
+        assert!(!refs.is_empty());
+        for r in refs {
+            let name = r.trim_start_matches("#/components/schemas/");
+            assert!(
+                doc["components"]["schemas"][name].is_object(),
+                "unresolved {}",
+                r
+            );
+        }
+    }
+}
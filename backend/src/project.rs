@@ -1,3 +1,4 @@
 mod adapter;
+mod migration;
 mod monitor;
 pub mod protocol;
@@ -3,6 +3,7 @@ use std::time::Duration;
 use crate::error::WatchResult;
 use async_trait::async_trait;
 use serde::{Serialize, de::DeserializeOwned};
+use verwatch_shared::Date;
 
 /// 抽象存储接口：负责数据的持久化
 #[async_trait(?Send)]
@@ -27,6 +28,8 @@ pub trait AlarmScheduler {
     async fn set_alarm(&self, scheduled_time: Duration) -> WatchResult<()>;
     /// 删除当前的闹钟
     async fn delete_alarm(&self) -> WatchResult<()>;
+    /// 获取当前闹钟距现在的时长，未设置闹钟时返回 `None`
+    async fn get_alarm(&self) -> WatchResult<Option<Duration>>;
 }
 
 pub struct WorkerStorage(pub worker::Storage);
@@ -74,6 +77,20 @@ impl AlarmScheduler for WorkerStorage {
             .await
             .map_err(|e| crate::error::WatchError::from(e).in_op("alarm.delete"))
     }
+
+    async fn get_alarm(&self) -> WatchResult<Option<Duration>> {
+        let scheduled_at = self
+            .0
+            .get_alarm()
+            .await
+            .map_err(|e| crate::error::WatchError::from(e).in_op("alarm.get"))?;
+        Ok(scheduled_at.map(|at| alarm_delay(at, Date::now_timestamp().as_millis())))
+    }
+}
+
+/// 将存储返回的绝对时间戳 (毫秒) 转换为与 `set_alarm` 一致的相对时长，已过期时为 0
+fn alarm_delay(scheduled_at: i64, now: i64) -> Duration {
+    Duration::from_millis(scheduled_at.saturating_sub(now).max(0) as u64)
 }
 
 /// Worker Env 的 EnvAdapter 实现
@@ -101,7 +118,7 @@ pub mod tests {
 
     pub struct MockStorage {
         pub map: RefCell<HashMap<String, String>>,
-        pub alarm: RefCell<Option<Duration>>,
+        alarm: RefCell<Option<Duration>>,
     }
 
     impl MockStorage {
@@ -182,6 +199,10 @@ pub mod tests {
             *self.alarm.borrow_mut() = None;
             Ok(())
         }
+
+        async fn get_alarm(&self) -> WatchResult<Option<Duration>> {
+            Ok(*self.alarm.borrow())
+        }
     }
 
     // =========================================================
@@ -275,13 +296,13 @@ pub mod tests {
         let storage = MockStorage::new();
 
         // 初始状态没有 alarm
-        assert!(storage.alarm.borrow().is_none());
+        assert!(storage.get_alarm().await.unwrap().is_none());
 
         // 设置 alarm
         let duration = Duration::from_secs(60);
         storage.set_alarm(duration).await.unwrap();
 
-        assert_eq!(*storage.alarm.borrow(), Some(duration));
+        assert_eq!(storage.get_alarm().await.unwrap(), Some(duration));
     }
 
     #[tokio::test]
@@ -294,7 +315,15 @@ pub mod tests {
         // 删除 alarm
         storage.delete_alarm().await.unwrap();
 
-        assert!(storage.alarm.borrow().is_none());
+        assert!(storage.get_alarm().await.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_alarm_delay_is_relative_to_now() {
+        assert_eq!(alarm_delay(61_000, 1_000), Duration::from_secs(60));
+        assert_eq!(alarm_delay(1_000, 1_000), Duration::ZERO);
+        // 已过期但尚未触发的 alarm
+        assert_eq!(alarm_delay(500, 1_000), Duration::ZERO);
     }
 
     #[tokio::test]
@@ -308,6 +337,6 @@ pub mod tests {
         let new_duration = Duration::from_secs(120);
         storage.set_alarm(new_duration).await.unwrap();
 
-        assert_eq!(*storage.alarm.borrow(), Some(new_duration));
+        assert_eq!(storage.get_alarm().await.unwrap(), Some(new_duration));
     }
 }
@@ -0,0 +1,153 @@
+use serde::Deserialize;
+use verwatch_shared::{
+    BaseConfig, ComparisonMode, CreateProjectRequest, DurationSecs, MonitorState, ProjectConfig,
+    TimeConfig, Timestamp,
+};
+
+// =========================================================
+// 旧版存储格式迁移
+// =========================================================
+
+/// 旧版 (v1) 存储的项目配置
+///
+/// 配置字段位于 `base` 下，用 `paused: bool` 表示暂停，且没有 `state` / `enabled`
+#[derive(Debug, Deserialize)]
+pub(crate) struct LegacyProjectConfig {
+    #[serde(default)]
+    pub unique_key: String,
+    pub base: BaseConfig,
+    #[serde(default)]
+    pub time_config: TimeConfig,
+    #[serde(default)]
+    pub initial_delay: DurationSecs,
+    #[serde(default)]
+    pub dispatch_token_secret: Option<String>,
+    #[serde(default)]
+    pub comparison_mode: ComparisonMode,
+    #[serde(default)]
+    pub paused: bool,
+}
+
+impl LegacyProjectConfig {
+    /// 升级为当前格式
+    ///
+    /// 未暂停的旧版配置视为运行中并在 `now` 立即检查一次，已暂停的保持暂停
+    pub fn upgrade(self, now: Timestamp) -> ProjectConfig {
+        let mut config = ProjectConfig::new(CreateProjectRequest {
+            base_config: self.base,
+            time_config: self.time_config,
+            initial_delay: self.initial_delay,
+            dispatch_token_secret: self.dispatch_token_secret,
+            comparison_mode: self.comparison_mode,
+            ..Default::default()
+        });
+        if !self.unique_key.is_empty() {
+            config.unique_key = self.unique_key;
+        }
+        if !self.paused {
+            config.state = MonitorState::running(now);
+        }
+        config
+    }
+}
+
+/// 存储中的配置：优先按当前格式解析，失败时回退到旧版格式
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum StoredConfig {
+    Current(Box<ProjectConfig>),
+    Legacy(LegacyProjectConfig),
+}
+
+impl StoredConfig {
+    /// 统一为当前格式，返回配置以及是否由旧版升级而来 (需要写回存储)
+    pub fn into_current(self, now: Timestamp) -> (ProjectConfig, bool) {
+        match self {
+            StoredConfig::Current(config) => (*config, false),
+            StoredConfig::Legacy(legacy) => (legacy.upgrade(now), true),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY_JSON: &str = r#"{
+        "base": {
+            "upstream_owner": "owner",
+            "upstream_repo": "repo",
+            "my_owner": "me",
+            "my_repo": "fork"
+        },
+        "time_config": {"check_interval": 7200, "retry_interval": 30},
+        "initial_delay": 0,
+        "dispatch_token_secret": "MY_PAT",
+        "comparison_mode": "updated_at"
+    }"#;
+
+    #[test]
+    fn test_legacy_json_falls_back_and_upgrades() {
+        let stored: StoredConfig = serde_json::from_str(LEGACY_JSON).unwrap();
+        let StoredConfig::Legacy(legacy) = stored else {
+            panic!("expected legacy config");
+        };
+
+        let config = legacy.upgrade(Timestamp::new(1_000));
+        assert_eq!(config.unique_key, "owner/repo->me/fork");
+        assert_eq!(config.state.next_check_at(), Some(Timestamp::new(1_000)));
+        assert!(config.enabled);
+        assert_eq!(
+            config.request.time_config.check_interval,
+            DurationSecs::from_hours(2)
+        );
+        assert_eq!(
+            config.request.dispatch_token_secret.as_deref(),
+            Some("MY_PAT")
+        );
+        assert_eq!(config.request.comparison_mode, ComparisonMode::UpdatedAt);
+    }
+
+    #[test]
+    fn test_legacy_paused_flag_is_kept() {
+        let mut value: serde_json::Value = serde_json::from_str(LEGACY_JSON).unwrap();
+        value["paused"] = serde_json::json!(true);
+        let stored: StoredConfig = serde_json::from_value(value).unwrap();
+
+        let (config, upgraded) = stored.into_current(Timestamp::new(1_000));
+        assert!(upgraded);
+        assert!(config.state.is_paused());
+        assert!(config.enabled);
+    }
+
+    #[test]
+    fn test_current_json_round_trips() {
+        let mut config = ProjectConfig::new(CreateProjectRequest {
+            base_config: BaseConfig {
+                upstream_owner: "owner".into(),
+                upstream_repo: "repo".into(),
+                my_owner: "me".into(),
+                my_repo: "fork".into(),
+            },
+            ..Default::default()
+        });
+        config.state = MonitorState::running(Timestamp::new(5_000));
+        config.enabled = false;
+
+        let json = serde_json::to_string(&config).unwrap();
+        let stored: StoredConfig = serde_json::from_str(&json).unwrap();
+        let (decoded, upgraded) = stored.into_current(Timestamp::new(1_000));
+        assert!(!upgraded);
+        assert_eq!(decoded.unique_key, config.unique_key);
+        assert_eq!(decoded.next_check_at(), Some(Timestamp::new(5_000)));
+        assert!(!decoded.enabled);
+    }
+
+    #[test]
+    fn test_current_json_is_not_treated_as_legacy() {
+        let config = ProjectConfig::new(CreateProjectRequest::default());
+        let json = serde_json::to_string(&config).unwrap();
+        let stored: StoredConfig = serde_json::from_str(&json).unwrap();
+        assert!(matches!(stored, StoredConfig::Current(_)));
+    }
+}
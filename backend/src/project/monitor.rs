@@ -1,26 +1,47 @@
-use crate::error::{WatchError, WatchResult};
+use crate::error::{WatchError, WatchErrorStatus, WatchResult};
 use crate::utils::github::release::GitHubRelease;
 // 引入同目录下的模块
 use super::adapter::{AlarmScheduler, EnvAdapter, StorageAdapter, WorkerEnv, WorkerStorage};
+use super::migration::StoredConfig;
 use super::protocol::*;
 // 引入外部依赖
-use crate::utils::github::gateway::GitHubGateway;
+use crate::utils::budget::{DEFAULT_SUBREQUEST_BUDGET, SubrequestBudget};
+use crate::utils::gitea::GiteaGateway;
+use crate::utils::github::gateway::{DEFAULT_USER_AGENT, GitHubGateway};
+use crate::utils::gitlab::GitLabGateway;
+use crate::utils::logging::{self, LogLevel};
+use crate::utils::notify::{CompositeNotifier, NotifyEvent};
+use crate::utils::provider::ReleaseProvider;
 use crate::utils::request::{HttpClient, WorkerHttpClient};
 use crate::utils::rpc::{ApiRequest, RpcHandler};
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
-use verwatch_shared::{Date, MonitorState, ProjectConfig};
+use verwatch_shared::{
+    CheckEvent, CheckOutcome, ComparisonMode, Date, DurationSecs, MonitorState, ProjectConfig,
+    ProjectWithState, Provider, SourceKind, TimeConfig, Timestamp,
+    protocol::{FeedEntry, UpdateProjectResponse},
+};
 use worker::*;
 
 // =========================================================
 // 条件编译日志宏
 // =========================================================
+// info / warn 受 `LOG_LEVEL` 控制，error 总是输出
 #[cfg(target_arch = "wasm32")]
 macro_rules! log_info {
-    ($($t:tt)*) => (worker::console_log!($($t)*))
+    ($($t:tt)*) => {
+        if crate::utils::logging::enabled(crate::utils::logging::LogLevel::Info) {
+            worker::console_log!($($t)*)
+        }
+    };
 }
 #[cfg(not(target_arch = "wasm32"))]
 macro_rules! log_info {
-    ($($t:tt)*) => (println!($($t)*))
+    ($($t:tt)*) => {
+        if crate::utils::logging::enabled(crate::utils::logging::LogLevel::Info) {
+            println!($($t)*)
+        }
+    };
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -34,7 +55,33 @@ macro_rules! log_error {
 
 #[cfg(target_arch = "wasm32")]
 macro_rules! log_warn {
-    ($($t:tt)*) => (worker::console_warn!($($t)*))
+    ($($t:tt)*) => {
+        if crate::utils::logging::enabled(crate::utils::logging::LogLevel::Warn) {
+            worker::console_warn!($($t)*)
+        }
+    };
+}
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! log_warn {
+    ($($t:tt)*) => {
+        if crate::utils::logging::enabled(crate::utils::logging::LogLevel::Warn) {
+            eprintln!($($t)*)
+        }
+    };
+}
+
+/// 结构化日志：每次调用输出一行 JSON，与上面的人类可读日志分开 (不受 `LOG_LEVEL` 影响)
+#[cfg(target_arch = "wasm32")]
+macro_rules! log_json {
+    ($line:expr) => {
+        worker::console_log!("{}", $line)
+    };
+}
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! log_json {
+    ($line:expr) => {
+        println!("{}", $line)
+    };
 }
 
 // =========================================================
@@ -42,6 +89,82 @@ macro_rules! log_warn {
 // =========================================================
 pub(crate) const STATE_KEY_CONFIG: &str = "config";
 pub(crate) const STATE_KEY_VERSION: &str = "current_version";
+pub(crate) const STATE_KEY_HISTORY: &str = "history";
+/// 检查历史最多保留的条数
+pub(crate) const HISTORY_CAPACITY: usize = 20;
+pub(crate) const STATE_KEY_FAILURES: &str = "consecutive_failures";
+/// 检测到新版本但 Dispatch 失败时保存的待重试状态
+pub(crate) const STATE_KEY_PENDING_DISPATCH: &str = "pending_dispatch";
+/// 同一版本 Dispatch 的最大尝试次数 (含首次)，超过后放弃重试
+pub(crate) const MAX_DISPATCH_ATTEMPTS: u32 = 5;
+/// 退避指数上限：retry_interval 最多放大 2^6 = 64 倍
+pub(crate) const MAX_BACKOFF_EXPONENT: u32 = 6;
+/// 定时检查的最短 Alarm 间隔 (秒)，可通过 `MIN_ALARM_SECS` 调整
+pub(crate) const DEFAULT_MIN_ALARM_SECS: u64 = 5;
+
+/// 计算连续失败后的重试间隔
+///
+/// `failures` 为本次之前已连续失败的次数，结果为
+/// `retry_interval * 2^min(failures, MAX_BACKOFF_EXPONENT)`，且不超过 `check_interval`
+pub(crate) fn backoff_interval(time_config: &TimeConfig, failures: u32) -> DurationSecs {
+    let factor = 1u64 << failures.min(MAX_BACKOFF_EXPONENT);
+    let secs = time_config.retry_interval.as_secs().saturating_mul(factor);
+    DurationSecs::from_secs(secs.min(time_config.check_interval.as_secs()))
+}
+
+/// 获取上游最新版本需要的子请求数量
+///
+/// GitHub 的 Tags 来源需要额外请求 Tag 对应的 Commit 以取得时间
+fn fetch_subrequests(config: &ProjectConfig) -> u32 {
+    match (&config.request.provider, config.request.source_kind) {
+        (Provider::GitHub, SourceKind::Tags) => 2,
+        _ => 1,
+    }
+}
+
+/// 待重试的 Dispatch：检测到的新版本与已失败的次数
+///
+/// Release 说明不随版本状态持久化，重试时的载荷不含 notes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct PendingDispatch {
+    pub release: GitHubRelease,
+    pub attempts: u32,
+}
+
+/// 单次检查的结构化日志，序列化为一行 JSON 供 Logpush 等日志管道解析
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct CheckLog {
+    pub unique_key: String,
+    pub outcome: CheckOutcome,
+    pub remote_tag: Option<String>,
+    pub old_tag: Option<String>,
+    pub duration_ms: u64,
+    pub at: Timestamp,
+}
+
+impl CheckLog {
+    /// 以当前时间开始记录，`outcome` 在 [`CheckLog::finish`] 前暂记为 `Error`
+    pub fn start(unique_key: &str) -> Self {
+        Self {
+            unique_key: unique_key.to_string(),
+            outcome: CheckOutcome::Error,
+            remote_tag: None,
+            old_tag: None,
+            duration_ms: 0,
+            at: Date::now_timestamp(),
+        }
+    }
+
+    /// 记录最终结果，并以开始时间到当前时间的差值作为耗时
+    pub fn finish(&mut self, outcome: CheckOutcome) {
+        self.outcome = outcome;
+        self.duration_ms = (Date::now_timestamp() - self.at).as_millis() as u64;
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
 
 // =========================================================
 // 业务逻辑层 (Logic) - 可测试版本
@@ -75,7 +198,8 @@ where
 
     pub async fn setup(&self, cmd: SetupMonitorCmd) -> WatchResult<()> {
         let mut config = cmd.config;
-        let delay = config.request.initial_delay;
+        let jitter = config.request.time_config.jitter_for(&config.unique_key);
+        let delay = config.request.initial_delay + jitter;
 
         // 计算下一次检查时间
         let next_check_at = Date::now_timestamp() + delay;
@@ -91,6 +215,9 @@ where
         // 清理所有数据
         self.storage.delete(STATE_KEY_CONFIG).await?;
         self.storage.delete(STATE_KEY_VERSION).await?;
+        self.storage.delete(STATE_KEY_HISTORY).await?;
+        self.storage.delete(STATE_KEY_FAILURES).await?;
+        self.storage.delete(STATE_KEY_PENDING_DISPATCH).await?;
         // 取消闹钟
         self.storage.delete_alarm().await?;
 
@@ -98,11 +225,50 @@ where
     }
 
     pub async fn get_config(&self, _cmd: GetConfigCmd) -> WatchResult<Option<ProjectConfig>> {
-        self.storage.get(STATE_KEY_CONFIG).await
+        self.load_config().await
+    }
+
+    pub async fn get_history(&self, _cmd: GetHistoryCmd) -> WatchResult<Vec<CheckEvent>> {
+        Ok(self
+            .storage
+            .get(STATE_KEY_HISTORY)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// 配置及其版本状态：已记录的版本与最近一次检查时间，无配置或已禁用时返回 None
+    pub async fn get_with_state(
+        &self,
+        _cmd: GetWithStateCmd,
+    ) -> WatchResult<Option<ProjectWithState>> {
+        let config = match self.load_config().await? {
+            Some(c) if c.enabled => c,
+            _ => return Ok(None),
+        };
+        let version: Option<GitHubRelease> = self.storage.get(STATE_KEY_VERSION).await?;
+        let history: Vec<CheckEvent> = self
+            .storage
+            .get(STATE_KEY_HISTORY)
+            .await?
+            .unwrap_or_default();
+
+        Ok(Some(ProjectWithState {
+            config,
+            current_version: version.map(|r| r.tag_name),
+            checked_at: history.last().map(|e| e.at),
+        }))
+    }
+
+    /// 版本订阅条目，无配置或已禁用时返回 None
+    pub async fn get_feed_entry(&self, _cmd: GetFeedEntryCmd) -> WatchResult<Option<FeedEntry>> {
+        Ok(self
+            .get_with_state(GetWithStateCmd)
+            .await?
+            .map(FeedEntry::from))
     }
 
     pub async fn switch_monitor(&self, cmd: SwitchMonitorCmd) -> WatchResult<()> {
-        let mut config: ProjectConfig = match self.storage.get(STATE_KEY_CONFIG).await? {
+        let mut config: ProjectConfig = match self.load_config().await? {
             Some(c) => c,
             None => return Err(WatchError::not_found("No config found").in_op("switch_monitor")),
         };
@@ -128,22 +294,175 @@ where
         Ok(())
     }
 
-    /// 手动触发检查
+    /// 启用或禁用监控
+    pub async fn set_enabled(&self, cmd: SetEnabledCmd) -> WatchResult<()> {
+        let mut config: ProjectConfig = match self.load_config().await? {
+            Some(c) => c,
+            None => return Err(WatchError::not_found("No config found").in_op("set_enabled")),
+        };
+
+        if config.enabled == cmd.enabled {
+            return Ok(());
+        }
+        config.enabled = cmd.enabled;
+
+        if !cmd.enabled {
+            // 禁用：保留配置，取消调度
+            self.storage.put(STATE_KEY_CONFIG, &config).await?;
+            self.storage.delete_alarm().await?;
+        } else if config.state.is_paused() {
+            // 启用但仍处于暂停：等待恢复后再调度
+            self.storage.put(STATE_KEY_CONFIG, &config).await?;
+        } else {
+            // 启用且未暂停：立即开始
+            config.state = MonitorState::running(Date::now_timestamp());
+            self.storage.put(STATE_KEY_CONFIG, &config).await?;
+            self.storage.set_alarm(Duration::from_millis(0)).await?;
+        }
+
+        Ok(())
+    }
+
+    /// 更新可变配置
+    ///
+    /// 在 DO 内完成读改写，不修改暂停与启用状态。运行中的监控缩短检查间隔时，
+    /// 下一次检查提前到 `now + 新间隔` 并重新设置 Alarm，不必等待旧间隔的 Alarm
+    pub async fn update_config(&self, cmd: UpdateConfigCmd) -> WatchResult<UpdateProjectResponse> {
+        let original: ProjectConfig = match self.load_config().await? {
+            Some(c) => c,
+            None => return Err(WatchError::not_found("No config found").in_op("update_config")),
+        };
+
+        let mut config = original.clone();
+        cmd.apply(&mut config);
+        let changes = original.diff(&config);
+        if changes.is_empty() {
+            return Ok(UpdateProjectResponse { config, changes });
+        }
+
+        let check_interval = config.request.time_config.check_interval;
+        let rescheduled = match config.next_check_at() {
+            Some(current)
+                if config.enabled
+                    && check_interval != original.request.time_config.check_interval =>
+            {
+                let now = Date::now_timestamp();
+                let next_check_at = current.min(now + check_interval);
+                config.state = MonitorState::running(next_check_at);
+                Some(next_check_at.max(now) - now)
+            }
+            _ => None,
+        };
+
+        self.storage.put(STATE_KEY_CONFIG, &config).await?;
+        if let Some(delay) = rescheduled {
+            self.storage.set_alarm(delay).await?;
+        }
+
+        Ok(UpdateProjectResponse { config, changes })
+    }
+
+    /// 重新安排下一次检查时间
+    ///
+    /// 早于当前时间的 `next_check_at` 按 0 延迟处理；暂停或禁用的监控没有调度，返回错误
+    pub async fn reschedule(&self, cmd: RescheduleCmd) -> WatchResult<()> {
+        let mut config: ProjectConfig = match self.load_config().await? {
+            Some(c) => c,
+            None => return Err(WatchError::not_found("No config found").in_op("reschedule")),
+        };
+
+        if config.state.is_paused() {
+            return Err(WatchError::conflict("Monitor is paused").in_op("reschedule"));
+        }
+        if !config.enabled {
+            return Err(WatchError::conflict("Monitor is disabled").in_op("reschedule"));
+        }
+
+        let now = Date::now_timestamp();
+        let next_check_at = cmd.next_check_at.max(now);
+        config.state = MonitorState::running(next_check_at);
+        self.storage.put(STATE_KEY_CONFIG, &config).await?;
+        self.storage.set_alarm(next_check_at - now).await?;
+
+        Ok(())
+    }
+
+    /// 自愈检查：运行中但 Alarm 丢失时，按 `next_check_at` 重新设置 Alarm
+    ///
+    /// 无配置、暂停或禁用的监控本就不应有 Alarm，不做处理；返回是否重新设置了 Alarm
+    pub async fn health_check(&self, _cmd: HealthCheckCmd) -> WatchResult<bool> {
+        let config = match self.load_config().await? {
+            Some(c) if c.enabled => c,
+            _ => return Ok(false),
+        };
+        let Some(next_check_at) = config.next_check_at() else {
+            return Ok(false);
+        };
+        if self.storage.get_alarm().await?.is_some() {
+            return Ok(false);
+        }
+
+        let now = Date::now_timestamp();
+        self.storage.set_alarm(next_check_at.max(now) - now).await?;
+        log_warn!("Re-armed missing alarm [{}]", config.unique_key);
+
+        Ok(true)
+    }
+
+    /// 手动触发检查，已禁用的监控返回错误
     pub async fn trigger(&self, _cmd: TriggerCheckCmd) -> WatchResult<()> {
-        let config: Option<ProjectConfig> = self.storage.get(STATE_KEY_CONFIG).await?;
+        let config: Option<ProjectConfig> = self.load_config().await?;
         match config {
-            Some(cfg) => self
-                .perform_check_flow(&cfg)
-                .await
-                .map_err(|e| e.in_op("trigger")),
+            Some(cfg) if !cfg.enabled => {
+                Err(WatchError::conflict("Monitor is disabled").in_op("trigger"))
+            }
+            Some(mut cfg) => {
+                let result = self.run_check(&mut cfg).await;
+                // 保存本次检查结果 (`last_outcome` 等)，不改变已安排的调度
+                self.storage.put(STATE_KEY_CONFIG, &cfg).await?;
+                result.map(|_| ()).map_err(|e| e.in_op("trigger"))
+            }
             None => Err(WatchError::not_found("No config found").in_op("trigger")),
         }
     }
 
+    /// 强制 Dispatch：获取上游最新版本并无条件触发，返回触发的 Tag
+    ///
+    /// 与手动检查不同，不与本地版本比较，也不更新已存储的版本与 `last_dispatch_at`，
+    /// 用于下游 Workflow 失败后按当前最新版本重新触发
+    pub async fn force_dispatch(&self, _cmd: ForceDispatchCmd) -> WatchResult<String> {
+        let config = self
+            .load_config()
+            .await?
+            .ok_or_else(|| WatchError::not_found("No config found").in_op("force_dispatch"))?;
+        if self.dispatch_disabled() {
+            return Err(
+                WatchError::conflict("Dispatch is disabled (DISPATCH_DISABLED)")
+                    .in_op("force_dispatch"),
+            );
+        }
+
+        let gateway = self.github_gateway(config.request.comparison_mode);
+        let release = self
+            .fetch_remote_release(&config, &gateway)
+            .await
+            .map_err(|e| e.in_op("force_dispatch"))?;
+        self.dispatch(&gateway, &config, &release)
+            .await
+            .map_err(|e| e.in_op("force_dispatch"))?;
+
+        log_info!(
+            "[{}] Force dispatched {}",
+            config.unique_key,
+            release.tag_name
+        );
+        Ok(release.tag_name)
+    }
+
     // --- Alarm 回调函数 ---
 
     pub async fn on_alarm(&self) -> WatchResult<()> {
-        let config: Option<ProjectConfig> = self.storage.get(STATE_KEY_CONFIG).await?;
+        let config: Option<ProjectConfig> = self.load_config().await?;
 
         // 1. 僵尸检查
         let mut config = match config {
@@ -154,28 +473,37 @@ where
             }
         };
 
-        // 2. 暂停检查
-        if config.state.is_paused() {
+        // 2. 禁用/暂停检查
+        if !config.enabled || !config.is_running() {
             self.storage.delete_alarm().await?;
             return Ok(());
         }
 
         // 3. 执行核心逻辑 (捕获错误以决定下一次调度时间)
-        let result = self.perform_check_flow(&config).await;
+        let result = self.run_check(&mut config).await;
 
-        // 记录日志
-        match &result {
-            Ok(_) => log_info!("Monitor Success [{}]", config.unique_key),
-            Err(e) => log_error!("Monitor Failed [{}]: {}", config.unique_key, e),
-        }
-
-        // 4. 计算下一次时间
+        // 4. 计算下一次时间 (失败时按连续失败次数指数退避，成功时重置计数)
         let next_interval = if result.is_ok() {
-            config.request.time_config.check_interval
+            self.storage.delete(STATE_KEY_FAILURES).await?;
+            // 静默时段内最晚在时段结束时再检查一次，及时触发被推迟的 Dispatch
+            let check_interval = config.request.time_config.check_interval;
+            match config.request.quiet_hours_remaining(Date::now_timestamp()) {
+                Some(remaining) => check_interval.min(remaining),
+                None => check_interval,
+            }
         } else {
-            config.request.time_config.retry_interval
+            let failures: u32 = self.storage.get(STATE_KEY_FAILURES).await?.unwrap_or(0);
+            self.storage
+                .put(STATE_KEY_FAILURES, &failures.saturating_add(1))
+                .await?;
+            backoff_interval(&config.request.time_config, failures)
         };
 
+        // 叠加调度抖动，分散相同间隔的 Alarm；并且不低于最短间隔，
+        // 避免过小的 retry_interval 在持续出错时形成忙循环
+        let jitter = config.request.time_config.jitter_for(&config.unique_key);
+        let next_interval = (next_interval + jitter).max(self.min_alarm_interval());
+
         // 5. 更新状态中的下一次检查时间
         let next_check_at = Date::now_timestamp() + next_interval;
         config.state = MonitorState::running(next_check_at);
@@ -187,43 +515,139 @@ where
         Ok(())
     }
 
-    async fn perform_check_flow(&self, config: &ProjectConfig) -> WatchResult<()> {
-        // 获取 Secrets
-        let github_token_name = self
-            .env
-            .var("GITHUB_TOKEN_NAME")
-            .unwrap_or_else(|| "GITHUB_TOKEN".to_string());
-        let global_token = self.env.secret(&github_token_name);
+    /// 读取配置，旧版格式会被升级并写回存储
+    async fn load_config(&self) -> WatchResult<Option<ProjectConfig>> {
+        let Some(stored) = self.storage.get::<StoredConfig>(STATE_KEY_CONFIG).await? else {
+            return Ok(None);
+        };
+        let (config, upgraded) = stored.into_current(Date::now_timestamp());
+        if upgraded {
+            log_warn!("Migrated legacy config [{}]", config.unique_key);
+            self.storage.put(STATE_KEY_CONFIG, &config).await?;
+        }
+        Ok(Some(config))
+    }
 
-        // 1. 初始化 Gateway (注入 comparison_mode)
-        // 这里传入了 config 中的模式，Gateway 后续会自动只解析该模式所需的字段
-        let gateway =
-            GitHubGateway::new(&self.client, global_token, config.request.comparison_mode);
+    /// 追加一条检查事件，超出容量时丢弃最旧的记录
+    async fn record_history(&self, event: CheckEvent) -> WatchResult<()> {
+        let mut history: Vec<CheckEvent> = self
+            .storage
+            .get(STATE_KEY_HISTORY)
+            .await?
+            .unwrap_or_default();
+        history.push(event);
+        if history.len() > HISTORY_CAPACITY {
+            let overflow = history.len() - HISTORY_CAPACITY;
+            history.drain(..overflow);
+        }
+        self.storage.put(STATE_KEY_HISTORY, &history).await
+    }
 
-        // A. 获取上游 Release (强类型，必定包含有效时间戳)
-        let remote_release = gateway
-            .fetch_latest_release(
-                &config.request.base_config.upstream_owner,
-                &config.request.base_config.upstream_repo,
-            )
-            .await
-            .map_err(|e| {
-                WatchError::external_api(e.to_string()).in_op_with(
-                    "github.fetch_release",
+    /// 执行一次检查并完成记录：输出结构化检查日志、写入检查历史，并更新配置中的
+    /// `last_outcome` 与 `failed_checks` (由调用方保存)
+    ///
+    /// 定时检查与手动触发共用，返回检查流程本身的结果
+    async fn run_check(&self, config: &mut ProjectConfig) -> WatchResult<CheckEvent> {
+        let mut check_log = CheckLog::start(&config.unique_key);
+        let mut budget = self.subrequest_budget();
+        let result = self
+            .perform_check_flow(config, &mut check_log, &mut budget)
+            .await;
+
+        let event = match &result {
+            Ok(event) => {
+                log_info!("Monitor Success [{}]", config.unique_key);
+                event.clone()
+            }
+            Err(e) => {
+                log_error!("Monitor Failed [{}]: {}", config.unique_key, e);
+                config.failed_checks = config.failed_checks.saturating_add(1);
+                CheckEvent::now(CheckOutcome::Error, e.to_string())
+            }
+        };
+        check_log.finish(event.outcome);
+        config.last_outcome = Some(event.outcome);
+        log_json!(check_log.to_json());
+        // 历史记录写入失败不应影响检查结果与后续调度
+        if let Err(e) = self.record_history(event).await {
+            log_error!("Record History Failed [{}]: {}", config.unique_key, e);
+        }
+
+        result
+    }
+
+    /// 执行一次完整检查，过程中观察到的远程/本地版本会写入 `log`
+    ///
+    /// 发出的子请求记入 `budget`：请求上游与 Dispatch 总是执行，预算不足时跳过部分通知
+    async fn perform_check_flow(
+        &self,
+        config: &mut ProjectConfig,
+        log: &mut CheckLog,
+        budget: &mut SubrequestBudget,
+    ) -> WatchResult<CheckEvent> {
+        let dispatch_disabled = self.dispatch_disabled();
+
+        // 0. 上次 Dispatch 失败：优先重试，不重新请求上游 (全局停用或静默时段内不重试)
+        let quiet_remaining = config.request.quiet_hours_remaining(Date::now_timestamp());
+        if !dispatch_disabled
+            && quiet_remaining.is_none()
+            && let Some(pending) = self
+                .storage
+                .get::<PendingDispatch>(STATE_KEY_PENDING_DISPATCH)
+                .await?
+        {
+            return self
+                .retry_pending_dispatch(config, log, pending, budget)
+                .await;
+        }
+
+        // A. 初始化 Gateway 并获取上游最新 Release
+        let gateway = self.github_gateway(config.request.comparison_mode);
+        budget.spend(fetch_subrequests(config));
+        let remote_release = self.fetch_remote_release(config, &gateway).await?;
+        log.remote_tag = Some(remote_release.tag_name.clone());
+
+        // A2. Tag 过滤：不匹配时跳过，且不更新版本状态
+        if let Some(pattern) = &config.request.tag_pattern {
+            let re = regex_lite::Regex::new(pattern).map_err(|e| {
+                WatchError::invalid_input(format!("Invalid tag_pattern '{}': {}", pattern, e))
+                    .in_op("check.tag_pattern")
+            })?;
+            if !re.is_match(&remote_release.tag_name) {
+                return Ok(CheckEvent::now(
+                    CheckOutcome::NoChange,
+                    format!("No matching tag: {}", remote_release.tag_name),
+                ));
+            }
+        }
+
+        // A3. 版本约束：不满足 semver 约束 (或 Tag 无法解析) 时跳过，且不更新版本状态
+        if let Some(constraint) = &config.request.version_constraint {
+            let req = semver::VersionReq::parse(constraint).map_err(|e| {
+                WatchError::invalid_input(format!(
+                    "Invalid version_constraint '{}': {}",
+                    constraint, e
+                ))
+                .in_op("check.version_constraint")
+            })?;
+            if !remote_release.semver().is_some_and(|v| req.matches(&v)) {
+                return Ok(CheckEvent::now(
+                    CheckOutcome::NoChange,
                     format!(
-                        "{}/{}",
-                        config.request.base_config.upstream_owner,
-                        config.request.base_config.upstream_repo
+                        "Outside version constraint '{}': {}",
+                        constraint, remote_release.tag_name
                     ),
-                )
-            })?;
+                ));
+            }
+        }
 
         // B & C. 获取本地状态并进行比较
         // 存储的是 GitHubRelease 结构体(JSON)，而不仅仅是 String
         let local_state: Option<GitHubRelease> = self.storage.get(STATE_KEY_VERSION).await?;
+        log.old_tag = local_state.as_ref().map(|r| r.tag_name.clone());
 
-        if let Some(local_release) = local_state {
-            match remote_release.is_newer_than(&local_release) {
+        if let Some(local_release) = &local_state {
+            match remote_release.is_newer_than(local_release) {
                 // 远程版本确实更新 -> 继续执行
                 Ok(true) => {
                     log_info!(
@@ -233,14 +657,309 @@ where
                     );
                 }
                 // 远程版本不比本地新 -> 结束流程
-                Ok(false) => return Ok(()),
+                Ok(false) => {
+                    return Ok(CheckEvent::now(
+                        CheckOutcome::NoChange,
+                        format!("Up to date: {}", local_release.tag_name),
+                    ));
+                }
                 // 模式不匹配 (例如本地存的是 Updated 模式，但现在配置改成了 Published)
                 // 策略：视为新版本，覆盖旧数据以修正状态
                 Err(_) => {}
             }
         }
 
-        // D. 触发 Dispatch
+        // C2. 建立基线：尚无本地版本时只记录当前版本，后续更新才触发 Dispatch
+        if local_state.is_none() && config.request.initialize_baseline {
+            log_info!(
+                "[{}] Baseline recorded: {}",
+                config.unique_key,
+                remote_release.tag_name
+            );
+            self.storage.put(STATE_KEY_VERSION, &remote_release).await?;
+            return Ok(CheckEvent::now(
+                CheckOutcome::NoChange,
+                format!("Baseline recorded: {}", remote_release.tag_name),
+            ));
+        }
+
+        // D0. 试运行：记录版本但不触发 Dispatch 与通知
+        if config.request.dry_run {
+            let detail = format!(
+                "Dry run: would dispatch {} (Old: {})",
+                remote_release.tag_name,
+                local_state.as_ref().map_or("none", |r| r.tag_name.as_str())
+            );
+            log_info!("[{}] {}", config.unique_key, detail);
+            self.storage.put(STATE_KEY_VERSION, &remote_release).await?;
+            return Ok(CheckEvent::now(CheckOutcome::Success, detail));
+        }
+
+        // D1. 全局停用 Dispatch：照常推进版本状态，但不触发 Dispatch 与通知
+        if dispatch_disabled {
+            let detail = format!(
+                "Dispatch suppressed (DISPATCH_DISABLED): {} (Old: {})",
+                remote_release.tag_name,
+                local_state.as_ref().map_or("none", |r| r.tag_name.as_str())
+            );
+            log_warn!("[{}] {}", config.unique_key, detail);
+            self.storage.put(STATE_KEY_VERSION, &remote_release).await?;
+            // 版本已推进，之前未完成的 Dispatch 不再重试
+            self.storage.delete(STATE_KEY_PENDING_DISPATCH).await?;
+            return Ok(CheckEvent::now(CheckOutcome::Success, detail));
+        }
+
+        // D2. 静默时段：不触发 Dispatch 也不更新版本状态，时段结束后的检查会再次发现该版本
+        if let Some(remaining) = quiet_remaining {
+            let detail = format!(
+                "Quiet hours: deferred dispatch of {} ({} left)",
+                remote_release.tag_name,
+                remaining.humanize()
+            );
+            log_info!("[{}] {}", config.unique_key, detail);
+            return Ok(CheckEvent::now(CheckOutcome::NoChange, detail));
+        }
+
+        // D. 触发 Dispatch (失败时保存待重试状态，下次检查优先重试而不重新请求上游)
+        budget.spend(1);
+        if let Err(e) = self.dispatch(&gateway, config, &remote_release).await {
+            let pending = PendingDispatch {
+                release: remote_release,
+                attempts: 1,
+            };
+            self.storage
+                .put(STATE_KEY_PENDING_DISPATCH, &pending)
+                .await?;
+            return Err(e);
+        }
+
+        // E & F. 更新状态并发送通知
+        self.complete_dispatch(
+            config,
+            &remote_release,
+            local_state.as_ref().map(|r| r.tag_name.as_str()),
+            budget,
+        )
+        .await?;
+
+        Ok(CheckEvent::now(
+            CheckOutcome::Success,
+            format!("Dispatched {}", remote_release.tag_name),
+        ))
+    }
+
+    /// 重试上次失败的 Dispatch (使用保存的 Release，不重新请求上游)
+    ///
+    /// 累计失败达到 [`MAX_DISPATCH_ATTEMPTS`] 次后放弃重试，之后的检查恢复正常流程
+    async fn retry_pending_dispatch(
+        &self,
+        config: &mut ProjectConfig,
+        log: &mut CheckLog,
+        mut pending: PendingDispatch,
+        budget: &mut SubrequestBudget,
+    ) -> WatchResult<CheckEvent> {
+        log.remote_tag = Some(pending.release.tag_name.clone());
+        let local_state: Option<GitHubRelease> = self.storage.get(STATE_KEY_VERSION).await?;
+        log.old_tag = local_state.map(|r| r.tag_name);
+
+        let gateway = self.github_gateway(config.request.comparison_mode);
+        budget.spend(1);
+        if let Err(e) = self.dispatch(&gateway, config, &pending.release).await {
+            pending.attempts += 1;
+            if pending.attempts >= MAX_DISPATCH_ATTEMPTS {
+                log_error!(
+                    "[{}] Giving up pending dispatch of {} after {} attempts",
+                    config.unique_key,
+                    pending.release.tag_name,
+                    pending.attempts
+                );
+                self.storage.delete(STATE_KEY_PENDING_DISPATCH).await?;
+            } else {
+                self.storage
+                    .put(STATE_KEY_PENDING_DISPATCH, &pending)
+                    .await?;
+            }
+            return Err(e.in_op("retry_pending_dispatch"));
+        }
+
+        self.storage.delete(STATE_KEY_PENDING_DISPATCH).await?;
+        self.complete_dispatch(config, &pending.release, log.old_tag.as_deref(), budget)
+            .await?;
+
+        Ok(CheckEvent::now(
+            CheckOutcome::Success,
+            format!(
+                "Dispatched {} (retry {})",
+                pending.release.tag_name, pending.attempts
+            ),
+        ))
+    }
+
+    /// Dispatch 成功后：保存新版本、记录 `last_dispatch_at` 并发送通知
+    async fn complete_dispatch(
+        &self,
+        config: &mut ProjectConfig,
+        release: &GitHubRelease,
+        old_version: Option<&str>,
+        budget: &mut SubrequestBudget,
+    ) -> WatchResult<()> {
+        // E. 更新状态
+        // 存储整个 release 对象，以便下次比较时保留 mode 信息
+        self.storage.put(STATE_KEY_VERSION, release).await?;
+        config.last_dispatch_at = Some(Date::now_timestamp());
+        self.storage.put(STATE_KEY_CONFIG, &*config).await?;
+
+        // F. 发送通知 (失败不影响本次检查结果，各渠道互不影响；预算不足时跳过剩余渠道)
+        let notifier = CompositeNotifier::from_request(&self.client, &config.request, |name| {
+            self.env.secret(name)
+        });
+        if !notifier.is_empty() {
+            let event = NotifyEvent::new(&config.request, release, old_version);
+            let report = notifier.notify_all(&event, budget).await;
+            for (sink, e) in report.failures {
+                log_warn!("Notify Failed [{}] ({}): {}", config.unique_key, sink, e);
+            }
+            if !report.skipped.is_empty() {
+                log_warn!(
+                    "Notify Skipped [{}] (subrequest budget exhausted): {}",
+                    config.unique_key,
+                    report.skipped.join(", ")
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 全局停用 Dispatch 的开关 (`DISPATCH_DISABLED` 为 `true`/`1`/`yes`/`on` 时生效)
+    fn dispatch_disabled(&self) -> bool {
+        self.env.var("DISPATCH_DISABLED").is_some_and(|v| {
+            matches!(
+                v.trim().to_ascii_lowercase().as_str(),
+                "true" | "1" | "yes" | "on"
+            )
+        })
+    }
+
+    /// 定时检查的最短 Alarm 间隔，读取 `MIN_ALARM_SECS`，未设置或无法解析时使用默认值
+    ///
+    /// 只作用于 Alarm 回调中的下一次调度；恢复/启用时的立即检查不受影响
+    fn min_alarm_interval(&self) -> DurationSecs {
+        let secs = self
+            .env
+            .var("MIN_ALARM_SECS")
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(DEFAULT_MIN_ALARM_SECS);
+        DurationSecs::from_secs(secs)
+    }
+
+    /// 单次检查可用的子请求预算，读取 `SUBREQUEST_BUDGET`，未设置或无法解析时使用默认值
+    fn subrequest_budget(&self) -> SubrequestBudget {
+        let limit = self
+            .env
+            .var("SUBREQUEST_BUDGET")
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(DEFAULT_SUBREQUEST_BUDGET);
+        SubrequestBudget::new(limit)
+    }
+
+    /// 所有上游平台请求使用的 User-Agent，读取 `GITHUB_USER_AGENT`，未设置时使用默认值
+    fn user_agent(&self) -> String {
+        self.env
+            .var("GITHUB_USER_AGENT")
+            .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string())
+    }
+
+    /// 构建 GitHub Gateway (注入 comparison_mode)
+    /// Gateway 后续会自动只解析该模式所需的字段
+    fn github_gateway(&self, mode: ComparisonMode) -> GitHubGateway<'_, C> {
+        let github_token_name = self
+            .env
+            .var("GITHUB_TOKEN_NAME")
+            .unwrap_or_else(|| "GITHUB_TOKEN".to_string());
+        let global_token = self.env.secret(&github_token_name);
+
+        let gateway =
+            GitHubGateway::new(&self.client, global_token, mode).with_user_agent(self.user_agent());
+        match self
+            .env
+            .var("DISPATCH_NOTES_MAX_BYTES")
+            .and_then(|v| v.parse::<usize>().ok())
+        {
+            Some(max_bytes) => gateway.with_notes_max_bytes(max_bytes),
+            None => gateway,
+        }
+    }
+
+    /// 按配置选择上游平台，获取最新 Release (强类型，必定包含有效时间戳)
+    async fn fetch_remote_release(
+        &self,
+        config: &ProjectConfig,
+        gateway: &GitHubGateway<'_, C>,
+    ) -> WatchResult<GitHubRelease> {
+        let gitlab;
+        let gitea;
+        let provider: &dyn ReleaseProvider = match &config.request.provider {
+            Provider::GitHub => gateway,
+            Provider::GitLab => {
+                let token_name = self
+                    .env
+                    .var("GITLAB_TOKEN_NAME")
+                    .unwrap_or_else(|| "GITLAB_TOKEN".to_string());
+                gitlab = GitLabGateway::new(
+                    &self.client,
+                    self.env.secret(&token_name),
+                    config.request.comparison_mode,
+                )
+                .with_user_agent(self.user_agent());
+                &gitlab
+            }
+            Provider::Gitea { base_url } => {
+                let token_name = self
+                    .env
+                    .var("GITEA_TOKEN_NAME")
+                    .unwrap_or_else(|| "GITEA_TOKEN".to_string());
+                gitea = GiteaGateway::new(
+                    &self.client,
+                    base_url,
+                    self.env.secret(&token_name),
+                    config.request.comparison_mode,
+                )
+                .with_user_agent(self.user_agent());
+                &gitea
+            }
+        };
+        provider
+            .fetch_latest(
+                &config.request.base_config.upstream_owner,
+                &config.request.base_config.upstream_repo,
+                config.request.source_kind,
+            )
+            .await
+            .map_err(|e| {
+                // 保留上游限流状态，其余错误统一视为上游 API 错误
+                let status = match e.status {
+                    WatchErrorStatus::RateLimited => WatchErrorStatus::RateLimited,
+                    _ => WatchErrorStatus::ExternalApi,
+                };
+                WatchError::new(status, e.to_string()).in_op_with(
+                    "upstream.fetch_release",
+                    format!(
+                        "{}/{}",
+                        config.request.base_config.upstream_owner,
+                        config.request.base_config.upstream_repo
+                    ),
+                )
+            })
+    }
+
+    /// 使用项目配置的 PAT 向下游仓库触发 Dispatch
+    async fn dispatch(
+        &self,
+        gateway: &GitHubGateway<'_, C>,
+        config: &ProjectConfig,
+        release: &GitHubRelease,
+    ) -> WatchResult<()> {
         let default_pat_name = self
             .env
             .var("PAT_TOKEN_NAME")
@@ -257,7 +976,7 @@ where
         })?;
 
         gateway
-            .trigger_dispatch(config, &remote_release.tag_name, &pat)
+            .trigger_dispatch(config, release, &pat)
             .await
             .map_err(|e| {
                 WatchError::external_api(e.to_string()).in_op_with(
@@ -267,13 +986,7 @@ where
                         config.request.base_config.my_owner, config.request.base_config.my_repo
                     ),
                 )
-            })?;
-
-        // E. 更新状态
-        // 存储整个 remote_release 对象，以便下次比较时保留 mode 信息
-        self.storage.put(STATE_KEY_VERSION, &remote_release).await?;
-
-        Ok(())
+            })
     }
 }
 
@@ -295,31 +1008,49 @@ pub struct ProjectMonitor {
     env: Env,
 }
 
+impl ProjectMonitor {
+    /// 按 `LOG_LEVEL` 设置本次请求/Alarm 的日志级别
+    fn init_log_level(&self) {
+        let level = self.env.var("LOG_LEVEL").ok().map(|v| v.to_string());
+        logging::set_level(LogLevel::from_var(level.as_deref()));
+    }
+}
+
 impl DurableObject for ProjectMonitor {
     fn new(state: State, env: Env) -> Self {
         Self { state, env }
     }
 
     async fn fetch(&self, req: Request) -> worker::Result<Response> {
+        self.init_log_level();
         let storage = WorkerStorage(self.state.storage());
         let env = WorkerEnv(&self.env);
-        let logic = ProjectMonitorLogic::new(storage, env, WorkerHttpClient);
+        let logic = ProjectMonitorLogic::new(storage, env, WorkerHttpClient::from_env(&self.env));
         let path = req.path();
 
         match path.as_str() {
             SetupMonitorCmd::PATH => RpcHandler::handle(req, |c| logic.setup(c)).await,
             StopMonitorCmd::PATH => RpcHandler::handle(req, |c| logic.stop(c)).await,
             TriggerCheckCmd::PATH => RpcHandler::handle(req, |c| logic.trigger(c)).await,
+            ForceDispatchCmd::PATH => RpcHandler::handle(req, |c| logic.force_dispatch(c)).await,
+            RescheduleCmd::PATH => RpcHandler::handle(req, |c| logic.reschedule(c)).await,
+            UpdateConfigCmd::PATH => RpcHandler::handle(req, |c| logic.update_config(c)).await,
             GetConfigCmd::PATH => RpcHandler::handle(req, |c| logic.get_config(c)).await,
             SwitchMonitorCmd::PATH => RpcHandler::handle(req, |c| logic.switch_monitor(c)).await,
+            GetHistoryCmd::PATH => RpcHandler::handle(req, |c| logic.get_history(c)).await,
+            GetFeedEntryCmd::PATH => RpcHandler::handle(req, |c| logic.get_feed_entry(c)).await,
+            GetWithStateCmd::PATH => RpcHandler::handle(req, |c| logic.get_with_state(c)).await,
+            HealthCheckCmd::PATH => RpcHandler::handle(req, |c| logic.health_check(c)).await,
+            SetEnabledCmd::PATH => RpcHandler::handle(req, |c| logic.set_enabled(c)).await,
             _ => Response::error("Not Found", 404),
         }
     }
 
     async fn alarm(&self) -> worker::Result<Response> {
+        self.init_log_level();
         let storage = WorkerStorage(self.state.storage());
         let env = WorkerEnv(&self.env);
-        let logic = ProjectMonitorLogic::new(storage, env, WorkerHttpClient);
+        let logic = ProjectMonitorLogic::new(storage, env, WorkerHttpClient::from_env(&self.env));
 
         // Alarm 内部即使出错，也只记录日志，不抛出异常给 Worker Runtime
         // 这样可以避免 Worker 无限重试当前的 Alarm
@@ -20,6 +20,7 @@ fn create_test_config() -> ProjectConfig {
         initial_delay: DurationSecs::from_secs(60),
         dispatch_token_secret: None,
         comparison_mode: ComparisonMode::PublishedAt,
+        ..Default::default()
     };
     ProjectConfig::new(request)
 }
@@ -91,8 +92,35 @@ async fn test_setup_sets_alarm() {
     logic.setup(cmd).await.unwrap();
 
     // 验证 alarm 已设置
-    let alarm = logic.storage.alarm.borrow();
-    assert_eq!(*alarm, Some(Duration::from(delay)));
+    let alarm = logic.storage.get_alarm().await.unwrap();
+    assert_eq!(alarm, Some(Duration::from(delay)));
+}
+
+#[tokio::test]
+async fn test_setup_applies_jitter() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.initial_delay = DurationSecs::from_secs(60);
+    config.request.time_config.jitter_secs = DurationSecs::from_secs(600);
+    let jitter = config.request.time_config.jitter_for(&config.unique_key);
+    assert!(jitter < DurationSecs::from_secs(600));
+
+    let before = verwatch_shared::Date::now_timestamp();
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+
+    // alarm 与 next_check_at 都包含抖动偏移
+    let expected = DurationSecs::from_secs(60) + jitter;
+    assert_eq!(
+        logic.storage.get_alarm().await.unwrap(),
+        Some(Duration::from(expected))
+    );
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    let next_check_at = saved.state.next_check_at().unwrap();
+    assert!(next_check_at >= before + expected);
 }
 
 // =========================================================
@@ -154,13 +182,13 @@ async fn test_stop_clears_alarm() {
     logic.setup(SetupMonitorCmd { config }).await.unwrap();
 
     // 确认有 alarm
-    assert!(logic.storage.alarm.borrow().is_some());
+    assert!(logic.storage.get_alarm().await.unwrap().is_some());
 
     // 执行 stop
     logic.stop(StopMonitorCmd).await.unwrap();
 
     // 验证 alarm 已删除
-    assert!(logic.storage.alarm.borrow().is_none());
+    assert!(logic.storage.get_alarm().await.unwrap().is_none());
 }
 
 // =========================================================
@@ -198,6 +226,28 @@ async fn test_get_config_returns_saved_config() {
     assert_eq!(result.unwrap().unique_key, config.unique_key);
 }
 
+#[tokio::test]
+async fn test_get_config_migrates_legacy_config() {
+    let storage = MockStorage::new();
+    storage.map.borrow_mut().insert(
+        STATE_KEY_CONFIG.to_string(),
+        r#"{"base":{"upstream_owner":"owner","upstream_repo":"repo","my_owner":"my_owner","my_repo":"my_repo"},"time_config":{"check_interval":3600,"retry_interval":10},"initial_delay":60,"dispatch_token_secret":null,"comparison_mode":"published_at"}"#
+            .to_string(),
+    );
+    let logic = create_logic(storage, MockEnv::new(), MockHttpClient::new());
+
+    let config = logic.get_config(GetConfigCmd).await.unwrap().unwrap();
+    assert_eq!(config.unique_key, create_test_config().unique_key);
+    assert!(!config.state.is_paused());
+
+    // 已按新格式写回存储
+    let raw = logic.storage.map.borrow()[STATE_KEY_CONFIG].clone();
+    let resaved: ProjectConfig = serde_json::from_str(&raw).unwrap();
+    assert_eq!(resaved.unique_key, config.unique_key);
+    assert!(raw.contains("\"state\""));
+    assert!(!raw.contains("\"base\""));
+}
+
 // =========================================================
 // switch_monitor 测试
 // =========================================================
@@ -237,7 +287,7 @@ async fn test_switch_monitor_pause() {
     assert!(saved.unwrap().state.is_paused());
 
     // 验证 alarm 已删除
-    assert!(logic.storage.alarm.borrow().is_none());
+    assert!(logic.storage.get_alarm().await.unwrap().is_none());
 }
 
 #[tokio::test]
@@ -269,7 +319,7 @@ async fn test_switch_monitor_resume() {
 
     // 验证 alarm 已设置 (立即触发，所以是 0)
     assert_eq!(
-        *logic.storage.alarm.borrow(),
+        logic.storage.get_alarm().await.unwrap(),
         Some(Duration::from_millis(0))
     );
 }
@@ -284,7 +334,7 @@ async fn test_switch_monitor_same_state_noop() {
     // 先 setup (状态为 Running)
     let config = create_test_config();
     logic.setup(SetupMonitorCmd { config }).await.unwrap();
-    let alarm_before = *logic.storage.alarm.borrow();
+    let alarm_before = logic.storage.get_alarm().await.unwrap();
 
     // 切换到 Running (已经是 Running)
     logic
@@ -293,68 +343,1890 @@ async fn test_switch_monitor_same_state_noop() {
         .unwrap();
 
     // 状态不变，alarm 也不变
-    let alarm_after = *logic.storage.alarm.borrow();
+    let alarm_after = logic.storage.get_alarm().await.unwrap();
     assert_eq!(alarm_before, alarm_after);
 }
 
 // =========================================================
-// trigger 测试 (无 config 情况)
+// set_enabled 测试
 // =========================================================
 
 #[tokio::test]
-async fn test_trigger_no_config_returns_error() {
+async fn test_set_enabled_false_keeps_config_and_deletes_alarm() {
     let storage = MockStorage::new();
     let env = MockEnv::new();
     let client = MockHttpClient::new();
     let logic = create_logic(storage, env, client);
 
-    let result = logic.trigger(TriggerCheckCmd).await;
+    let config = create_test_config();
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+
+    logic
+        .set_enabled(SetEnabledCmd { enabled: false })
+        .await
+        .unwrap();
+
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert!(!saved.enabled);
+    assert!(logic.storage.get_alarm().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_set_enabled_true_reschedules_immediately() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+    let logic = create_logic(storage, env, client);
+
+    let config = create_test_config();
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic
+        .set_enabled(SetEnabledCmd { enabled: false })
+        .await
+        .unwrap();
+
+    logic
+        .set_enabled(SetEnabledCmd { enabled: true })
+        .await
+        .unwrap();
+
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert!(saved.enabled);
+    assert!(!saved.state.is_paused());
+    assert_eq!(
+        logic.storage.get_alarm().await.unwrap(),
+        Some(Duration::from_millis(0))
+    );
+}
+
+#[tokio::test]
+async fn test_set_enabled_true_while_paused_does_not_schedule() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+    let logic = create_logic(storage, env, client);
+
+    let config = create_test_config();
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic
+        .switch_monitor(SwitchMonitorCmd { paused: true })
+        .await
+        .unwrap();
+    logic
+        .set_enabled(SetEnabledCmd { enabled: false })
+        .await
+        .unwrap();
+
+    logic
+        .set_enabled(SetEnabledCmd { enabled: true })
+        .await
+        .unwrap();
+
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert!(saved.enabled);
+    assert!(saved.state.is_paused());
+    assert!(logic.storage.get_alarm().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_set_enabled_no_config_returns_error() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+    let logic = create_logic(storage, env, client);
+
+    let result = logic.set_enabled(SetEnabledCmd { enabled: false }).await;
     assert!(result.is_err());
 }
 
 // =========================================================
-// on_alarm 测试
+// update_config 测试
 // =========================================================
 
 #[tokio::test]
-async fn test_on_alarm_no_config_deletes_alarm() {
+async fn test_update_config_paused_keeps_state() {
     let storage = MockStorage::new();
     let env = MockEnv::new();
     let client = MockHttpClient::new();
+    let logic = create_logic(storage, env, client);
 
-    // 手动设置 alarm 但没有 config
-    *storage.alarm.borrow_mut() = Some(Duration::from_secs(60));
+    let config = create_test_config();
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic
+        .switch_monitor(SwitchMonitorCmd { paused: true })
+        .await
+        .unwrap();
+    let mut stored: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    stored.failed_checks = 3;
+    stored.last_outcome = Some(CheckOutcome::Error);
+    logic.storage.put(STATE_KEY_CONFIG, &stored).await.unwrap();
+
+    let response = logic
+        .update_config(UpdateConfigCmd {
+            time_config: Some(TimeConfig {
+                check_interval: DurationSecs::from_hours(6),
+                ..TimeConfig::default()
+            }),
+            comparison_mode: Some(ComparisonMode::UpdatedAt),
+            dispatch_token_secret: Some("OTHER_PAT".into()),
+        })
+        .await
+        .unwrap();
 
-    let logic = create_logic(storage, env, client);
-    logic.on_alarm().await.unwrap();
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert_eq!(
+        saved.request.time_config.check_interval,
+        DurationSecs::from_hours(6)
+    );
+    assert_eq!(saved.request.comparison_mode, ComparisonMode::UpdatedAt);
+    assert_eq!(
+        saved.request.dispatch_token_secret.as_deref(),
+        Some("OTHER_PAT")
+    );
+    // 监控自身维护的字段与暂停状态不变，暂停中的监控不会重新调度
+    assert!(saved.state.is_paused());
+    assert_eq!(saved.failed_checks, 3);
+    assert_eq!(saved.last_outcome, Some(CheckOutcome::Error));
+    assert!(logic.storage.get_alarm().await.unwrap().is_none());
+    assert_eq!(response.changes.len(), 3);
+}
 
-    // alarm 应该被删除
-    assert!(logic.storage.alarm.borrow().is_none());
+#[tokio::test]
+async fn test_update_config_shorter_interval_rearms_alarm() {
+    let logic = create_logic(MockStorage::new(), MockEnv::new(), MockHttpClient::new());
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    // 模拟按 24h 间隔调度的运行中监控
+    let before = Date::now_timestamp();
+    logic
+        .reschedule(RescheduleCmd {
+            next_check_at: before + Duration::from_secs(24 * 3600),
+        })
+        .await
+        .unwrap();
+
+    logic
+        .update_config(UpdateConfigCmd {
+            time_config: Some(TimeConfig {
+                check_interval: DurationSecs::from_secs(15 * 60),
+                ..TimeConfig::default()
+            }),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let after = Date::now_timestamp();
+
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    let next_check_at = saved.next_check_at().unwrap();
+    assert!(next_check_at >= before + Duration::from_secs(15 * 60));
+    assert!(next_check_at <= after + Duration::from_secs(15 * 60));
+    let alarm = logic.storage.get_alarm().await.unwrap().unwrap();
+    assert!(alarm <= Duration::from_secs(15 * 60));
 }
 
 #[tokio::test]
-async fn test_on_alarm_paused_deletes_alarm() {
+async fn test_update_config_longer_interval_keeps_earlier_check() {
+    let logic = create_logic(MockStorage::new(), MockEnv::new(), MockHttpClient::new());
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    let target = Date::now_timestamp() + Duration::from_secs(600);
+    logic
+        .reschedule(RescheduleCmd {
+            next_check_at: target,
+        })
+        .await
+        .unwrap();
+
+    logic
+        .update_config(UpdateConfigCmd {
+            time_config: Some(TimeConfig {
+                check_interval: DurationSecs::from_hours(24),
+                ..TimeConfig::default()
+            }),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    // 已安排的更早检查保持不变
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert_eq!(saved.next_check_at(), Some(target));
+    let alarm = logic.storage.get_alarm().await.unwrap().unwrap();
+    assert!(alarm <= Duration::from_secs(600));
+}
+
+#[tokio::test]
+async fn test_update_config_disabled_monitor() {
     let storage = MockStorage::new();
     let env = MockEnv::new();
     let client = MockHttpClient::new();
     let logic = create_logic(storage, env, client);
 
-    // setup 然后暂停
     let config = create_test_config();
     logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic
+        .set_enabled(SetEnabledCmd { enabled: false })
+        .await
+        .unwrap();
+
+    let response = logic
+        .update_config(UpdateConfigCmd {
+            comparison_mode: Some(ComparisonMode::UpdatedAt),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert!(!response.config.enabled);
+    assert_eq!(
+        response.config.request.comparison_mode,
+        ComparisonMode::UpdatedAt
+    );
+    assert!(logic.storage.get_alarm().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_update_config_no_config_returns_error() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+    let logic = create_logic(storage, env, client);
+
+    let err = logic
+        .update_config(UpdateConfigCmd::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.status, WatchErrorStatus::NotFound);
+}
+
+// =========================================================
+// reschedule 测试
+// =========================================================
+
+#[tokio::test]
+async fn test_reschedule_sets_alarm_to_delta() {
+    let logic = create_logic(MockStorage::new(), MockEnv::new(), MockHttpClient::new());
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+
+    let before = Date::now_timestamp();
+    let target = before + Duration::from_secs(7200);
+    logic
+        .reschedule(RescheduleCmd {
+            next_check_at: target,
+        })
+        .await
+        .unwrap();
+    let after = Date::now_timestamp();
+
+    // alarm 延迟等于 target 与调用时刻之差
+    let alarm = logic.storage.get_alarm().await.unwrap().unwrap();
+    assert!(alarm <= target - before);
+    assert!(alarm >= target - after);
+
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert_eq!(saved.state.next_check_at(), Some(target));
+}
+
+#[tokio::test]
+async fn test_reschedule_past_timestamp_clamps_to_zero() {
+    let logic = create_logic(MockStorage::new(), MockEnv::new(), MockHttpClient::new());
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+
+    let before = Date::now_timestamp();
+    logic
+        .reschedule(RescheduleCmd {
+            next_check_at: Timestamp::new(0),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        logic.storage.get_alarm().await.unwrap(),
+        Some(Duration::ZERO)
+    );
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert!(saved.state.next_check_at().unwrap() >= before);
+}
+
+#[tokio::test]
+async fn test_reschedule_paused_monitor_returns_error() {
+    let logic = create_logic(MockStorage::new(), MockEnv::new(), MockHttpClient::new());
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
     logic
         .switch_monitor(SwitchMonitorCmd { paused: true })
         .await
         .unwrap();
 
-    // 手动设置 alarm 模拟意外情况
-    *logic.storage.alarm.borrow_mut() = Some(Duration::from_secs(60));
+    let err = logic
+        .reschedule(RescheduleCmd {
+            next_check_at: Date::now_timestamp(),
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(err.status, WatchErrorStatus::Conflict);
+    assert!(logic.storage.get_alarm().await.unwrap().is_none());
+}
 
-    // 触发 alarm
-    logic.on_alarm().await.unwrap();
+#[tokio::test]
+async fn test_reschedule_no_config_returns_error() {
+    let logic = create_logic(MockStorage::new(), MockEnv::new(), MockHttpClient::new());
+    let err = logic
+        .reschedule(RescheduleCmd {
+            next_check_at: Date::now_timestamp(),
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(err.status, WatchErrorStatus::NotFound);
+}
 
-    // alarm 应该被删除
-    assert!(logic.storage.alarm.borrow().is_none());
+// =========================================================
+// trigger 测试 (无 config 情况)
+// =========================================================
+
+// =========================================================
+// health_check 测试
+// =========================================================
+
+#[tokio::test]
+async fn test_health_check_rearms_lost_alarm() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    // 模拟平台丢失 Alarm
+    logic.storage.delete_alarm().await.unwrap();
+
+    assert!(logic.health_check(HealthCheckCmd).await.unwrap());
+    let alarm = logic.storage.get_alarm().await.unwrap().unwrap();
+    assert!(alarm <= Duration::from_secs(60));
+
+    // Alarm 已存在时不再处理
+    assert!(!logic.health_check(HealthCheckCmd).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_health_check_ignores_paused_monitor() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    logic
+        .switch_monitor(SwitchMonitorCmd { paused: true })
+        .await
+        .unwrap();
+
+    assert!(!logic.health_check(HealthCheckCmd).await.unwrap());
+    assert!(logic.storage.get_alarm().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_health_check_no_config_is_noop() {
+    let logic = create_logic(MockStorage::new(), MockEnv::new(), MockHttpClient::new());
+    assert!(!logic.health_check(HealthCheckCmd).await.unwrap());
+    assert!(logic.storage.get_alarm().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_trigger_no_config_returns_error() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+    let logic = create_logic(storage, env, client);
+
+    let result = logic.trigger(TriggerCheckCmd).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_trigger_disabled_monitor_returns_conflict() {
+    let client = MockHttpClient::new();
+    let logic = create_logic(MockStorage::new(), MockEnv::new(), client);
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    logic
+        .set_enabled(SetEnabledCmd { enabled: false })
+        .await
+        .unwrap();
+
+    let err = logic.trigger(TriggerCheckCmd).await.unwrap_err();
+    assert_eq!(err.status, WatchErrorStatus::Conflict);
+    // 不访问上游
+    assert!(logic.client.requests.borrow().is_empty());
+}
+
+// =========================================================
+// on_alarm 测试
+// =========================================================
+
+#[tokio::test]
+async fn test_on_alarm_no_config_deletes_alarm() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+
+    // 手动设置 alarm 但没有 config
+    storage.set_alarm(Duration::from_secs(60)).await.unwrap();
+
+    let logic = create_logic(storage, env, client);
+    logic.on_alarm().await.unwrap();
+
+    // alarm 应该被删除
+    assert!(logic.storage.get_alarm().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_on_alarm_paused_deletes_alarm() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+    let logic = create_logic(storage, env, client);
+
+    // setup 然后暂停
+    let config = create_test_config();
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic
+        .switch_monitor(SwitchMonitorCmd { paused: true })
+        .await
+        .unwrap();
+
+    // 手动设置 alarm 模拟意外情况
+    logic
+        .storage
+        .set_alarm(Duration::from_secs(60))
+        .await
+        .unwrap();
+
+    // 触发 alarm
+    logic.on_alarm().await.unwrap();
+
+    // alarm 应该被删除
+    assert!(logic.storage.get_alarm().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_on_alarm_disabled_skips_check_and_deletes_alarm() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    let logic = create_logic(storage, env, client);
+
+    let config = create_test_config();
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic
+        .set_enabled(SetEnabledCmd { enabled: false })
+        .await
+        .unwrap();
+
+    // 手动设置 alarm 模拟禁用前已排期的唤醒
+    logic
+        .storage
+        .set_alarm(Duration::from_secs(60))
+        .await
+        .unwrap();
+
+    logic.on_alarm().await.unwrap();
+
+    assert!(logic.storage.get_alarm().await.unwrap().is_none());
+    // 未发起任何请求，也未记录历史
+    assert!(logic.client.requests.borrow().is_empty());
+    let history: Option<Vec<CheckEvent>> = logic.storage.get(STATE_KEY_HISTORY).await.unwrap();
+    assert!(history.is_none());
+}
+
+// =========================================================
+// 检查历史测试
+// =========================================================
+
+const RELEASE_URL: &str = "https://api.github.com/repos/owner/repo/releases/latest";
+const DISPATCH_URL: &str = "https://api.github.com/repos/my_owner/my_repo/dispatches";
+
+fn mock_release(client: &MockHttpClient, tag: &str) {
+    client.mock_response(
+        RELEASE_URL,
+        200,
+        serde_json::json!({
+            "tag_name": tag,
+            "published_at": "2024-01-15T10:30:00Z",
+            "updated_at": "2024-01-15T10:30:00Z"
+        }),
+    );
+}
+
+#[tokio::test]
+async fn test_on_alarm_records_history_in_order() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+
+    // 第一次：发现新版本并 Dispatch
+    logic.on_alarm().await.unwrap();
+
+    // 第二次：上游 API 报错
+    logic
+        .client
+        .mock_response(RELEASE_URL, 500, serde_json::json!({}));
+    logic.on_alarm().await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].outcome, CheckOutcome::Success);
+    assert!(history[0].detail.contains("v1.0.0"));
+    assert_eq!(history[1].outcome, CheckOutcome::Error);
+    assert!(history[1].detail.contains("500"));
+    assert!(history[0].at <= history[1].at);
+
+    // 失败次数累计在配置中，供指标使用
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert_eq!(saved.failed_checks, 1);
+}
+
+#[tokio::test]
+async fn test_on_alarm_records_no_change() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+
+    logic.on_alarm().await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[1].outcome, CheckOutcome::NoChange);
+}
+
+#[tokio::test]
+async fn test_on_alarm_sets_last_dispatch_at() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert_eq!(saved.last_dispatch_at, None);
+
+    let before = Date::now_timestamp();
+    logic.on_alarm().await.unwrap();
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    let dispatched_at = saved
+        .last_dispatch_at
+        .expect("last_dispatch_at should be set");
+    assert!(dispatched_at >= before);
+    assert_eq!(saved.last_outcome, Some(CheckOutcome::Success));
+
+    // 版本未变化时不更新
+    logic.on_alarm().await.unwrap();
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert_eq!(saved.last_dispatch_at, Some(dispatched_at));
+    assert_eq!(saved.last_outcome, Some(CheckOutcome::NoChange));
+}
+
+#[tokio::test]
+async fn test_on_alarm_uses_configured_user_agent() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new()
+        .with_secret("MY_GITHUB_PAT", "pat")
+        .with_var("GITHUB_USER_AGENT", "verwatch-test (ops@example.com)");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let requests = logic.client.requests.borrow();
+    assert!(requests.iter().any(|(url, ..)| url == DISPATCH_URL));
+    for (_, _, headers, _) in requests.iter() {
+        assert_eq!(
+            headers.get("User-Agent").map(String::as_str),
+            Some("verwatch-test (ops@example.com)")
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_feed_entry_reports_stored_version() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    let entry = logic
+        .get_feed_entry(GetFeedEntryCmd)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(entry.upstream, "owner/repo");
+    assert_eq!(entry.current_version, None);
+    assert_eq!(entry.checked_at, None);
+
+    logic.on_alarm().await.unwrap();
+    let entry = logic
+        .get_feed_entry(GetFeedEntryCmd)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(entry.current_version.as_deref(), Some("v1.0.0"));
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(entry.checked_at, Some(history[0].at));
+
+    // 禁用后不计入 Feed
+    logic
+        .set_enabled(SetEnabledCmd { enabled: false })
+        .await
+        .unwrap();
+    assert!(
+        logic
+            .get_feed_entry(GetFeedEntryCmd)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_with_state_reports_config_and_version() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    let config = create_test_config();
+    logic
+        .setup(SetupMonitorCmd {
+            config: config.clone(),
+        })
+        .await
+        .unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let item = logic
+        .get_with_state(GetWithStateCmd)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(item.config.unique_key, config.unique_key);
+    assert_eq!(item.current_version.as_deref(), Some("v1.0.0"));
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(item.checked_at, history.last().map(|e| e.at));
+
+    // 禁用后不返回
+    logic
+        .set_enabled(SetEnabledCmd { enabled: false })
+        .await
+        .unwrap();
+    assert!(
+        logic
+            .get_with_state(GetWithStateCmd)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_trigger_records_history_and_outcome() {
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(MockStorage::new(), env, client);
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    let scheduled: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+
+    logic.trigger(TriggerCheckCmd).await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].outcome, CheckOutcome::Success);
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert_eq!(saved.last_outcome, Some(CheckOutcome::Success));
+    assert!(saved.last_dispatch_at.is_some());
+    // 手动检查不改变已安排的下一次检查
+    assert_eq!(saved.next_check_at(), scheduled.next_check_at());
+}
+
+#[tokio::test]
+async fn test_trigger_failure_is_recorded() {
+    let client = MockHttpClient::new();
+    client.mock_response(RELEASE_URL, 500, serde_json::json!({}));
+    let logic = create_logic(MockStorage::new(), MockEnv::new(), client);
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+
+    assert!(logic.trigger(TriggerCheckCmd).await.is_err());
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].outcome, CheckOutcome::Error);
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert_eq!(saved.last_outcome, Some(CheckOutcome::Error));
+    assert_eq!(saved.failed_checks, 1);
+}
+
+#[tokio::test]
+async fn test_history_is_capped() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+    client.mock_response(RELEASE_URL, 500, serde_json::json!({}));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+
+    for _ in 0..HISTORY_CAPACITY + 5 {
+        logic.on_alarm().await.unwrap();
+    }
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history.len(), HISTORY_CAPACITY);
+}
+
+#[tokio::test]
+async fn test_stop_clears_history() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+    client.mock_response(RELEASE_URL, 500, serde_json::json!({}));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    logic.on_alarm().await.unwrap();
+    logic.stop(StopMonitorCmd).await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert!(history.is_empty());
+}
+
+// =========================================================
+// Tag 过滤测试
+// =========================================================
+
+#[tokio::test]
+async fn test_tag_pattern_match_dispatches() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.2.3");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.tag_pattern = Some(r"^v\d+\.\d+\.\d+$".into());
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[0].outcome, CheckOutcome::Success);
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert_eq!(version.unwrap().tag_name, "v1.2.3");
+}
+
+#[tokio::test]
+async fn test_tag_pattern_mismatch_skips_without_updating_version() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "nightly-20240115");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.tag_pattern = Some(r"^v\d+\.\d+\.\d+$".into());
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[0].outcome, CheckOutcome::NoChange);
+    assert!(history[0].detail.contains("No matching tag"));
+
+    // 未触发 Dispatch，也未写入版本状态
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert!(version.is_none());
+    assert!(
+        !logic
+            .client
+            .requests
+            .borrow()
+            .iter()
+            .any(|(url, ..)| url == DISPATCH_URL)
+    );
+}
+
+// =========================================================
+// Dispatch 失败重试测试
+// =========================================================
+
+fn release_requests(client: &MockHttpClient) -> usize {
+    client
+        .requests
+        .borrow()
+        .iter()
+        .filter(|(url, ..)| url == RELEASE_URL)
+        .count()
+}
+
+#[tokio::test]
+async fn test_failed_dispatch_is_retried_without_refetching() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    // 第一次 Dispatch 失败，之后成功
+    client.mock_sequence(
+        DISPATCH_URL,
+        &[
+            (502, serde_json::json!({}), &[]),
+            (204, serde_json::json!(null), &[]),
+        ],
+    );
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+
+    // 第一次：检测到新版本，但 Dispatch 失败
+    logic.on_alarm().await.unwrap();
+    let pending: PendingDispatch = logic
+        .storage
+        .get(STATE_KEY_PENDING_DISPATCH)
+        .await
+        .unwrap()
+        .expect("pending dispatch should be saved");
+    assert_eq!(pending.release.tag_name, "v1.0.0");
+    assert_eq!(pending.attempts, 1);
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert!(version.is_none());
+
+    // 第二次：上游不可用也不影响重试
+    logic
+        .client
+        .mock_response(RELEASE_URL, 500, serde_json::json!({}));
+    logic.on_alarm().await.unwrap();
+
+    assert_eq!(release_requests(&logic.client), 1);
+    assert_eq!(dispatch_requests(&logic.client), 2);
+    let pending: Option<PendingDispatch> =
+        logic.storage.get(STATE_KEY_PENDING_DISPATCH).await.unwrap();
+    assert!(pending.is_none());
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert_eq!(version.unwrap().tag_name, "v1.0.0");
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[0].outcome, CheckOutcome::Error);
+    assert_eq!(history[1].outcome, CheckOutcome::Success);
+    assert!(history[1].detail.contains("retry"));
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert!(saved.last_dispatch_at.is_some());
+    // 成功后连续失败计数清零
+    let failures: Option<u32> = logic.storage.get(STATE_KEY_FAILURES).await.unwrap();
+    assert!(failures.is_none());
+}
+
+#[tokio::test]
+async fn test_pending_dispatch_gives_up_after_max_attempts() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 502, serde_json::json!({}));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+
+    for _ in 0..MAX_DISPATCH_ATTEMPTS {
+        logic.on_alarm().await.unwrap();
+    }
+    assert_eq!(release_requests(&logic.client), 1);
+    assert_eq!(
+        dispatch_requests(&logic.client),
+        MAX_DISPATCH_ATTEMPTS as usize
+    );
+    let pending: Option<PendingDispatch> =
+        logic.storage.get(STATE_KEY_PENDING_DISPATCH).await.unwrap();
+    assert!(pending.is_none());
+
+    // 放弃后恢复正常流程，重新请求上游
+    logic.on_alarm().await.unwrap();
+    assert_eq!(release_requests(&logic.client), 2);
+}
+
+#[tokio::test]
+async fn test_stop_clears_pending_dispatch() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 502, serde_json::json!({}));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    logic.on_alarm().await.unwrap();
+    logic.stop(StopMonitorCmd).await.unwrap();
+
+    let pending: Option<PendingDispatch> =
+        logic.storage.get(STATE_KEY_PENDING_DISPATCH).await.unwrap();
+    assert!(pending.is_none());
+}
+
+// =========================================================
+// 全局停用 Dispatch 测试
+// =========================================================
+
+#[tokio::test]
+async fn test_dispatch_disabled_advances_version_without_dispatch() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new()
+        .with_secret("MY_GITHUB_PAT", "pat")
+        .with_var("DISPATCH_DISABLED", "true");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    logic.on_alarm().await.unwrap();
+
+    assert_eq!(dispatch_requests(&logic.client), 0);
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert_eq!(version.unwrap().tag_name, "v1.0.0");
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert!(history[0].detail.contains("suppressed"));
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert_eq!(saved.last_dispatch_at, None);
+
+    // 强制 Dispatch 同样被拒绝
+    let err = logic.force_dispatch(ForceDispatchCmd).await.unwrap_err();
+    assert_eq!(err.status, WatchErrorStatus::Conflict);
+    assert_eq!(dispatch_requests(&logic.client), 0);
+}
+
+#[tokio::test]
+async fn test_dispatch_disabled_drops_pending_dispatch() {
+    let storage = MockStorage::new();
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.1.0");
+    let logic = create_logic(
+        storage,
+        MockEnv::new().with_var("DISPATCH_DISABLED", "1"),
+        client,
+    );
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    let stale = GitHubRelease {
+        tag_name: "v1.0.0".into(),
+        timestamp: crate::utils::github::release::ReleaseTimestamp::Published(
+            verwatch_shared::Timestamp::new(0),
+        ),
+        html_url: None,
+        body: None,
+    };
+    logic
+        .storage
+        .put(
+            STATE_KEY_PENDING_DISPATCH,
+            &PendingDispatch {
+                release: stale,
+                attempts: 1,
+            },
+        )
+        .await
+        .unwrap();
+    logic.on_alarm().await.unwrap();
+
+    assert_eq!(dispatch_requests(&logic.client), 0);
+    let pending: Option<PendingDispatch> =
+        logic.storage.get(STATE_KEY_PENDING_DISPATCH).await.unwrap();
+    assert!(pending.is_none());
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert_eq!(version.unwrap().tag_name, "v1.1.0");
+}
+
+// =========================================================
+// 强制 Dispatch 测试
+// =========================================================
+
+fn dispatch_requests(client: &MockHttpClient) -> usize {
+    client
+        .requests
+        .borrow()
+        .iter()
+        .filter(|(url, ..)| url == DISPATCH_URL)
+        .count()
+}
+
+#[tokio::test]
+async fn test_force_dispatch_fires_even_when_version_unchanged() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    logic.on_alarm().await.unwrap();
+    assert_eq!(dispatch_requests(&logic.client), 1);
+    let stored_before: GitHubRelease = logic.storage.get(STATE_KEY_VERSION).await.unwrap().unwrap();
+    let config_before: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+
+    // 本地与上游版本相同，仍然触发 Dispatch
+    let tag = logic.force_dispatch(ForceDispatchCmd).await.unwrap();
+    assert_eq!(tag, "v1.0.0");
+    assert_eq!(dispatch_requests(&logic.client), 2);
+
+    // 不修改版本状态与上次 Dispatch 时间
+    let stored_after: GitHubRelease = logic.storage.get(STATE_KEY_VERSION).await.unwrap().unwrap();
+    assert_eq!(stored_after.tag_name, stored_before.tag_name);
+    let config_after: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    assert_eq!(
+        config_after.last_dispatch_at,
+        config_before.last_dispatch_at
+    );
+}
+
+#[tokio::test]
+async fn test_force_dispatch_without_stored_version_keeps_state_empty() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v2.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+
+    let tag = logic.force_dispatch(ForceDispatchCmd).await.unwrap();
+    assert_eq!(tag, "v2.0.0");
+    assert_eq!(dispatch_requests(&logic.client), 1);
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert!(version.is_none());
+}
+
+#[tokio::test]
+async fn test_force_dispatch_no_config_returns_error() {
+    let logic = create_logic(MockStorage::new(), MockEnv::new(), MockHttpClient::new());
+
+    let result = logic.force_dispatch(ForceDispatchCmd).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_version_constraint_match_dispatches() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.4.2");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.version_constraint = Some(">=1.0, <2.0".into());
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[0].outcome, CheckOutcome::Success);
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert_eq!(version.unwrap().tag_name, "v1.4.2");
+}
+
+#[tokio::test]
+async fn test_version_constraint_mismatch_skips_without_updating_version() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v2.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.version_constraint = Some(">=1.0, <2.0".into());
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[0].outcome, CheckOutcome::NoChange);
+    assert!(history[0].detail.contains("Outside version constraint"));
+
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert!(version.is_none());
+    assert!(
+        !logic
+            .client
+            .requests
+            .borrow()
+            .iter()
+            .any(|(url, ..)| url == DISPATCH_URL)
+    );
+}
+
+// =========================================================
+// 结构化日志测试
+// =========================================================
+
+#[test]
+fn test_check_log_to_json_has_expected_fields() {
+    let mut log = CheckLog::start("owner/repo->my_owner/my_repo");
+    log.remote_tag = Some("v2.0.0".into());
+    log.old_tag = Some("v1.0.0".into());
+    log.finish(CheckOutcome::Success);
+
+    let line = log.to_json();
+    assert!(!line.contains('\n'));
+    let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+    assert_eq!(value["unique_key"], "owner/repo->my_owner/my_repo");
+    assert_eq!(value["outcome"], "success");
+    assert_eq!(value["remote_tag"], "v2.0.0");
+    assert_eq!(value["old_tag"], "v1.0.0");
+    assert!(value["duration_ms"].is_u64());
+    assert_eq!(value["at"].as_i64(), Some(log.at.as_millis()));
+}
+
+#[test]
+fn test_check_log_missing_tags_serialize_as_null() {
+    let mut log = CheckLog::start("key");
+    log.finish(CheckOutcome::Error);
+
+    let value: serde_json::Value = serde_json::from_str(&log.to_json()).unwrap();
+    assert_eq!(value["outcome"], "error");
+    assert!(value["remote_tag"].is_null());
+    assert!(value["old_tag"].is_null());
+}
+
+// =========================================================
+// 试运行测试
+// =========================================================
+
+#[tokio::test]
+async fn test_dry_run_records_version_without_dispatch() {
+    let storage = MockStorage::new();
+    // 不配置 PAT：试运行不应需要 Dispatch 令牌
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.dry_run = true;
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[0].outcome, CheckOutcome::Success);
+    assert!(history[0].detail.contains("Dry run: would dispatch v1.0.0"));
+
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert_eq!(version.unwrap().tag_name, "v1.0.0");
+    assert!(
+        !logic
+            .client
+            .requests
+            .borrow()
+            .iter()
+            .any(|(url, ..)| url == DISPATCH_URL)
+    );
+}
+
+#[tokio::test]
+async fn test_dry_run_same_version_is_no_change() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new();
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.dry_run = true;
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic.on_alarm().await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[1].outcome, CheckOutcome::NoChange);
+}
+
+#[tokio::test]
+async fn test_comparison_mode_change_treats_release_as_new() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    client.mock_response(
+        RELEASE_URL,
+        200,
+        serde_json::json!({
+            "tag_name": "v1.0.0",
+            "created_at": "2024-01-10T00:00:00Z",
+            "published_at": "2024-01-15T10:30:00Z",
+            "updated_at": "2024-01-15T10:30:00Z"
+        }),
+    );
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    logic.on_alarm().await.unwrap();
+
+    // 改为 CreatedAt 后，已存储的 Published 版本无法比较，按新版本处理并覆盖
+    let mut config = logic.get_config(GetConfigCmd).await.unwrap().unwrap();
+    config.request.comparison_mode = ComparisonMode::CreatedAt;
+    logic.storage.put(STATE_KEY_CONFIG, &config).await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[1].outcome, CheckOutcome::Success);
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert!(matches!(
+        version.unwrap().timestamp,
+        crate::utils::github::release::ReleaseTimestamp::Created(_)
+    ));
+
+    // 同一版本再次检查：模式一致，无变化
+    logic.on_alarm().await.unwrap();
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[2].outcome, CheckOutcome::NoChange);
+}
+
+// =========================================================
+// 基线测试
+// =========================================================
+
+fn dispatch_count(
+    logic: &ProjectMonitorLogicTestable<MockStorage, MockEnv, MockHttpClient>,
+) -> usize {
+    logic
+        .client
+        .requests
+        .borrow()
+        .iter()
+        .filter(|(url, ..)| url == DISPATCH_URL)
+        .count()
+}
+
+#[tokio::test]
+async fn test_initialize_baseline_skips_first_dispatch() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.initialize_baseline = true;
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+
+    // 第一次：只记录基线
+    logic.on_alarm().await.unwrap();
+    assert_eq!(dispatch_count(&logic), 0);
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert_eq!(version.unwrap().tag_name, "v1.0.0");
+
+    // 第二次：上游发布更新的版本后触发 Dispatch
+    logic.client.mock_response(
+        RELEASE_URL,
+        200,
+        serde_json::json!({
+            "tag_name": "v1.1.0",
+            "published_at": "2024-02-01T00:00:00Z",
+            "updated_at": "2024-02-01T00:00:00Z"
+        }),
+    );
+    logic.on_alarm().await.unwrap();
+    assert_eq!(dispatch_count(&logic), 1);
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[0].outcome, CheckOutcome::NoChange);
+    assert!(history[0].detail.contains("Baseline recorded: v1.0.0"));
+    assert_eq!(history[1].outcome, CheckOutcome::Success);
+}
+
+#[tokio::test]
+async fn test_without_baseline_first_check_dispatches() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v1.0.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    logic
+        .setup(SetupMonitorCmd {
+            config: create_test_config(),
+        })
+        .await
+        .unwrap();
+    logic.on_alarm().await.unwrap();
+    assert_eq!(dispatch_count(&logic), 1);
+}
+
+// =========================================================
+// 上游平台测试
+// =========================================================
+
+#[tokio::test]
+async fn test_gitlab_provider_fetches_gitlab_and_dispatches_to_github() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new()
+        .with_secret("MY_GITHUB_PAT", "pat")
+        .with_secret("GITLAB_TOKEN", "glpat");
+    let client = MockHttpClient::new();
+    client.mock_response(
+        "https://gitlab.com/api/v4/projects/owner%2Frepo/releases?per_page=1",
+        200,
+        serde_json::json!([{
+            "tag_name": "v2.0.0",
+            "released_at": "2024-01-15T10:30:00Z",
+            "created_at": "2024-01-15T10:30:00Z"
+        }]),
+    );
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.provider = verwatch_shared::Provider::GitLab;
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[0].outcome, CheckOutcome::Success);
+
+    let requests = logic.client.requests.borrow();
+    // 未请求 GitHub Releases
+    assert!(!requests.iter().any(|(url, ..)| url == RELEASE_URL));
+    let (_, _, headers, body) = requests
+        .iter()
+        .find(|(url, ..)| url == DISPATCH_URL)
+        .expect("dispatch request not sent");
+    assert_eq!(
+        headers.get("Authorization").map(String::as_str),
+        Some("Bearer pat")
+    );
+    assert!(body.as_deref().unwrap().contains("v2.0.0"));
+}
+
+// =========================================================
+// 通知测试
+// =========================================================
+
+const WEBHOOK_URL: &str = "https://discord.com/api/webhooks/1/token";
+
+#[tokio::test]
+async fn test_notify_webhook_receives_repo_and_version() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v3.1.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    client.mock_response(WEBHOOK_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.notify_webhook = Some(WEBHOOK_URL.into());
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let requests = logic.client.requests.borrow();
+    let (_, method, _, body) = requests
+        .iter()
+        .find(|(url, ..)| url == WEBHOOK_URL)
+        .expect("webhook not called");
+    assert_eq!(method, "Post");
+    let body: serde_json::Value = serde_json::from_str(body.as_deref().unwrap()).unwrap();
+    let content = body["content"].as_str().unwrap();
+    assert!(content.contains("owner/repo"));
+    assert!(content.contains("v3.1.0"));
+}
+
+#[tokio::test]
+async fn test_telegram_token_is_resolved_from_secret() {
+    const TELEGRAM_URL: &str = "https://api.telegram.org/bot123:abc/sendMessage";
+    let storage = MockStorage::new();
+    let env = MockEnv::new()
+        .with_secret("MY_GITHUB_PAT", "pat")
+        .with_secret("TG_BOT_TOKEN", "123:abc");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v3.1.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    client.mock_response(TELEGRAM_URL, 200, serde_json::json!({ "ok": true }));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.telegram = Some(verwatch_shared::TelegramConfig {
+        bot_token_secret: "TG_BOT_TOKEN".into(),
+        chat_id: "42".into(),
+    });
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let requests = logic.client.requests.borrow();
+    let (_, _, _, body) = requests
+        .iter()
+        .find(|(url, ..)| url == TELEGRAM_URL)
+        .expect("telegram not called");
+    let body: serde_json::Value = serde_json::from_str(body.as_deref().unwrap()).unwrap();
+    assert_eq!(body["chat_id"], "42");
+    assert!(body["text"].as_str().unwrap().contains("v3.1.0"));
+}
+
+#[tokio::test]
+async fn test_notify_failure_does_not_fail_check() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v3.1.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    client.mock_response(WEBHOOK_URL, 500, serde_json::json!({}));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.notify_webhook = Some(WEBHOOK_URL.into());
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[0].outcome, CheckOutcome::Success);
+}
+
+#[tokio::test]
+async fn test_template_webhook_receives_old_and_new_version() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    client.mock_response("https://example.com/hook", 200, serde_json::json!({}));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.webhook = Some(verwatch_shared::WebhookConfig {
+        url: "https://example.com/hook".into(),
+        template: r#"{"text":"{repo}: {old_version} -> {version}"}"#.into(),
+    });
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+
+    // 已有旧版本记录
+    logic
+        .storage
+        .put(
+            STATE_KEY_VERSION,
+            &GitHubRelease {
+                tag_name: "v1.0.0".into(),
+                timestamp: crate::utils::github::release::ReleaseTimestamp::Published(
+                    verwatch_shared::Timestamp::new(0),
+                ),
+                html_url: None,
+                body: None,
+            },
+        )
+        .await
+        .unwrap();
+    mock_release(&logic.client, "v2.0.0");
+    logic.on_alarm().await.unwrap();
+
+    let requests = logic.client.requests.borrow();
+    let (_, _, _, body) = requests
+        .iter()
+        .find(|(url, ..)| url == "https://example.com/hook")
+        .expect("webhook not called");
+    assert_eq!(
+        body.as_deref(),
+        Some(r#"{"text":"owner/repo: v1.0.0 -> v2.0.0"}"#)
+    );
+}
+
+#[tokio::test]
+async fn test_subrequest_budget_skips_extra_notifications() {
+    const SLACK_URL: &str = "https://hooks.slack.com/services/T/B/X";
+    const HOOK_URL: &str = "https://example.com/hook";
+    let storage = MockStorage::new();
+    // 上游 1 + Dispatch 1，只剩 1 个子请求用于通知
+    let env = MockEnv::new()
+        .with_secret("MY_GITHUB_PAT", "pat")
+        .with_var("SUBREQUEST_BUDGET", "3");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v3.1.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    client.mock_response(WEBHOOK_URL, 204, serde_json::json!(null));
+    client.mock_response(SLACK_URL, 200, serde_json::json!({}));
+    client.mock_response(HOOK_URL, 200, serde_json::json!({}));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.notify_webhook = Some(WEBHOOK_URL.into());
+    config.request.slack_webhook = Some(SLACK_URL.into());
+    config.request.webhook = Some(verwatch_shared::WebhookConfig {
+        url: HOOK_URL.into(),
+        template: r#"{"text":"{version}"}"#.into(),
+    });
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    {
+        let requests = logic.client.requests.borrow();
+        let called = |target: &str| requests.iter().any(|(url, ..)| url == target);
+        assert!(called(DISPATCH_URL));
+        assert!(called(WEBHOOK_URL));
+        assert!(!called(SLACK_URL));
+        assert!(!called(HOOK_URL));
+    }
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[0].outcome, CheckOutcome::Success);
+}
+
+// =========================================================
+// 失败退避测试
+// =========================================================
+
+#[test]
+fn test_backoff_interval_is_bounded_by_check_interval() {
+    let time_config = TimeConfig {
+        check_interval: DurationSecs::from_secs(300),
+        retry_interval: DurationSecs::from_secs(10),
+        ..TimeConfig::default()
+    };
+
+    assert_eq!(
+        backoff_interval(&time_config, 0),
+        DurationSecs::from_secs(10)
+    );
+    assert_eq!(
+        backoff_interval(&time_config, 1),
+        DurationSecs::from_secs(20)
+    );
+    assert_eq!(
+        backoff_interval(&time_config, 4),
+        DurationSecs::from_secs(160)
+    );
+    assert_eq!(
+        backoff_interval(&time_config, 5),
+        DurationSecs::from_secs(300)
+    );
+    assert_eq!(
+        backoff_interval(&time_config, u32::MAX),
+        DurationSecs::from_secs(300)
+    );
+}
+
+#[tokio::test]
+async fn test_on_alarm_backoff_grows_then_resets() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    client.mock_response(RELEASE_URL, 500, serde_json::json!({}));
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.time_config = TimeConfig {
+        check_interval: DurationSecs::from_secs(3600),
+        retry_interval: DurationSecs::from_secs(10),
+        ..TimeConfig::default()
+    };
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+
+    // 连续失败：间隔逐次翻倍
+    for expected in [10, 20, 40, 80] {
+        logic.on_alarm().await.unwrap();
+        assert_eq!(
+            logic.storage.get_alarm().await.unwrap(),
+            Some(Duration::from_secs(expected))
+        );
+    }
+
+    // 成功后恢复为 check_interval
+    mock_release(&logic.client, "v1.0.0");
+    logic.on_alarm().await.unwrap();
+    assert_eq!(
+        logic.storage.get_alarm().await.unwrap(),
+        Some(Duration::from_secs(3600))
+    );
+
+    // 计数已重置：再次失败从 retry_interval 开始
+    logic
+        .client
+        .mock_response(RELEASE_URL, 500, serde_json::json!({}));
+    logic.on_alarm().await.unwrap();
+    assert_eq!(
+        logic.storage.get_alarm().await.unwrap(),
+        Some(Duration::from_secs(10))
+    );
+}
+
+#[tokio::test]
+async fn test_on_alarm_applies_min_interval_floor() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    client.mock_response(RELEASE_URL, 500, serde_json::json!({}));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.time_config = TimeConfig {
+        check_interval: DurationSecs::from_secs(3600),
+        retry_interval: DurationSecs::from_secs(1),
+        ..TimeConfig::default()
+    };
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+
+    // retry_interval 低于下限时按下限调度
+    logic.on_alarm().await.unwrap();
+    assert_eq!(
+        logic.storage.get_alarm().await.unwrap(),
+        Some(Duration::from_secs(DEFAULT_MIN_ALARM_SECS))
+    );
+    let saved: ProjectConfig = logic.storage.get(STATE_KEY_CONFIG).await.unwrap().unwrap();
+    let next_check_at = saved.state.next_check_at().unwrap();
+    assert!(
+        next_check_at.signed_diff_secs(Date::now_timestamp()) >= DEFAULT_MIN_ALARM_SECS as i64 - 1
+    );
+}
+
+#[tokio::test]
+async fn test_min_alarm_interval_is_configurable() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new()
+        .with_secret("MY_GITHUB_PAT", "pat")
+        .with_var("MIN_ALARM_SECS", "30");
+    let client = MockHttpClient::new();
+    client.mock_response(RELEASE_URL, 500, serde_json::json!({}));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.time_config = TimeConfig {
+        check_interval: DurationSecs::from_secs(3600),
+        retry_interval: DurationSecs::from_secs(10),
+        ..TimeConfig::default()
+    };
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+
+    logic.on_alarm().await.unwrap();
+    assert_eq!(
+        logic.storage.get_alarm().await.unwrap(),
+        Some(Duration::from_secs(30))
+    );
+
+    // 恢复监控时的立即检查不受下限影响
+    logic
+        .switch_monitor(SwitchMonitorCmd { paused: true })
+        .await
+        .unwrap();
+    logic
+        .switch_monitor(SwitchMonitorCmd { paused: false })
+        .await
+        .unwrap();
+    assert_eq!(
+        logic.storage.get_alarm().await.unwrap(),
+        Some(Duration::from_millis(0))
+    );
+}
+
+/// 当前 UTC 小时 (偏移 `offset` 小时)
+fn utc_hour_from_now(offset: i64) -> u8 {
+    ((Date::now_timestamp().as_millis() / 3_600_000 + offset).rem_euclid(24)) as u8
+}
+
+#[tokio::test]
+async fn test_quiet_hours_defer_dispatch() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v3.1.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.quiet_hours = Some((utc_hour_from_now(0), utc_hour_from_now(1)));
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    // 未触发 Dispatch，也未记录版本
+    assert!(
+        !logic
+            .client
+            .requests
+            .borrow()
+            .iter()
+            .any(|(url, ..)| url == DISPATCH_URL)
+    );
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert!(version.is_none());
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert!(history[0].detail.contains("Quiet hours"));
+
+    // 下一次检查安排在时段结束时，早于 check_interval
+    let alarm = logic.storage.get_alarm().await.unwrap().unwrap();
+    assert!(alarm <= Duration::from_secs(3600));
+}
+
+#[tokio::test]
+async fn test_outside_quiet_hours_dispatches_normally() {
+    let storage = MockStorage::new();
+    let env = MockEnv::new().with_secret("MY_GITHUB_PAT", "pat");
+    let client = MockHttpClient::new();
+    mock_release(&client, "v3.1.0");
+    client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+    let logic = create_logic(storage, env, client);
+
+    let mut config = create_test_config();
+    config.request.quiet_hours = Some((utc_hour_from_now(1), utc_hour_from_now(2)));
+    logic.setup(SetupMonitorCmd { config }).await.unwrap();
+    logic.on_alarm().await.unwrap();
+
+    let history = logic.get_history(GetHistoryCmd).await.unwrap();
+    assert_eq!(history[0].outcome, CheckOutcome::Success);
+    let version: Option<GitHubRelease> = logic.storage.get(STATE_KEY_VERSION).await.unwrap();
+    assert_eq!(version.unwrap().tag_name, "v3.1.0");
+    assert_eq!(
+        logic.storage.get_alarm().await.unwrap(),
+        Some(Duration::from(
+            create_test_config().request.time_config.check_interval
+        ))
+    );
 }
 
 // =========================================================
@@ -1,6 +1,9 @@
 use crate::utils::rpc::ApiRequest;
 use serde::{Deserialize, Serialize};
-use verwatch_shared::ProjectConfig;
+use verwatch_shared::{
+    CheckEvent, ComparisonMode, ProjectConfig, ProjectWithState, TimeConfig, Timestamp,
+    protocol::{FeedEntry, UpdateProjectResponse},
+};
 
 // =========================================================
 // 指令定义
@@ -35,6 +38,71 @@ pub struct TriggerCheckCmd;
 impl ApiRequest for TriggerCheckCmd {
     type Response = ();
     const PATH: &'static str = "/monitor/trigger";
+    const LONG_RUNNING: bool = true;
+}
+
+/// 强制触发 Dispatch (ForceDispatch)
+/// 获取上游最新版本并无条件触发，不比较也不更新已存储的版本
+#[derive(Serialize, Deserialize)]
+pub struct ForceDispatchCmd;
+
+impl ApiRequest for ForceDispatchCmd {
+    type Response = String; // 触发的 Tag
+    const PATH: &'static str = "/monitor/force_dispatch";
+    const LONG_RUNNING: bool = true;
+}
+
+/// 重新安排下一次检查时间 (Reschedule)
+/// 不修改检查间隔，仅按 `next_check_at` 重设 Alarm；过去的时间会立即触发
+#[derive(Serialize, Deserialize)]
+pub struct RescheduleCmd {
+    pub next_check_at: Timestamp,
+}
+
+impl ApiRequest for RescheduleCmd {
+    type Response = ();
+    const PATH: &'static str = "/monitor/reschedule";
+}
+
+/// 更新可变配置 (UpdateConfig)
+/// 只修改提供的字段，保留监控状态、启用状态、调度与检查结果；返回更新后的配置与变化的字段
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateConfigCmd {
+    pub time_config: Option<TimeConfig>,
+    pub comparison_mode: Option<ComparisonMode>,
+    /// 空字符串表示清除覆盖，回退到默认的 PAT Secret
+    pub dispatch_token_secret: Option<String>,
+}
+
+impl UpdateConfigCmd {
+    /// 将提供的字段写入 `config`
+    pub fn apply(&self, config: &mut ProjectConfig) {
+        if let Some(time_config) = &self.time_config {
+            config.request.time_config = time_config.clone();
+        }
+        if let Some(mode) = self.comparison_mode {
+            config.request.comparison_mode = mode;
+        }
+        if let Some(secret) = &self.dispatch_token_secret {
+            let secret = secret.trim();
+            config.request.dispatch_token_secret = (!secret.is_empty()).then(|| secret.to_string());
+        }
+    }
+}
+
+impl ApiRequest for UpdateConfigCmd {
+    type Response = UpdateProjectResponse;
+    const PATH: &'static str = "/monitor/update";
+}
+
+/// 自愈检查 (Health Check)
+/// 运行中的监控若丢失了 Alarm，则按 `next_check_at` 重新设置；返回是否重新设置
+#[derive(Serialize, Deserialize)]
+pub struct HealthCheckCmd;
+
+impl ApiRequest for HealthCheckCmd {
+    type Response = bool;
+    const PATH: &'static str = "/monitor/health_check";
 }
 
 /// 获取当前配置
@@ -46,6 +114,26 @@ impl ApiRequest for GetConfigCmd {
     const PATH: &'static str = "/monitor/config";
 }
 
+/// 获取版本订阅条目 (Feed)
+/// 无配置或已禁用时返回 None
+#[derive(Serialize, Deserialize)]
+pub struct GetFeedEntryCmd;
+
+impl ApiRequest for GetFeedEntryCmd {
+    type Response = Option<FeedEntry>;
+    const PATH: &'static str = "/monitor/feed_entry";
+}
+
+/// 获取配置及其版本状态
+/// 无配置或已禁用时返回 None
+#[derive(Serialize, Deserialize)]
+pub struct GetWithStateCmd;
+
+impl ApiRequest for GetWithStateCmd {
+    type Response = Option<ProjectWithState>;
+    const PATH: &'static str = "/monitor/with_state";
+}
+
 /// 切换监控启停状态
 #[derive(Serialize, Deserialize)]
 pub struct SwitchMonitorCmd {
@@ -56,3 +144,24 @@ impl ApiRequest for SwitchMonitorCmd {
     type Response = ();
     const PATH: &'static str = "/monitor/switch";
 }
+
+/// 启用或禁用监控
+/// 禁用时保留配置并删除 Alarm，启用时若未暂停则立即恢复调度
+#[derive(Serialize, Deserialize)]
+pub struct SetEnabledCmd {
+    pub enabled: bool,
+}
+
+impl ApiRequest for SetEnabledCmd {
+    type Response = ();
+    const PATH: &'static str = "/monitor/enabled";
+}
+
+/// 获取最近的检查历史 (按时间顺序，最新的在最后)
+#[derive(Serialize, Deserialize)]
+pub struct GetHistoryCmd;
+
+impl ApiRequest for GetHistoryCmd {
+    type Response = Vec<CheckEvent>;
+    const PATH: &'static str = "/monitor/history";
+}
@@ -3,9 +3,15 @@ pub mod protocol;
 mod registry;
 
 use crate::error::WatchResult;
-use crate::utils::rpc::{ApiRequest, RpcClient};
+use crate::project::protocol::UpdateConfigCmd;
+use crate::utils::rate_limit::RateLimit;
+use crate::utils::rpc::{ApiRequest, DEFAULT_RPC_RETRIES, RpcClient, rpc_timeout};
 use protocol::*;
-use verwatch_shared::ProjectConfig;
+use verwatch_shared::{
+    AuditEvent, CheckEvent, DeadLetter, ProjectConfig, ProjectListing, ProjectPage,
+    ProjectWithState, Timestamp,
+    protocol::{FeedEntry, SwitchAllReport, UpdateProjectResponse},
+};
 use worker::Env;
 
 // =========================================================
@@ -20,12 +26,49 @@ pub trait Registry {
     async fn unregister(&self, unique_key: &str) -> WatchResult<bool>;
     /// 列出所有已注册的 Monitor 的 Config
     async fn list(&self) -> WatchResult<Vec<ProjectConfig>>;
+    /// 列出所有已注册的 Monitor 的 Config，同时报告读取失败的 key
+    /// (`include_disabled` 为 true 时包含已禁用的项目)
+    async fn list_lenient(&self, include_disabled: bool) -> WatchResult<ProjectListing>;
+    /// 分页列出 Monitor 的 Config (`cursor` 为上一页返回的游标)
+    async fn list_page(&self, cursor: Option<&str>, limit: usize) -> WatchResult<ProjectPage>;
+    /// 获取单个 Monitor 的 Config (未注册时返回 None)
+    async fn get_config(&self, unique_key: &str) -> WatchResult<Option<ProjectConfig>>;
     /// 检查是否已注册
     async fn is_registered(&self, unique_key: &str) -> WatchResult<bool>;
     /// 切换 Monitor 监控状态
     async fn switch_monitor(&self, unique_key: &str, paused: bool) -> WatchResult<bool>;
+    /// 批量切换所有 Monitor 的监控状态，返回各项目是否实际切换以及失败的 key
+    async fn switch_all(&self, paused: bool) -> WatchResult<SwitchAllReport>;
+    /// 启用或禁用 Monitor
+    async fn set_enabled(&self, unique_key: &str, enabled: bool) -> WatchResult<bool>;
     /// 手动触发 Monitor 检查
     async fn trigger_check(&self, unique_key: &str) -> WatchResult<bool>;
+    /// 强制触发 Monitor 的 Dispatch，返回触发的 Tag (未注册时返回 None)
+    async fn force_dispatch(&self, unique_key: &str) -> WatchResult<Option<String>>;
+    /// 重新安排 Monitor 的下一次检查时间 (未注册时返回 false)
+    async fn reschedule(&self, unique_key: &str, next_check_at: Timestamp) -> WatchResult<bool>;
+    /// 更新 Monitor 的可变配置，不改变监控状态与调度 (未注册时返回 None)
+    async fn update_config(
+        &self,
+        unique_key: &str,
+        update: &UpdateConfigCmd,
+    ) -> WatchResult<Option<UpdateProjectResponse>>;
+    /// 获取 Monitor 检查历史 (未注册时返回 None)
+    async fn get_history(&self, unique_key: &str) -> WatchResult<Option<Vec<CheckEvent>>>;
+    /// 对所有 Monitor 执行自愈检查，返回重新设置了 Alarm 的 key
+    async fn audit(&self) -> WatchResult<Vec<String>>;
+    /// 获取所有启用项目的版本订阅 (按 upstream 排序)
+    async fn feed(&self) -> WatchResult<Vec<FeedEntry>>;
+    /// 获取所有启用项目的配置及其版本状态 (按 unique_key 排序)
+    async fn list_detailed(&self) -> WatchResult<Vec<ProjectWithState>>;
+    /// 为鉴权标签消耗一个限流令牌 (耗尽时返回 false)
+    async fn consume_rate_token(&self, label: &str, limit: &RateLimit) -> WatchResult<bool>;
+    /// 追加一条管理操作审计记录
+    async fn append_audit_event(&self, event: &AuditEvent) -> WatchResult<()>;
+    /// 获取管理操作审计日志 (最新的在最后)
+    async fn audit_log(&self) -> WatchResult<Vec<AuditEvent>>;
+    /// 获取注册失败记录 (最新的在最后)
+    async fn dead_letters(&self) -> WatchResult<Vec<DeadLetter>>;
 }
 
 // =========================================================
@@ -49,7 +92,8 @@ impl DoProjectRegistry {
             .get_stub()
             .map_err(|e| crate::error::WatchError::from(e).in_op("registry.stub"))?;
         // Registry DO base URL
-        let client = RpcClient::new(stub, "http://registry");
+        let client = RpcClient::new(stub, "http://registry", DEFAULT_RPC_RETRIES)
+            .with_timeout(rpc_timeout(env));
         Ok(Self { client })
     }
 
@@ -79,6 +123,26 @@ impl Registry for DoProjectRegistry {
         self.execute(ListMonitorsCmd).await
     }
 
+    async fn list_lenient(&self, include_disabled: bool) -> WatchResult<ProjectListing> {
+        self.execute(ListMonitorsLenientCmd { include_disabled })
+            .await
+    }
+
+    async fn list_page(&self, cursor: Option<&str>, limit: usize) -> WatchResult<ProjectPage> {
+        self.execute(ListMonitorsPageCmd {
+            cursor: cursor.map(str::to_string),
+            limit,
+        })
+        .await
+    }
+
+    async fn get_config(&self, unique_key: &str) -> WatchResult<Option<ProjectConfig>> {
+        self.execute(RegistryGetConfigCmd {
+            unique_key: unique_key.to_string(),
+        })
+        .await
+    }
+
     async fn is_registered(&self, unique_key: &str) -> WatchResult<bool> {
         self.execute(IsRegisteredCmd {
             unique_key: unique_key.to_string(),
@@ -94,12 +158,93 @@ impl Registry for DoProjectRegistry {
         .await
     }
 
+    async fn switch_all(&self, paused: bool) -> WatchResult<SwitchAllReport> {
+        self.execute(RegistrySwitchAllCmd { paused }).await
+    }
+
+    async fn set_enabled(&self, unique_key: &str, enabled: bool) -> WatchResult<bool> {
+        self.execute(RegistrySetEnabledCmd {
+            unique_key: unique_key.to_string(),
+            enabled,
+        })
+        .await
+    }
+
     async fn trigger_check(&self, unique_key: &str) -> WatchResult<bool> {
         self.execute(RegistryTriggerCheckCmd {
             unique_key: unique_key.to_string(),
         })
         .await
     }
+
+    async fn force_dispatch(&self, unique_key: &str) -> WatchResult<Option<String>> {
+        self.execute(RegistryForceDispatchCmd {
+            unique_key: unique_key.to_string(),
+        })
+        .await
+    }
+
+    async fn reschedule(&self, unique_key: &str, next_check_at: Timestamp) -> WatchResult<bool> {
+        self.execute(RegistryRescheduleCmd {
+            unique_key: unique_key.to_string(),
+            next_check_at,
+        })
+        .await
+    }
+
+    async fn update_config(
+        &self,
+        unique_key: &str,
+        update: &UpdateConfigCmd,
+    ) -> WatchResult<Option<UpdateProjectResponse>> {
+        self.execute(RegistryUpdateConfigCmd {
+            unique_key: unique_key.to_string(),
+            update: update.clone(),
+        })
+        .await
+    }
+
+    async fn get_history(&self, unique_key: &str) -> WatchResult<Option<Vec<CheckEvent>>> {
+        self.execute(RegistryGetHistoryCmd {
+            unique_key: unique_key.to_string(),
+        })
+        .await
+    }
+
+    async fn audit(&self) -> WatchResult<Vec<String>> {
+        self.execute(RegistryAuditCmd).await
+    }
+
+    async fn feed(&self) -> WatchResult<Vec<FeedEntry>> {
+        self.execute(GetFeedCmd).await
+    }
+
+    async fn list_detailed(&self) -> WatchResult<Vec<ProjectWithState>> {
+        self.execute(ListDetailedCmd).await
+    }
+
+    async fn consume_rate_token(&self, label: &str, limit: &RateLimit) -> WatchResult<bool> {
+        self.execute(ConsumeRateTokenCmd {
+            label: label.to_string(),
+            limit: *limit,
+        })
+        .await
+    }
+
+    async fn append_audit_event(&self, event: &AuditEvent) -> WatchResult<()> {
+        self.execute(AppendAuditEventCmd {
+            event: event.clone(),
+        })
+        .await
+    }
+
+    async fn audit_log(&self) -> WatchResult<Vec<AuditEvent>> {
+        self.execute(GetAuditLogCmd).await
+    }
+
+    async fn dead_letters(&self) -> WatchResult<Vec<DeadLetter>> {
+        self.execute(GetDeadLetterCmd).await
+    }
 }
 
 // =========================================================
@@ -108,17 +253,39 @@ impl Registry for DoProjectRegistry {
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use crate::utils::rate_limit::RateBucket;
     use std::cell::RefCell;
     use std::collections::HashMap;
 
     pub struct MockRegistry {
         pub monitors: RefCell<HashMap<String, ProjectConfig>>,
+        /// 模拟各 Monitor 存储的版本状态 (与 Monitor.stop 一致：仅在注销时清除)
+        pub versions: RefCell<HashMap<String, String>>,
+        /// 模拟各 Monitor 存储的检查历史
+        pub histories: RefCell<HashMap<String, Vec<CheckEvent>>>,
+        /// 模拟 Registry 中各鉴权标签的限流令牌桶
+        pub rate_buckets: RefCell<HashMap<String, RateBucket>>,
+        /// 模拟配置已损坏、读取失败的 key
+        pub corrupt_keys: RefCell<Vec<String>>,
+        /// 模拟 Alarm 丢失的 key (自愈检查后清除)
+        pub lost_alarms: RefCell<Vec<String>>,
+        /// 模拟 Registry 中的审计日志
+        pub audit_events: RefCell<Vec<AuditEvent>>,
+        /// 模拟 Registry 中的注册失败记录
+        pub dead_letters: RefCell<Vec<DeadLetter>>,
     }
 
     impl MockRegistry {
         pub fn new() -> Self {
             Self {
                 monitors: RefCell::new(HashMap::new()),
+                versions: RefCell::new(HashMap::new()),
+                histories: RefCell::new(HashMap::new()),
+                rate_buckets: RefCell::new(HashMap::new()),
+                corrupt_keys: RefCell::new(Vec::new()),
+                lost_alarms: RefCell::new(Vec::new()),
+                audit_events: RefCell::new(Vec::new()),
+                dead_letters: RefCell::new(Vec::new()),
             }
         }
     }
@@ -134,11 +301,63 @@ pub mod tests {
         }
 
         async fn unregister(&self, unique_key: &str) -> WatchResult<bool> {
+            self.versions.borrow_mut().remove(unique_key);
+            self.histories.borrow_mut().remove(unique_key);
             Ok(self.monitors.borrow_mut().remove(unique_key).is_some())
         }
 
         async fn list(&self) -> WatchResult<Vec<ProjectConfig>> {
-            Ok(self.monitors.borrow().values().cloned().collect())
+            Ok(self
+                .monitors
+                .borrow()
+                .values()
+                .filter(|c| c.enabled)
+                .cloned()
+                .collect())
+        }
+
+        async fn list_lenient(&self, include_disabled: bool) -> WatchResult<ProjectListing> {
+            let projects = self
+                .monitors
+                .borrow()
+                .values()
+                .filter(|c| c.enabled || include_disabled)
+                .cloned()
+                .collect();
+            Ok(ProjectListing {
+                projects,
+                failed_keys: self.corrupt_keys.borrow().iter().cloned().collect(),
+            })
+        }
+
+        async fn list_page(&self, cursor: Option<&str>, limit: usize) -> WatchResult<ProjectPage> {
+            let monitors = self.monitors.borrow();
+            let mut keys: Vec<&String> = monitors.keys().collect();
+            keys.sort();
+            let mut page: Vec<&String> = keys
+                .into_iter()
+                .filter(|k| cursor.is_none_or(|c| k.as_str() > c))
+                .take(limit + 1)
+                .collect();
+            let next_cursor = if page.len() > limit {
+                page.truncate(limit);
+                page.last().map(|k| k.to_string())
+            } else {
+                None
+            };
+            Ok(ProjectPage {
+                items: page
+                    .into_iter()
+                    .map(|k| monitors[k].clone())
+                    .filter(|c| c.enabled)
+                    .collect(),
+                next_cursor,
+                failed_keys: Vec::new(),
+            })
+        }
+
+        async fn get_config(&self, unique_key: &str) -> WatchResult<Option<ProjectConfig>> {
+            Ok(self.monitors.borrow().get(unique_key).cloned())
         }
 
         async fn is_registered(&self, unique_key: &str) -> WatchResult<bool> {
@@ -161,8 +380,180 @@ pub mod tests {
             }
         }
 
+        async fn switch_all(&self, paused: bool) -> WatchResult<SwitchAllReport> {
+            let mut keys: Vec<String> = self.monitors.borrow().keys().cloned().collect();
+            keys.sort();
+            let mut report = SwitchAllReport::default();
+            for key in keys {
+                if self.corrupt_keys.borrow().contains(&key) {
+                    report.failed_keys.push(key);
+                    continue;
+                }
+                let already = self.monitors.borrow()[&key].state.is_paused() == paused;
+                if !already {
+                    self.switch_monitor(&key, paused).await?;
+                }
+                report.results.push((key, !already));
+            }
+            Ok(report)
+        }
+
+        async fn set_enabled(&self, unique_key: &str, enabled: bool) -> WatchResult<bool> {
+            match self.monitors.borrow_mut().get_mut(unique_key) {
+                Some(config) => {
+                    config.enabled = enabled;
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+
         async fn trigger_check(&self, unique_key: &str) -> WatchResult<bool> {
             Ok(self.monitors.borrow().contains_key(unique_key))
         }
+
+        async fn force_dispatch(&self, unique_key: &str) -> WatchResult<Option<String>> {
+            if !self.monitors.borrow().contains_key(unique_key) {
+                return Ok(None);
+            }
+            // 与 Monitor 一致：不修改已存储的版本
+            Ok(Some(
+                self.versions
+                    .borrow()
+                    .get(unique_key)
+                    .cloned()
+                    .unwrap_or_else(|| "v1.0.0".into()),
+            ))
+        }
+
+        async fn reschedule(
+            &self,
+            unique_key: &str,
+            next_check_at: Timestamp,
+        ) -> WatchResult<bool> {
+            match self.monitors.borrow_mut().get_mut(unique_key) {
+                Some(config) if config.state.is_paused() => {
+                    Err(crate::error::WatchError::conflict("Monitor is paused"))
+                }
+                Some(config) => {
+                    config.state = verwatch_shared::MonitorState::running(next_check_at);
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+
+        async fn update_config(
+            &self,
+            unique_key: &str,
+            update: &UpdateConfigCmd,
+        ) -> WatchResult<Option<UpdateProjectResponse>> {
+            let mut monitors = self.monitors.borrow_mut();
+            let Some(config) = monitors.get_mut(unique_key) else {
+                return Ok(None);
+            };
+            let original = config.clone();
+            update.apply(config);
+            Ok(Some(UpdateProjectResponse {
+                config: config.clone(),
+                changes: original.diff(config),
+            }))
+        }
+
+        async fn get_history(&self, unique_key: &str) -> WatchResult<Option<Vec<CheckEvent>>> {
+            if !self.monitors.borrow().contains_key(unique_key) {
+                return Ok(None);
+            }
+            Ok(Some(
+                self.histories
+                    .borrow()
+                    .get(unique_key)
+                    .cloned()
+                    .unwrap_or_default(),
+            ))
+        }
+
+        async fn audit(&self) -> WatchResult<Vec<String>> {
+            let monitors = self.monitors.borrow();
+            let mut rearmed: Vec<String> = self
+                .lost_alarms
+                .borrow_mut()
+                .drain(..)
+                .filter(|k| {
+                    monitors
+                        .get(k)
+                        .is_some_and(|c| c.enabled && !c.state.is_paused())
+                })
+                .collect();
+            rearmed.sort();
+            Ok(rearmed)
+        }
+
+        async fn consume_rate_token(&self, label: &str, limit: &RateLimit) -> WatchResult<bool> {
+            let now = verwatch_shared::Date::now_timestamp();
+            let mut buckets = self.rate_buckets.borrow_mut();
+            let bucket = buckets
+                .entry(label.to_string())
+                .or_insert_with(|| RateBucket::full(limit, now));
+            Ok(bucket.try_take(limit, now))
+        }
+
+        async fn feed(&self) -> WatchResult<Vec<FeedEntry>> {
+            let versions = self.versions.borrow();
+            let histories = self.histories.borrow();
+            let mut feed: Vec<FeedEntry> = self
+                .monitors
+                .borrow()
+                .values()
+                .filter(|c| c.enabled)
+                .map(|c| FeedEntry {
+                    upstream: format!(
+                        "{}/{}",
+                        c.request.base_config.upstream_owner, c.request.base_config.upstream_repo
+                    ),
+                    current_version: versions.get(&c.unique_key).cloned(),
+                    checked_at: histories
+                        .get(&c.unique_key)
+                        .and_then(|h| h.last())
+                        .map(|e| e.at),
+                })
+                .collect();
+            feed.sort_by(|a, b| a.upstream.cmp(&b.upstream));
+            Ok(feed)
+        }
+
+        async fn list_detailed(&self) -> WatchResult<Vec<ProjectWithState>> {
+            let versions = self.versions.borrow();
+            let histories = self.histories.borrow();
+            let mut items: Vec<ProjectWithState> = self
+                .monitors
+                .borrow()
+                .values()
+                .filter(|c| c.enabled)
+                .map(|c| ProjectWithState {
+                    config: c.clone(),
+                    current_version: versions.get(&c.unique_key).cloned(),
+                    checked_at: histories
+                        .get(&c.unique_key)
+                        .and_then(|h| h.last())
+                        .map(|e| e.at),
+                })
+                .collect();
+            items.sort_by(|a, b| a.config.unique_key.cmp(&b.config.unique_key));
+            Ok(items)
+        }
+
+        async fn append_audit_event(&self, event: &AuditEvent) -> WatchResult<()> {
+            self.audit_events.borrow_mut().push(event.clone());
+            Ok(())
+        }
+
+        async fn audit_log(&self) -> WatchResult<Vec<AuditEvent>> {
+            Ok(self.audit_events.borrow().clone())
+        }
+
+        async fn dead_letters(&self) -> WatchResult<Vec<DeadLetter>> {
+            Ok(self.dead_letters.borrow().clone())
+        }
     }
 }
@@ -1,10 +1,17 @@
 use crate::error::WatchResult;
 use crate::project::protocol::{
-    GetConfigCmd, SetupMonitorCmd, StopMonitorCmd, SwitchMonitorCmd, TriggerCheckCmd,
+    ForceDispatchCmd, GetConfigCmd, GetFeedEntryCmd, GetHistoryCmd, GetWithStateCmd,
+    HealthCheckCmd, RescheduleCmd, SetEnabledCmd, SetupMonitorCmd, StopMonitorCmd,
+    SwitchMonitorCmd, TriggerCheckCmd, UpdateConfigCmd,
 };
-use crate::utils::rpc::{ApiRequest, RpcClient};
+use crate::repository::registry::CachedConfig;
+use crate::utils::rate_limit::RateBucket;
+use crate::utils::rpc::{ApiRequest, DEFAULT_RPC_RETRIES, RpcClient, rpc_timeout};
 use async_trait::async_trait;
-use verwatch_shared::ProjectConfig;
+use verwatch_shared::{
+    AuditEvent, CheckEvent, DeadLetter, ProjectConfig, ProjectWithState, Timestamp,
+    protocol::{FeedEntry, UpdateProjectResponse},
+};
 use worker::Env;
 
 // =========================================================
@@ -20,8 +27,52 @@ pub trait RegistryStorageAdapter {
     async fn remove(&self, key: &str) -> WatchResult<bool>;
     /// 获取所有 key
     async fn list(&self) -> WatchResult<Vec<String>>;
+    /// 按 key 顺序分页获取，返回本页的 key 与下一页游标
+    ///
+    /// `cursor` 为上一页最后一个 key，本页从其之后开始。
+    /// 默认实现基于 `list` 在内存中分页，存储后端可覆盖为原生分页。
+    async fn list_paged(
+        &self,
+        cursor: Option<&str>,
+        limit: usize,
+    ) -> WatchResult<(Vec<String>, Option<String>)> {
+        let mut keys = self.list().await?;
+        keys.sort();
+        let start = cursor.map_or(0, |c| keys.partition_point(|k| k.as_str() <= c));
+        let keys = keys.into_iter().skip(start).take(limit + 1).collect();
+        Ok(split_page(keys, limit))
+    }
     /// 检查 key 是否存在
     async fn contains(&self, key: &str) -> WatchResult<bool>;
+    /// 读取某个鉴权标签的限流令牌桶
+    async fn get_rate_bucket(&self, label: &str) -> WatchResult<Option<RateBucket>>;
+    /// 保存某个鉴权标签的限流令牌桶
+    async fn put_rate_bucket(&self, label: &str, bucket: &RateBucket) -> WatchResult<()>;
+    /// 读取管理操作审计日志
+    async fn get_audit_log(&self) -> WatchResult<Vec<AuditEvent>>;
+    /// 保存管理操作审计日志
+    async fn put_audit_log(&self, events: &[AuditEvent]) -> WatchResult<()>;
+    /// 读取注册失败记录
+    async fn get_dead_letters(&self) -> WatchResult<Vec<DeadLetter>>;
+    /// 保存注册失败记录
+    async fn put_dead_letters(&self, entries: &[DeadLetter]) -> WatchResult<()>;
+    /// 读取缓存的 Monitor 配置
+    async fn get_cached_config(&self, key: &str) -> WatchResult<Option<CachedConfig>>;
+    /// 保存 Monitor 配置的缓存
+    async fn put_cached_config(&self, key: &str, entry: &CachedConfig) -> WatchResult<()>;
+    /// 删除 Monitor 配置的缓存 (不存在时忽略)
+    async fn delete_cached_config(&self, key: &str) -> WatchResult<()>;
+}
+
+/// 将最多 `limit + 1` 个有序 key 切分为本页与下一页游标
+pub(crate) fn split_page(mut keys: Vec<String>, limit: usize) -> (Vec<String>, Option<String>) {
+    if keys.len() > limit {
+        keys.truncate(limit);
+        let next = keys.last().cloned();
+        (keys, next)
+    } else {
+        (keys, None)
+    }
 }
 
 // =========================================================
@@ -43,6 +94,23 @@ pub trait MonitorClient {
     async fn get_config(&self, unique_key: &str) -> WatchResult<Option<ProjectConfig>>;
     async fn switch(&self, unique_key: &str, paused: bool) -> WatchResult<()>;
     async fn trigger_check(&self, unique_key: &str) -> WatchResult<()>;
+    /// 强制触发 Dispatch，返回触发的 Tag
+    async fn force_dispatch(&self, unique_key: &str) -> WatchResult<String>;
+    async fn reschedule(&self, unique_key: &str, next_check_at: Timestamp) -> WatchResult<()>;
+    /// 更新可变配置，返回更新后的配置与变化的字段
+    async fn update_config(
+        &self,
+        unique_key: &str,
+        update: &UpdateConfigCmd,
+    ) -> WatchResult<UpdateProjectResponse>;
+    async fn get_history(&self, unique_key: &str) -> WatchResult<Vec<CheckEvent>>;
+    /// 获取版本订阅条目 (无配置或已禁用时返回 None)
+    async fn get_feed_entry(&self, unique_key: &str) -> WatchResult<Option<FeedEntry>>;
+    /// 获取配置及其版本状态 (无配置或已禁用时返回 None)
+    async fn get_with_state(&self, unique_key: &str) -> WatchResult<Option<ProjectWithState>>;
+    async fn set_enabled(&self, unique_key: &str, enabled: bool) -> WatchResult<()>;
+    /// 自愈检查，返回是否重新设置了丢失的 Alarm
+    async fn health_check(&self, unique_key: &str) -> WatchResult<bool>;
 }
 
 // =========================================================
@@ -52,27 +120,18 @@ pub trait MonitorClient {
 pub struct WorkerRegistryStorage(pub worker::Storage);
 
 const REGISTRY_PREFIX: &str = "reg:";
-
-#[async_trait(?Send)]
-impl RegistryStorageAdapter for WorkerRegistryStorage {
-    async fn add(&self, key: &str) -> WatchResult<()> {
-        let storage_key = format!("{}{}", REGISTRY_PREFIX, key);
-        self.0
-            .put(&storage_key, "")
-            .await
-            .map_err(|e| crate::error::WatchError::from(e).in_op_with("registry.add", key))
-    }
-
-    async fn remove(&self, key: &str) -> WatchResult<bool> {
-        let storage_key = format!("{}{}", REGISTRY_PREFIX, key);
-        self.0
-            .delete(&storage_key)
-            .await
-            .map_err(|e| crate::error::WatchError::from(e).in_op_with("registry.remove", key))
-    }
-
-    async fn list(&self) -> WatchResult<Vec<String>> {
-        let opts = worker::ListOptions::new().prefix(REGISTRY_PREFIX);
+/// 限流令牌桶前缀，与 REGISTRY_PREFIX 分开，不会出现在 list 结果中
+const RATE_LIMIT_PREFIX: &str = "rate:";
+/// 审计日志的存储 key，同样不带 REGISTRY_PREFIX
+const AUDIT_LOG_KEY: &str = "audit_log";
+/// 注册失败记录的存储 key
+const DEAD_LETTER_KEY: &str = "deadletter";
+/// Monitor 配置缓存前缀，同样不带 REGISTRY_PREFIX
+const CONFIG_CACHE_PREFIX: &str = "cfgcache:";
+
+impl WorkerRegistryStorage {
+    /// 按选项列出 key 并移除前缀
+    async fn list_keys(&self, opts: worker::ListOptions<'_>) -> WatchResult<Vec<String>> {
         let map = self
             .0
             .list_with_options(opts)
@@ -100,18 +159,143 @@ impl RegistryStorageAdapter for WorkerRegistryStorage {
         Ok(keys)
     }
 
-    async fn contains(&self, key: &str) -> WatchResult<bool> {
-        let storage_key = format!("{}{}", REGISTRY_PREFIX, key);
-        let result: Option<String> = self.0.get(&storage_key).await.or_else(|e| {
+    /// 读取可选的值，key 不存在 (`No such value`) 时返回 `None`
+    ///
+    /// 其余错误附加 `op` 追踪，`detail` 通常为项目 key
+    async fn get_optional<T: serde::de::DeserializeOwned>(
+        &self,
+        storage_key: &str,
+        op: &str,
+        detail: Option<&str>,
+    ) -> WatchResult<Option<T>> {
+        self.0.get(storage_key).await.or_else(|e| {
             let msg = e.to_string();
             if msg.contains("No such value") {
                 Ok(None)
             } else {
-                Err(crate::error::WatchError::from(e).in_op_with("registry.contains", key))
+                let err = crate::error::WatchError::from(e);
+                Err(match detail {
+                    Some(detail) => err.in_op_with(op, detail),
+                    None => err.in_op(op),
+                })
             }
-        })?;
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl RegistryStorageAdapter for WorkerRegistryStorage {
+    async fn add(&self, key: &str) -> WatchResult<()> {
+        let storage_key = format!("{}{}", REGISTRY_PREFIX, key);
+        self.0
+            .put(&storage_key, "")
+            .await
+            .map_err(|e| crate::error::WatchError::from(e).in_op_with("registry.add", key))
+    }
+
+    async fn remove(&self, key: &str) -> WatchResult<bool> {
+        let storage_key = format!("{}{}", REGISTRY_PREFIX, key);
+        self.0
+            .delete(&storage_key)
+            .await
+            .map_err(|e| crate::error::WatchError::from(e).in_op_with("registry.remove", key))
+    }
+
+    async fn list(&self) -> WatchResult<Vec<String>> {
+        let opts = worker::ListOptions::new().prefix(REGISTRY_PREFIX);
+        self.list_keys(opts).await
+    }
+
+    async fn list_paged(
+        &self,
+        cursor: Option<&str>,
+        limit: usize,
+    ) -> WatchResult<(Vec<String>, Option<String>)> {
+        // start 为闭区间，追加 \0 使本页从 cursor 之后的第一个 key 开始
+        let start = cursor.map(|c| format!("{}{}\0", REGISTRY_PREFIX, c));
+        // 多取一个用于判断是否还有下一页
+        let mut opts = worker::ListOptions::new()
+            .prefix(REGISTRY_PREFIX)
+            .limit(limit + 1);
+        if let Some(start) = &start {
+            opts = opts.start(start);
+        }
+        let keys = self.list_keys(opts).await?;
+        Ok(split_page(keys, limit))
+    }
+
+    async fn contains(&self, key: &str) -> WatchResult<bool> {
+        let storage_key = format!("{}{}", REGISTRY_PREFIX, key);
+        let result: Option<String> = self
+            .get_optional(&storage_key, "registry.contains", Some(key))
+            .await?;
         Ok(result.is_some())
     }
+
+    async fn get_rate_bucket(&self, label: &str) -> WatchResult<Option<RateBucket>> {
+        let storage_key = format!("{}{}", RATE_LIMIT_PREFIX, label);
+        self.get_optional(&storage_key, "registry.rate.get", Some(label))
+            .await
+    }
+
+    async fn put_rate_bucket(&self, label: &str, bucket: &RateBucket) -> WatchResult<()> {
+        let storage_key = format!("{}{}", RATE_LIMIT_PREFIX, label);
+        self.0
+            .put(&storage_key, bucket)
+            .await
+            .map_err(|e| crate::error::WatchError::from(e).in_op_with("registry.rate.put", label))
+    }
+
+    async fn get_audit_log(&self) -> WatchResult<Vec<AuditEvent>> {
+        let events: Option<Vec<AuditEvent>> = self
+            .get_optional(AUDIT_LOG_KEY, "registry.audit_log.get", None)
+            .await?;
+        Ok(events.unwrap_or_default())
+    }
+
+    async fn put_audit_log(&self, events: &[AuditEvent]) -> WatchResult<()> {
+        self.0
+            .put(AUDIT_LOG_KEY, events)
+            .await
+            .map_err(|e| crate::error::WatchError::from(e).in_op("registry.audit_log.put"))
+    }
+
+    async fn get_dead_letters(&self) -> WatchResult<Vec<DeadLetter>> {
+        let entries: Option<Vec<DeadLetter>> = self
+            .get_optional(DEAD_LETTER_KEY, "registry.deadletter.get", None)
+            .await?;
+        Ok(entries.unwrap_or_default())
+    }
+
+    async fn put_dead_letters(&self, entries: &[DeadLetter]) -> WatchResult<()> {
+        self.0
+            .put(DEAD_LETTER_KEY, entries)
+            .await
+            .map_err(|e| crate::error::WatchError::from(e).in_op("registry.deadletter.put"))
+    }
+
+    async fn get_cached_config(&self, key: &str) -> WatchResult<Option<CachedConfig>> {
+        let storage_key = format!("{}{}", CONFIG_CACHE_PREFIX, key);
+        self.get_optional(&storage_key, "registry.cache.get", Some(key))
+            .await
+    }
+
+    async fn put_cached_config(&self, key: &str, entry: &CachedConfig) -> WatchResult<()> {
+        let storage_key = format!("{}{}", CONFIG_CACHE_PREFIX, key);
+        self.0
+            .put(&storage_key, entry)
+            .await
+            .map_err(|e| crate::error::WatchError::from(e).in_op_with("registry.cache.put", key))
+    }
+
+    async fn delete_cached_config(&self, key: &str) -> WatchResult<()> {
+        let storage_key = format!("{}{}", CONFIG_CACHE_PREFIX, key);
+        self.0
+            .delete(&storage_key)
+            .await
+            .map(|_| ())
+            .map_err(|e| crate::error::WatchError::from(e).in_op_with("registry.cache.delete", key))
+    }
 }
 
 pub struct WorkerEnv<'a>(pub &'a Env);
@@ -148,7 +332,8 @@ impl<'a> WorkerMonitorClient<'a> {
 
     async fn send<T: ApiRequest>(&self, unique_key: &str, cmd: &T) -> WatchResult<T::Response> {
         let stub = self.get_stub(unique_key)?;
-        let client = RpcClient::new(stub, "http://monitor");
+        let client = RpcClient::new(stub, "http://monitor", DEFAULT_RPC_RETRIES)
+            .with_timeout(rpc_timeout(self.env));
         client
             .send(cmd)
             .await
@@ -183,6 +368,43 @@ impl<'a> MonitorClient for WorkerMonitorClient<'a> {
     async fn trigger_check(&self, unique_key: &str) -> WatchResult<()> {
         self.send(unique_key, &TriggerCheckCmd).await
     }
+
+    async fn force_dispatch(&self, unique_key: &str) -> WatchResult<String> {
+        self.send(unique_key, &ForceDispatchCmd).await
+    }
+
+    async fn reschedule(&self, unique_key: &str, next_check_at: Timestamp) -> WatchResult<()> {
+        self.send(unique_key, &RescheduleCmd { next_check_at })
+            .await
+    }
+
+    async fn update_config(
+        &self,
+        unique_key: &str,
+        update: &UpdateConfigCmd,
+    ) -> WatchResult<UpdateProjectResponse> {
+        self.send(unique_key, update).await
+    }
+
+    async fn get_history(&self, unique_key: &str) -> WatchResult<Vec<CheckEvent>> {
+        self.send(unique_key, &GetHistoryCmd).await
+    }
+
+    async fn get_feed_entry(&self, unique_key: &str) -> WatchResult<Option<FeedEntry>> {
+        self.send(unique_key, &GetFeedEntryCmd).await
+    }
+
+    async fn get_with_state(&self, unique_key: &str) -> WatchResult<Option<ProjectWithState>> {
+        self.send(unique_key, &GetWithStateCmd).await
+    }
+
+    async fn set_enabled(&self, unique_key: &str, enabled: bool) -> WatchResult<()> {
+        self.send(unique_key, &SetEnabledCmd { enabled }).await
+    }
+
+    async fn health_check(&self, unique_key: &str) -> WatchResult<bool> {
+        self.send(unique_key, &HealthCheckCmd).await
+    }
 }
 
 // =========================================================
@@ -204,6 +426,11 @@ pub mod tests {
                 vars: HashMap::new(),
             }
         }
+
+        pub fn with_var(mut self, name: &str, value: &str) -> Self {
+            self.vars.insert(name.to_string(), value.to_string());
+            self
+        }
     }
 
     impl EnvAdapter for MockEnv {
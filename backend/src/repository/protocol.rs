@@ -1,6 +1,12 @@
+use crate::project::protocol::UpdateConfigCmd;
+use crate::utils::rate_limit::RateLimit;
 use crate::utils::rpc::ApiRequest;
 use serde::{Deserialize, Serialize};
-use verwatch_shared::ProjectConfig;
+use verwatch_shared::{
+    AuditEvent, CheckEvent, DeadLetter, ProjectConfig, ProjectListing, ProjectPage,
+    ProjectWithState, Timestamp,
+    protocol::{FeedEntry, SwitchAllReport, UpdateProjectResponse},
+};
 
 // =========================================================
 // Registry 指令定义
@@ -30,7 +36,7 @@ impl ApiRequest for UnregisterMonitorCmd {
     const PATH: &'static str = "/registry/unregister";
 }
 
-/// 获取所有已注册的 Monitor 的 ProjectConfig 列表
+/// 获取所有已注册且启用的 Monitor 的 ProjectConfig 列表
 /// 会遍历查询每个 Monitor
 #[derive(Serialize, Deserialize)]
 pub struct ListMonitorsCmd;
@@ -38,6 +44,34 @@ pub struct ListMonitorsCmd;
 impl ApiRequest for ListMonitorsCmd {
     type Response = Vec<ProjectConfig>;
     const PATH: &'static str = "/registry/list";
+    const LONG_RUNNING: bool = true;
+}
+
+/// 获取所有已注册的 Monitor 的 ProjectConfig，并报告读取失败的 key
+/// `include_disabled` 为 false 时剔除已禁用的项目
+#[derive(Serialize, Deserialize)]
+pub struct ListMonitorsLenientCmd {
+    pub include_disabled: bool,
+}
+
+impl ApiRequest for ListMonitorsLenientCmd {
+    type Response = ProjectListing;
+    const PATH: &'static str = "/registry/list_lenient";
+    const LONG_RUNNING: bool = true;
+}
+
+/// 分页获取已注册且启用的 Monitor 的 ProjectConfig
+/// 仅查询本页 key 对应的 Monitor，禁用的项目会从本页结果中剔除
+#[derive(Serialize, Deserialize)]
+pub struct ListMonitorsPageCmd {
+    pub cursor: Option<String>,
+    pub limit: usize,
+}
+
+impl ApiRequest for ListMonitorsPageCmd {
+    type Response = ProjectPage;
+    const PATH: &'static str = "/registry/list_page";
+    const LONG_RUNNING: bool = true;
 }
 
 /// 检查某个 Monitor 是否已注册
@@ -63,6 +97,30 @@ impl ApiRequest for RegistrySwitchMonitorCmd {
     const PATH: &'static str = "/registry/switch";
 }
 
+/// 批量切换所有已注册 Monitor 的监控状态
+#[derive(Serialize, Deserialize)]
+pub struct RegistrySwitchAllCmd {
+    pub paused: bool,
+}
+
+impl ApiRequest for RegistrySwitchAllCmd {
+    type Response = SwitchAllReport;
+    const PATH: &'static str = "/registry/switch_all";
+    const LONG_RUNNING: bool = true;
+}
+
+/// 启用或禁用 Monitor
+#[derive(Serialize, Deserialize)]
+pub struct RegistrySetEnabledCmd {
+    pub unique_key: String,
+    pub enabled: bool,
+}
+
+impl ApiRequest for RegistrySetEnabledCmd {
+    type Response = bool; // 未注册时返回 false
+    const PATH: &'static str = "/registry/enabled";
+}
+
 /// 手动触发 Monitor 检查
 #[derive(Serialize, Deserialize)]
 pub struct RegistryTriggerCheckCmd {
@@ -72,4 +130,134 @@ pub struct RegistryTriggerCheckCmd {
 impl ApiRequest for RegistryTriggerCheckCmd {
     type Response = bool; // 指示触发命令是否成功发送
     const PATH: &'static str = "/registry/trigger";
+    const LONG_RUNNING: bool = true;
+}
+
+/// 强制触发 Monitor 的 Dispatch
+#[derive(Serialize, Deserialize)]
+pub struct RegistryForceDispatchCmd {
+    pub unique_key: String,
+}
+
+impl ApiRequest for RegistryForceDispatchCmd {
+    type Response = Option<String>; // 触发的 Tag，未注册时返回 None
+    const PATH: &'static str = "/registry/force_dispatch";
+    const LONG_RUNNING: bool = true;
+}
+
+/// 重新安排 Monitor 的下一次检查时间
+#[derive(Serialize, Deserialize)]
+pub struct RegistryRescheduleCmd {
+    pub unique_key: String,
+    pub next_check_at: Timestamp,
+}
+
+impl ApiRequest for RegistryRescheduleCmd {
+    type Response = bool; // 未注册时返回 false
+    const PATH: &'static str = "/registry/reschedule";
+}
+
+/// 更新 Monitor 的可变配置
+#[derive(Serialize, Deserialize)]
+pub struct RegistryUpdateConfigCmd {
+    pub unique_key: String,
+    pub update: UpdateConfigCmd,
+}
+
+impl ApiRequest for RegistryUpdateConfigCmd {
+    type Response = Option<UpdateProjectResponse>; // 未注册时返回 None
+    const PATH: &'static str = "/registry/update_config";
+}
+
+/// 对所有 Monitor 执行自愈检查
+#[derive(Serialize, Deserialize)]
+pub struct RegistryAuditCmd;
+
+impl ApiRequest for RegistryAuditCmd {
+    type Response = Vec<String>; // 重新设置了 Alarm 的 unique_key
+    const PATH: &'static str = "/registry/audit";
+    const LONG_RUNNING: bool = true;
+}
+
+/// 获取所有启用项目的版本订阅 (Feed)
+#[derive(Serialize, Deserialize)]
+pub struct GetFeedCmd;
+
+impl ApiRequest for GetFeedCmd {
+    type Response = Vec<FeedEntry>; // 按 upstream 排序
+    const PATH: &'static str = "/registry/feed";
+    const LONG_RUNNING: bool = true;
+}
+
+/// 获取所有启用项目的配置及其版本状态
+#[derive(Serialize, Deserialize)]
+pub struct ListDetailedCmd;
+
+impl ApiRequest for ListDetailedCmd {
+    type Response = Vec<ProjectWithState>; // 按 unique_key 排序
+    const PATH: &'static str = "/registry/list_detailed";
+    const LONG_RUNNING: bool = true;
+}
+
+/// 获取单个 Monitor 的 ProjectConfig
+#[derive(Serialize, Deserialize)]
+pub struct RegistryGetConfigCmd {
+    pub unique_key: String,
+}
+
+impl ApiRequest for RegistryGetConfigCmd {
+    type Response = Option<ProjectConfig>; // 未注册时返回 None
+    const PATH: &'static str = "/registry/get";
+}
+
+/// 获取 Monitor 的检查历史
+#[derive(Serialize, Deserialize)]
+pub struct RegistryGetHistoryCmd {
+    pub unique_key: String,
+}
+
+impl ApiRequest for RegistryGetHistoryCmd {
+    type Response = Option<Vec<CheckEvent>>; // 未注册时返回 None
+    const PATH: &'static str = "/registry/history";
+}
+
+/// 追加一条管理操作审计记录
+#[derive(Serialize, Deserialize)]
+pub struct AppendAuditEventCmd {
+    pub event: AuditEvent,
+}
+
+impl ApiRequest for AppendAuditEventCmd {
+    type Response = ();
+    const PATH: &'static str = "/registry/audit_log/append";
+}
+
+/// 获取管理操作审计日志 (按时间顺序，最新的在最后)
+#[derive(Serialize, Deserialize)]
+pub struct GetAuditLogCmd;
+
+impl ApiRequest for GetAuditLogCmd {
+    type Response = Vec<AuditEvent>;
+    const PATH: &'static str = "/registry/audit_log";
+}
+
+/// 获取注册失败记录 (按时间顺序，最新的在最后)
+#[derive(Serialize, Deserialize)]
+pub struct GetDeadLetterCmd;
+
+impl ApiRequest for GetDeadLetterCmd {
+    type Response = Vec<DeadLetter>;
+    const PATH: &'static str = "/registry/deadletter";
+}
+
+/// 为某个鉴权标签消耗一个限流令牌
+#[derive(Serialize, Deserialize)]
+pub struct ConsumeRateTokenCmd {
+    pub label: String,
+    pub limit: RateLimit,
+}
+
+impl ApiRequest for ConsumeRateTokenCmd {
+    type Response = bool; // 令牌耗尽时返回 false
+    const PATH: &'static str = "/registry/rate_limit";
 }
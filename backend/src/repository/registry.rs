@@ -3,18 +3,38 @@ use super::adapter::{
     WorkerRegistryStorage,
 };
 use super::protocol::*;
-use crate::error::WatchResult;
+use crate::error::{WatchError, WatchResult};
+use crate::utils::rate_limit::RateBucket;
 use crate::utils::rpc::{ApiRequest, RpcHandler};
-use verwatch_shared::ProjectConfig;
+use serde::{Deserialize, Serialize};
+use verwatch_shared::{
+    AuditEvent, CheckEvent, Date, DeadLetter, ProjectConfig, ProjectListing, ProjectPage,
+    ProjectWithState, Timestamp,
+    protocol::{FeedEntry, SwitchAllReport, UpdateProjectResponse},
+};
 use worker::*;
 
 // =========================================================
 // 业务逻辑层 (Logic)
 // =========================================================
 
+/// 列表聚合时并发查询 Monitor 的默认上限
+const DEFAULT_LIST_CONCURRENCY: usize = 10;
+/// 审计日志保留的最大条数，超出时丢弃最旧的记录
+pub(crate) const MAX_AUDIT_EVENTS: usize = 200;
+/// 注册失败记录保留的最大条数，超出时丢弃最旧的记录
+pub(crate) const MAX_DEAD_LETTERS: usize = 50;
+
+/// 缓存在 Registry 中的 Monitor 配置及其写入时间
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedConfig {
+    pub config: ProjectConfig,
+    pub cached_at: Timestamp,
+}
+
 pub struct ProjectRegistryLogic<S, E, M> {
     storage: S,
-    _env: E,
+    env: E,
     monitor_client: M,
 }
 
@@ -27,24 +47,86 @@ where
     pub fn new(storage: S, env: E, monitor_client: M) -> Self {
         Self {
             storage,
-            _env: env,
+            env,
             monitor_client,
         }
     }
 
+    /// 列表聚合的并发上限，可通过 `MONITOR_LIST_CONCURRENCY` 配置
+    fn list_concurrency(&self) -> usize {
+        self.env
+            .var("MONITOR_LIST_CONCURRENCY")
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|n| *n > 0)
+            .unwrap_or(DEFAULT_LIST_CONCURRENCY)
+    }
+
+    /// 配置缓存的有效期 (毫秒)，读取 `CONFIG_CACHE_TTL_SECS`，未设置或为 0 时不启用缓存
+    fn config_cache_ttl_ms(&self) -> Option<i64> {
+        self.env
+            .var("CONFIG_CACHE_TTL_SECS")
+            .and_then(|v| v.trim().parse::<i64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(|secs| secs.saturating_mul(1000))
+    }
+
+    /// 读取 Monitor 配置，启用缓存时优先使用未过期的缓存
+    ///
+    /// 缓存期间 Monitor 自行推进的状态 (下一次检查时间、最近结果等) 不会反映出来；
+    /// 经 Registry 的变更操作会立即使对应缓存失效。缓存读写失败时退回直接查询
+    async fn load_config(&self, key: &str) -> WatchResult<Option<ProjectConfig>> {
+        let Some(ttl_ms) = self.config_cache_ttl_ms() else {
+            return self.monitor_client.get_config(key).await;
+        };
+
+        let now = Date::now_timestamp();
+        if let Ok(Some(entry)) = self.storage.get_cached_config(key).await {
+            let age = now.signed_diff_millis(entry.cached_at);
+            if (0..ttl_ms).contains(&age) {
+                return Ok(Some(entry.config));
+            }
+        }
+
+        let config = self.monitor_client.get_config(key).await?;
+        if let Some(config) = &config {
+            let entry = CachedConfig {
+                config: config.clone(),
+                cached_at: now,
+            };
+            let _ = self.storage.put_cached_config(key, &entry).await;
+        }
+        Ok(config)
+    }
+
+    /// 使某个 Monitor 的配置缓存失效，在所有经 Registry 修改 Monitor 的操作之后调用
+    ///
+    /// 未启用缓存时同样执行，避免关闭缓存后再开启时读到遗留的旧数据
+    async fn invalidate_config(&self, key: &str) -> WatchResult<()> {
+        self.storage
+            .delete_cached_config(key)
+            .await
+            .map_err(|e| e.in_op_with("cache.invalidate", key))
+    }
+
     /// 注册一个 Monitor
     /// 1. 计算 unique_key
     /// 2. 调用 Monitor setup
     /// 3. 记录到 Registry
+    ///
+    /// setup 失败时不会记录 key，而是写入注册失败记录以便排查
     pub async fn register(&self, cmd: RegisterMonitorCmd) -> WatchResult<String> {
         let config = cmd.config;
         let unique_key = config.unique_key.clone();
 
         // 调用 ProjectMonitor 的 setup
-        self.monitor_client
-            .setup(&unique_key, &config)
-            .await
-            .map_err(|e| e.in_op_with("register.setup", &unique_key))?;
+        if let Err(e) = self.monitor_client.setup(&unique_key, &config).await {
+            let e = e.in_op_with("register.setup", &unique_key);
+            self.record_dead_letter(&unique_key, &e).await;
+            // Monitor 可能已部分写入，同样使缓存失效；失败时仍返回 setup 的原始错误
+            let _ = self.invalidate_config(&unique_key).await;
+            return Err(e);
+        }
+        self.invalidate_config(&unique_key).await?;
 
         // 记录到 Registry
         self.storage
@@ -55,6 +137,28 @@ where
         Ok(unique_key)
     }
 
+    /// 追加一条注册失败记录，只保留最近 `MAX_DEAD_LETTERS` 条
+    /// 仅用于排查，写入失败不影响返回给调用方的原始错误
+    async fn record_dead_letter(&self, unique_key: &str, error: &WatchError) {
+        let Ok(mut entries) = self.storage.get_dead_letters().await else {
+            return;
+        };
+        entries.push(DeadLetter {
+            at: Date::now_timestamp(),
+            unique_key: unique_key.to_string(),
+            error: error.display_chain(),
+        });
+        if entries.len() > MAX_DEAD_LETTERS {
+            entries.drain(..entries.len() - MAX_DEAD_LETTERS);
+        }
+        let _ = self.storage.put_dead_letters(&entries).await;
+    }
+
+    /// 获取注册失败记录 (最新的在最后)
+    pub async fn get_dead_letters(&self, _cmd: GetDeadLetterCmd) -> WatchResult<Vec<DeadLetter>> {
+        self.storage.get_dead_letters().await
+    }
+
     /// 注销一个 Monitor
     /// 1. 调用 Monitor stop
     /// 2. 从 Registry 移除
@@ -71,6 +175,7 @@ where
             .stop(unique_key)
             .await
             .map_err(|e| e.in_op_with("unregister.stop", unique_key))?;
+        self.invalidate_config(unique_key).await?;
 
         // 从 Registry 移除
         self.storage
@@ -79,25 +184,66 @@ where
             .map_err(|e| e.in_op_with("unregister.remove", unique_key))
     }
 
-    /// 列出所有已注册的 Monitor 的 ProjectConfig
-    /// 遍历查询每个 Monitor
+    /// 列出所有已注册且启用的 Monitor 的 ProjectConfig
+    /// 遍历查询每个 Monitor，禁用的项目不计入结果
     pub async fn list(&self, _cmd: ListMonitorsCmd) -> WatchResult<Vec<ProjectConfig>> {
+        let cmd = ListMonitorsLenientCmd {
+            include_disabled: false,
+        };
+        Ok(self.list_lenient(cmd).await?.projects)
+    }
+
+    /// 列出 Monitor，读取失败的（可能是脏数据）记录到 `failed_keys` 而不是静默丢弃
+    pub async fn list_lenient(&self, cmd: ListMonitorsLenientCmd) -> WatchResult<ProjectListing> {
         let keys = self.storage.list().await?;
 
-        // 并发获取所有 Config
-        let tasks = keys
-            .iter()
-            .map(|key| async { self.monitor_client.get_config(key).await });
+        // 限流并发获取所有 Config
+        let tasks = keys.iter().map(|key| self.load_config(key));
 
-        let results = crate::utils::concurrent::join_all(tasks).await;
+        let results =
+            crate::utils::concurrent::join_all_bounded(tasks, self.list_concurrency()).await;
 
-        // 收集成功的 Config，忽略失败的（可能是脏数据）
-        let configs: Vec<ProjectConfig> = results
-            .into_iter()
-            .filter_map(|r| r.ok().flatten())
-            .collect();
+        let mut listing = ProjectListing::default();
+        for (key, result) in keys.into_iter().zip(results) {
+            match result {
+                Ok(Some(config)) if config.enabled || cmd.include_disabled => {
+                    listing.projects.push(config)
+                }
+                Ok(_) => {}
+                Err(_) => listing.failed_keys.push(key),
+            }
+        }
+
+        Ok(listing)
+    }
+
+    /// 分页列出 Monitor 的 ProjectConfig
+    /// 仅并发查询本页的 Monitor，游标基于 Registry 中 key 的顺序
+    pub async fn list_page(&self, cmd: ListMonitorsPageCmd) -> WatchResult<ProjectPage> {
+        let (keys, next_cursor) = self
+            .storage
+            .list_paged(cmd.cursor.as_deref(), cmd.limit)
+            .await?;
+
+        let tasks = keys.iter().map(|key| self.load_config(key));
+
+        let results =
+            crate::utils::concurrent::join_all_bounded(tasks, self.list_concurrency()).await;
+
+        // 与 list_lenient 一致：读取失败的记录到 `failed_keys`，不静默丢弃
+        let mut page = ProjectPage {
+            next_cursor,
+            ..Default::default()
+        };
+        for (key, result) in keys.into_iter().zip(results) {
+            match result {
+                Ok(Some(config)) if config.enabled => page.items.push(config),
+                Ok(_) => {}
+                Err(_) => page.failed_keys.push(key),
+            }
+        }
 
-        Ok(configs)
+        Ok(page)
     }
 
     pub async fn is_registered(&self, cmd: IsRegisteredCmd) -> WatchResult<bool> {
@@ -113,6 +259,115 @@ where
             .switch(&cmd.unique_key, cmd.paused)
             .await
             .map_err(|e| e.in_op_with("switch_monitor", &cmd.unique_key))?;
+        self.invalidate_config(&cmd.unique_key).await?;
+        Ok(true)
+    }
+
+    /// 批量切换所有已注册 Monitor 的监控状态
+    ///
+    /// 限流并发处理每个 Monitor，`results` 中已处于目标状态的项目记为 `false`；
+    /// 配置缺失、读取或切换失败 (含切换后缓存失效失败) 的项目记入 `failed_keys`，不影响其余项目
+    pub async fn switch_all(&self, cmd: RegistrySwitchAllCmd) -> WatchResult<SwitchAllReport> {
+        let mut keys = self.storage.list().await?;
+        keys.sort();
+
+        // 判断当前状态时绕过缓存，避免依据过期状态跳过切换
+        let tasks = keys.iter().map(|key| async move {
+            match self.monitor_client.get_config(key).await? {
+                Some(config) if config.state.is_paused() == cmd.paused => Ok(false),
+                Some(_) => {
+                    self.monitor_client.switch(key, cmd.paused).await?;
+                    self.invalidate_config(key).await?;
+                    Ok(true)
+                }
+                None => Err(WatchError::not_found("No config found").in_op_with("switch_all", key)),
+            }
+        });
+
+        let results =
+            crate::utils::concurrent::join_all_bounded(tasks, self.list_concurrency()).await;
+
+        let mut report = SwitchAllReport::default();
+        for (key, result) in keys.into_iter().zip(results) {
+            match result {
+                Ok(applied) => report.results.push((key, applied)),
+                Err(_) => report.failed_keys.push(key),
+            }
+        }
+        Ok(report)
+    }
+
+    /// 对所有 Monitor 执行自愈检查，返回重新设置了 Alarm 的 key (按字典序)
+    ///
+    /// 单个 Monitor 检查失败不影响其余项目
+    pub async fn audit(&self, _cmd: RegistryAuditCmd) -> WatchResult<Vec<String>> {
+        let mut keys = self.storage.list().await?;
+        keys.sort();
+
+        let tasks = keys
+            .iter()
+            .map(|key| async { self.monitor_client.health_check(key).await });
+
+        let results =
+            crate::utils::concurrent::join_all_bounded(tasks, self.list_concurrency()).await;
+
+        Ok(keys
+            .into_iter()
+            .zip(results)
+            .filter_map(|(key, rearmed)| matches!(rearmed, Ok(true)).then_some(key))
+            .collect())
+    }
+
+    /// 版本订阅：并发获取各 Monitor 的已记录版本，按 upstream 排序
+    /// 已禁用或读取失败的项目不计入结果
+    pub async fn feed(&self, _cmd: GetFeedCmd) -> WatchResult<Vec<FeedEntry>> {
+        let keys = self.storage.list().await?;
+
+        let tasks = keys
+            .iter()
+            .map(|key| async { self.monitor_client.get_feed_entry(key).await });
+
+        let results =
+            crate::utils::concurrent::join_all_bounded(tasks, self.list_concurrency()).await;
+
+        let mut feed: Vec<FeedEntry> = results
+            .into_iter()
+            .filter_map(|entry| entry.ok().flatten())
+            .collect();
+        feed.sort_by(|a, b| a.upstream.cmp(&b.upstream));
+        Ok(feed)
+    }
+
+    /// 详细列表：并发获取各 Monitor 的配置与版本状态，按 unique_key 排序
+    /// 已禁用或读取失败的项目不计入结果
+    pub async fn list_detailed(&self, _cmd: ListDetailedCmd) -> WatchResult<Vec<ProjectWithState>> {
+        let keys = self.storage.list().await?;
+
+        let tasks = keys
+            .iter()
+            .map(|key| async { self.monitor_client.get_with_state(key).await });
+
+        let results =
+            crate::utils::concurrent::join_all_bounded(tasks, self.list_concurrency()).await;
+
+        let mut items: Vec<ProjectWithState> = results
+            .into_iter()
+            .filter_map(|item| item.ok().flatten())
+            .collect();
+        items.sort_by(|a, b| a.config.unique_key.cmp(&b.config.unique_key));
+        Ok(items)
+    }
+
+    /// 启用或禁用监控
+    pub async fn set_enabled(&self, cmd: RegistrySetEnabledCmd) -> WatchResult<bool> {
+        if !self.storage.contains(&cmd.unique_key).await? {
+            return Ok(false);
+        }
+        self.monitor_client
+            .set_enabled(&cmd.unique_key, cmd.enabled)
+            .await
+            .map_err(|e| e.in_op_with("set_enabled", &cmd.unique_key))?;
+        self.invalidate_config(&cmd.unique_key).await?;
         Ok(true)
     }
 
@@ -125,8 +380,119 @@ where
             .trigger_check(&cmd.unique_key)
             .await
             .map_err(|e| e.in_op_with("trigger_check", &cmd.unique_key))?;
+        self.invalidate_config(&cmd.unique_key).await?;
         Ok(true)
     }
+
+    /// 强制触发 Dispatch，返回触发的 Tag (未注册时返回 None)
+    pub async fn force_dispatch(
+        &self,
+        cmd: RegistryForceDispatchCmd,
+    ) -> WatchResult<Option<String>> {
+        if !self.storage.contains(&cmd.unique_key).await? {
+            return Ok(None);
+        }
+        let tag = self
+            .monitor_client
+            .force_dispatch(&cmd.unique_key)
+            .await
+            .map_err(|e| e.in_op_with("force_dispatch", &cmd.unique_key))?;
+        self.invalidate_config(&cmd.unique_key).await?;
+        Ok(Some(tag))
+    }
+
+    /// 重新安排下一次检查时间
+    pub async fn reschedule(&self, cmd: RegistryRescheduleCmd) -> WatchResult<bool> {
+        if !self.storage.contains(&cmd.unique_key).await? {
+            return Ok(false);
+        }
+        self.monitor_client
+            .reschedule(&cmd.unique_key, cmd.next_check_at)
+            .await
+            .map_err(|e| e.in_op_with("reschedule", &cmd.unique_key))?;
+        self.invalidate_config(&cmd.unique_key).await?;
+        Ok(true)
+    }
+
+    /// 更新 Monitor 的可变配置 (未注册时返回 None)
+    pub async fn update_config(
+        &self,
+        cmd: RegistryUpdateConfigCmd,
+    ) -> WatchResult<Option<UpdateProjectResponse>> {
+        if !self.storage.contains(&cmd.unique_key).await? {
+            return Ok(None);
+        }
+        let response = self
+            .monitor_client
+            .update_config(&cmd.unique_key, &cmd.update)
+            .await
+            .map_err(|e| e.in_op_with("update_config", &cmd.unique_key))?;
+        self.invalidate_config(&cmd.unique_key).await?;
+        Ok(Some(response))
+    }
+
+    /// 获取单个 Monitor 的配置 (未注册时返回 None)
+    pub async fn get_config(
+        &self,
+        cmd: RegistryGetConfigCmd,
+    ) -> WatchResult<Option<ProjectConfig>> {
+        if !self.storage.contains(&cmd.unique_key).await? {
+            return Ok(None);
+        }
+        self.load_config(&cmd.unique_key)
+            .await
+            .map_err(|e| e.in_op_with("get_config", &cmd.unique_key))
+    }
+
+    /// 获取检查历史
+    pub async fn get_history(
+        &self,
+        cmd: RegistryGetHistoryCmd,
+    ) -> WatchResult<Option<Vec<CheckEvent>>> {
+        if !self.storage.contains(&cmd.unique_key).await? {
+            return Ok(None);
+        }
+        self.monitor_client
+            .get_history(&cmd.unique_key)
+            .await
+            .map(Some)
+            .map_err(|e| e.in_op_with("get_history", &cmd.unique_key))
+    }
+
+    /// 追加一条审计记录，只保留最近 `MAX_AUDIT_EVENTS` 条
+    ///
+    /// 与限流一样依赖 Registry 单例 DO 的串行执行保证读改写的原子性
+    pub async fn append_audit_event(&self, cmd: AppendAuditEventCmd) -> WatchResult<()> {
+        let mut events = self.storage.get_audit_log().await?;
+        events.push(cmd.event);
+        if events.len() > MAX_AUDIT_EVENTS {
+            events.drain(..events.len() - MAX_AUDIT_EVENTS);
+        }
+        self.storage.put_audit_log(&events).await
+    }
+
+    /// 获取审计日志 (最新的在最后)
+    pub async fn get_audit_log(&self, _cmd: GetAuditLogCmd) -> WatchResult<Vec<AuditEvent>> {
+        self.storage.get_audit_log().await
+    }
+
+    /// 为鉴权标签消耗一个限流令牌，返回是否放行
+    ///
+    /// Registry 是单例 DO，请求串行执行，读改写无需额外加锁
+    pub async fn consume_rate_token(&self, cmd: ConsumeRateTokenCmd) -> WatchResult<bool> {
+        let now = Date::now_timestamp();
+        let mut bucket = self
+            .storage
+            .get_rate_bucket(&cmd.label)
+            .await?
+            .unwrap_or_else(|| RateBucket::full(&cmd.limit, now));
+        let allowed = bucket.try_take(&cmd.limit, now);
+        self.storage
+            .put_rate_bucket(&cmd.label, &bucket)
+            .await
+            .map_err(|e| e.in_op_with("rate_limit", &cmd.label))?;
+        Ok(allowed)
+    }
 }
 
 // =========================================================
@@ -161,6 +527,10 @@ impl DurableObject for ProjectRegistry {
             RegisterMonitorCmd::PATH => RpcHandler::handle(req, |c| logic.register(c)).await,
             UnregisterMonitorCmd::PATH => RpcHandler::handle(req, |c| logic.unregister(c)).await,
             ListMonitorsCmd::PATH => RpcHandler::handle(req, |c| logic.list(c)).await,
+            ListMonitorsLenientCmd::PATH => {
+                RpcHandler::handle(req, |c| logic.list_lenient(c)).await
+            }
+            ListMonitorsPageCmd::PATH => RpcHandler::handle(req, |c| logic.list_page(c)).await,
             IsRegisteredCmd::PATH => RpcHandler::handle(req, |c| logic.is_registered(c)).await,
             RegistrySwitchMonitorCmd::PATH => {
                 RpcHandler::handle(req, |c| logic.switch_monitor(c)).await
@@ -168,6 +538,28 @@ impl DurableObject for ProjectRegistry {
             RegistryTriggerCheckCmd::PATH => {
                 RpcHandler::handle(req, |c| logic.trigger_check(c)).await
             }
+            RegistryForceDispatchCmd::PATH => {
+                RpcHandler::handle(req, |c| logic.force_dispatch(c)).await
+            }
+            RegistryRescheduleCmd::PATH => RpcHandler::handle(req, |c| logic.reschedule(c)).await,
+            RegistryUpdateConfigCmd::PATH => {
+                RpcHandler::handle(req, |c| logic.update_config(c)).await
+            }
+            RegistryGetHistoryCmd::PATH => RpcHandler::handle(req, |c| logic.get_history(c)).await,
+            RegistryGetConfigCmd::PATH => RpcHandler::handle(req, |c| logic.get_config(c)).await,
+            RegistrySetEnabledCmd::PATH => RpcHandler::handle(req, |c| logic.set_enabled(c)).await,
+            RegistrySwitchAllCmd::PATH => RpcHandler::handle(req, |c| logic.switch_all(c)).await,
+            RegistryAuditCmd::PATH => RpcHandler::handle(req, |c| logic.audit(c)).await,
+            GetFeedCmd::PATH => RpcHandler::handle(req, |c| logic.feed(c)).await,
+            ListDetailedCmd::PATH => RpcHandler::handle(req, |c| logic.list_detailed(c)).await,
+            AppendAuditEventCmd::PATH => {
+                RpcHandler::handle(req, |c| logic.append_audit_event(c)).await
+            }
+            GetAuditLogCmd::PATH => RpcHandler::handle(req, |c| logic.get_audit_log(c)).await,
+            GetDeadLetterCmd::PATH => RpcHandler::handle(req, |c| logic.get_dead_letters(c)).await,
+            ConsumeRateTokenCmd::PATH => {
+                RpcHandler::handle(req, |c| logic.consume_rate_token(c)).await
+            }
             _ => Response::error("Not Found", 404),
         }
     }
@@ -2,11 +2,16 @@ use super::super::adapter::tests::MockEnv;
 use super::super::adapter::{MonitorClient, RegistryStorageAdapter};
 use super::*;
 use crate::error::{WatchError, WatchResult};
+use crate::project::protocol::UpdateConfigCmd;
+use crate::utils::rate_limit::{RateBucket, RateLimit};
 use async_trait::async_trait;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
-use verwatch_shared::{BaseConfig, CreateProjectRequest, MonitorState, TimeConfig};
+use verwatch_shared::{
+    AuditEvent, BaseConfig, CheckEvent, CreateProjectRequest, DeadLetter, MonitorState,
+    ProjectPage, ProjectWithState, TimeConfig, Timestamp, protocol::FeedEntry,
+};
 
 // =========================================================
 // Shared Mock Components
@@ -21,6 +26,24 @@ struct TestContext {
     monitor_configs: RefCell<HashMap<String, ProjectConfig>>,
     /// Set of keys to simulate failure on get_config
     fail_get_config_keys: RefCell<HashSet<String>>,
+    /// In-memory storage of rate limit buckets
+    rate_buckets: RefCell<HashMap<String, RateBucket>>,
+    /// In-memory storage of the audit log
+    audit_log: RefCell<Vec<AuditEvent>>,
+    /// In-memory storage of failed registrations
+    dead_letters: RefCell<Vec<DeadLetter>>,
+    /// In-memory storage of cached monitor configs
+    cached_configs: RefCell<HashMap<String, CachedConfig>>,
+    /// Set of keys to simulate failure on setup
+    fail_setup_keys: RefCell<HashSet<String>>,
+    /// Simulate failure when deleting cached configs
+    fail_cache_delete: Cell<bool>,
+    /// Keys whose monitor re-arms a lost alarm on health check
+    lost_alarm_keys: RefCell<HashSet<String>>,
+    /// Number of get_config calls currently in flight
+    in_flight: Cell<usize>,
+    /// Highest observed value of `in_flight`
+    max_in_flight: Cell<usize>,
 }
 
 impl TestContext {
@@ -30,6 +53,15 @@ impl TestContext {
             storage_keys: RefCell::new(HashSet::new()),
             monitor_configs: RefCell::new(HashMap::new()),
             fail_get_config_keys: RefCell::new(HashSet::new()),
+            rate_buckets: RefCell::new(HashMap::new()),
+            audit_log: RefCell::new(Vec::new()),
+            dead_letters: RefCell::new(Vec::new()),
+            cached_configs: RefCell::new(HashMap::new()),
+            fail_setup_keys: RefCell::new(HashSet::new()),
+            fail_cache_delete: Cell::new(false),
+            lost_alarm_keys: RefCell::new(HashSet::new()),
+            in_flight: Cell::new(0),
+            max_in_flight: Cell::new(0),
         }
     }
 
@@ -64,6 +96,56 @@ impl RegistryStorageAdapter for TestStorage {
         self.ctx.push_log(format!("storage:contains:{}", key));
         Ok(self.ctx.storage_keys.borrow().contains(key))
     }
+
+    async fn get_rate_bucket(&self, label: &str) -> WatchResult<Option<RateBucket>> {
+        Ok(self.ctx.rate_buckets.borrow().get(label).cloned())
+    }
+
+    async fn put_rate_bucket(&self, label: &str, bucket: &RateBucket) -> WatchResult<()> {
+        self.ctx
+            .rate_buckets
+            .borrow_mut()
+            .insert(label.to_string(), bucket.clone());
+        Ok(())
+    }
+
+    async fn get_audit_log(&self) -> WatchResult<Vec<AuditEvent>> {
+        Ok(self.ctx.audit_log.borrow().clone())
+    }
+
+    async fn put_audit_log(&self, events: &[AuditEvent]) -> WatchResult<()> {
+        *self.ctx.audit_log.borrow_mut() = events.to_vec();
+        Ok(())
+    }
+
+    async fn get_dead_letters(&self) -> WatchResult<Vec<DeadLetter>> {
+        Ok(self.ctx.dead_letters.borrow().clone())
+    }
+
+    async fn put_dead_letters(&self, entries: &[DeadLetter]) -> WatchResult<()> {
+        *self.ctx.dead_letters.borrow_mut() = entries.to_vec();
+        Ok(())
+    }
+
+    async fn get_cached_config(&self, key: &str) -> WatchResult<Option<CachedConfig>> {
+        Ok(self.ctx.cached_configs.borrow().get(key).cloned())
+    }
+
+    async fn put_cached_config(&self, key: &str, entry: &CachedConfig) -> WatchResult<()> {
+        self.ctx
+            .cached_configs
+            .borrow_mut()
+            .insert(key.to_string(), entry.clone());
+        Ok(())
+    }
+
+    async fn delete_cached_config(&self, key: &str) -> WatchResult<()> {
+        if self.ctx.fail_cache_delete.get() {
+            return Err(WatchError::store("Simulated cache failure"));
+        }
+        self.ctx.cached_configs.borrow_mut().remove(key);
+        Ok(())
+    }
 }
 
 struct TestMonitorClient {
@@ -74,6 +156,9 @@ struct TestMonitorClient {
 impl MonitorClient for TestMonitorClient {
     async fn setup(&self, unique_key: &str, config: &ProjectConfig) -> WatchResult<()> {
         self.ctx.push_log(format!("monitor:setup:{}", unique_key));
+        if self.ctx.fail_setup_keys.borrow().contains(unique_key) {
+            return Err(WatchError::store("Simulated setup failure"));
+        }
         self.ctx
             .monitor_configs
             .borrow_mut()
@@ -92,12 +177,29 @@ impl MonitorClient for TestMonitorClient {
         if self.ctx.fail_get_config_keys.borrow().contains(unique_key) {
             return Err(WatchError::store("Simulated failure"));
         }
+
+        // Yield once so that concurrent calls overlap and can be counted
+        let in_flight = self.ctx.in_flight.get() + 1;
+        self.ctx.in_flight.set(in_flight);
+        self.ctx
+            .max_in_flight
+            .set(self.ctx.max_in_flight.get().max(in_flight));
+        tokio::task::yield_now().await;
+        self.ctx.in_flight.set(self.ctx.in_flight.get() - 1);
+
         Ok(self.ctx.monitor_configs.borrow().get(unique_key).cloned())
     }
 
     async fn switch(&self, unique_key: &str, paused: bool) -> WatchResult<()> {
         self.ctx
             .push_log(format!("monitor:switch:{}:{}", unique_key, paused));
+        if let Some(config) = self.ctx.monitor_configs.borrow_mut().get_mut(unique_key) {
+            config.state = if paused {
+                MonitorState::Paused
+            } else {
+                MonitorState::running(Timestamp::new(0))
+            };
+        }
         Ok(())
     }
 
@@ -106,17 +208,124 @@ impl MonitorClient for TestMonitorClient {
             .push_log(format!("monitor:trigger_check:{}", unique_key));
         Ok(())
     }
+
+    async fn force_dispatch(&self, unique_key: &str) -> WatchResult<String> {
+        self.ctx
+            .push_log(format!("monitor:force_dispatch:{}", unique_key));
+        Ok("v1.0.0".into())
+    }
+
+    async fn reschedule(&self, unique_key: &str, next_check_at: Timestamp) -> WatchResult<()> {
+        self.ctx.push_log(format!(
+            "monitor:reschedule:{}:{}",
+            unique_key,
+            next_check_at.as_millis()
+        ));
+        Ok(())
+    }
+
+    async fn update_config(
+        &self,
+        unique_key: &str,
+        update: &UpdateConfigCmd,
+    ) -> WatchResult<UpdateProjectResponse> {
+        self.ctx
+            .push_log(format!("monitor:update_config:{}", unique_key));
+        let mut configs = self.ctx.monitor_configs.borrow_mut();
+        let Some(config) = configs.get_mut(unique_key) else {
+            return Err(WatchError::not_found("No config found"));
+        };
+        let original = config.clone();
+        update.apply(config);
+        Ok(UpdateProjectResponse {
+            config: config.clone(),
+            changes: original.diff(config),
+        })
+    }
+
+    async fn get_history(&self, unique_key: &str) -> WatchResult<Vec<CheckEvent>> {
+        self.ctx
+            .push_log(format!("monitor:get_history:{}", unique_key));
+        Ok(Vec::new())
+    }
+
+    async fn get_feed_entry(&self, unique_key: &str) -> WatchResult<Option<FeedEntry>> {
+        self.ctx
+            .push_log(format!("monitor:get_feed_entry:{}", unique_key));
+        if self.ctx.fail_get_config_keys.borrow().contains(unique_key) {
+            return Err(WatchError::store("Simulated failure"));
+        }
+        Ok(self
+            .ctx
+            .monitor_configs
+            .borrow()
+            .get(unique_key)
+            .filter(|c| c.enabled)
+            .map(|c| FeedEntry {
+                upstream: format!(
+                    "{}/{}",
+                    c.request.base_config.upstream_owner, c.request.base_config.upstream_repo
+                ),
+                current_version: Some(format!("{}-v1", unique_key)),
+                checked_at: None,
+            }))
+    }
+
+    async fn get_with_state(&self, unique_key: &str) -> WatchResult<Option<ProjectWithState>> {
+        self.ctx
+            .push_log(format!("monitor:get_with_state:{}", unique_key));
+        if self.ctx.fail_get_config_keys.borrow().contains(unique_key) {
+            return Err(WatchError::store("Simulated failure"));
+        }
+        Ok(self
+            .ctx
+            .monitor_configs
+            .borrow()
+            .get(unique_key)
+            .filter(|c| c.enabled)
+            .map(|c| ProjectWithState {
+                config: c.clone(),
+                current_version: Some(format!("{}-v1", unique_key)),
+                checked_at: None,
+            }))
+    }
+
+    async fn set_enabled(&self, unique_key: &str, enabled: bool) -> WatchResult<()> {
+        self.ctx
+            .push_log(format!("monitor:set_enabled:{}:{}", unique_key, enabled));
+        if let Some(config) = self.ctx.monitor_configs.borrow_mut().get_mut(unique_key) {
+            config.enabled = enabled;
+        }
+        Ok(())
+    }
+
+    async fn health_check(&self, unique_key: &str) -> WatchResult<bool> {
+        self.ctx
+            .push_log(format!("monitor:health_check:{}", unique_key));
+        if self.ctx.fail_get_config_keys.borrow().contains(unique_key) {
+            return Err(WatchError::store("Simulated failure"));
+        }
+        Ok(self.ctx.lost_alarm_keys.borrow_mut().remove(unique_key))
+    }
 }
 
 // Helper to create logic instance
 fn setup_env() -> (
     Rc<TestContext>,
     ProjectRegistryLogic<TestStorage, MockEnv, TestMonitorClient>,
+) {
+    setup_env_with(MockEnv::new())
+}
+
+fn setup_env_with(
+    env: MockEnv,
+) -> (
+    Rc<TestContext>,
+    ProjectRegistryLogic<TestStorage, MockEnv, TestMonitorClient>,
 ) {
     let ctx = Rc::new(TestContext::new());
     let storage = TestStorage { ctx: ctx.clone() };
     let client = TestMonitorClient { ctx: ctx.clone() };
-    let env = MockEnv::new(); // Assuming MockEnv::new() exists
     let logic = ProjectRegistryLogic::new(storage, env, client);
     (ctx, logic)
 }
@@ -135,8 +344,13 @@ fn make_test_config(key: &str) -> ProjectConfig {
             comparison_mode: verwatch_shared::ComparisonMode::PublishedAt,
             dispatch_token_secret: None,
             initial_delay: verwatch_shared::DurationSecs::from_secs(0),
+            ..Default::default()
         },
         state: MonitorState::Paused,
+        enabled: true,
+        last_dispatch_at: None,
+        last_outcome: None,
+        failed_checks: 0,
     }
 }
 
@@ -330,6 +544,37 @@ async fn test_list_with_partial_failure() {
     assert!(!keys.contains(&"bad-1"));
 }
 
+#[tokio::test]
+async fn test_list_lenient_reports_failed_keys() {
+    let (ctx, logic) = setup_env();
+
+    for key in ["good", "corrupt"] {
+        logic
+            .register(RegisterMonitorCmd {
+                config: make_test_config(key),
+            })
+            .await
+            .unwrap();
+    }
+    ctx.fail_get_config_keys
+        .borrow_mut()
+        .insert("corrupt".into());
+
+    let listing = logic
+        .list_lenient(ListMonitorsLenientCmd {
+            include_disabled: false,
+        })
+        .await
+        .unwrap();
+    let keys: Vec<_> = listing
+        .projects
+        .iter()
+        .map(|c| c.unique_key.as_str())
+        .collect();
+    assert_eq!(keys, ["good"]);
+    assert_eq!(listing.failed_keys, ["corrupt"]);
+}
+
 // New Tests
 
 #[tokio::test]
@@ -401,6 +646,128 @@ async fn test_trigger_check_success() {
     assert!(logs.iter().any(|s| s == "monitor:trigger_check:check-test"));
 }
 
+#[tokio::test]
+async fn test_force_dispatch_forwards_to_monitor() {
+    let (ctx, logic) = setup_env();
+    logic
+        .register(RegisterMonitorCmd {
+            config: make_test_config("force-test"),
+        })
+        .await
+        .unwrap();
+
+    let tag = logic
+        .force_dispatch(RegistryForceDispatchCmd {
+            unique_key: "force-test".into(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(tag.as_deref(), Some("v1.0.0"));
+    assert!(
+        ctx.log
+            .borrow()
+            .iter()
+            .any(|s| s == "monitor:force_dispatch:force-test")
+    );
+
+    // 未注册时不转发
+    let tag = logic
+        .force_dispatch(RegistryForceDispatchCmd {
+            unique_key: "missing".into(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(tag, None);
+}
+
+#[tokio::test]
+async fn test_reschedule_forwards_to_monitor() {
+    let (ctx, logic) = setup_env();
+    logic
+        .register(RegisterMonitorCmd {
+            config: make_test_config("resched"),
+        })
+        .await
+        .unwrap();
+
+    let registered = logic
+        .reschedule(RegistryRescheduleCmd {
+            unique_key: "resched".into(),
+            next_check_at: Timestamp::new(1_000),
+        })
+        .await
+        .unwrap();
+    assert!(registered);
+
+    let missing = logic
+        .reschedule(RegistryRescheduleCmd {
+            unique_key: "not-found".into(),
+            next_check_at: Timestamp::new(1_000),
+        })
+        .await
+        .unwrap();
+    assert!(!missing);
+
+    let logs = ctx.log.borrow();
+    assert!(logs.iter().any(|s| s == "monitor:reschedule:resched:1000"));
+    assert!(
+        !logs
+            .iter()
+            .any(|s| s.starts_with("monitor:reschedule:not-found"))
+    );
+}
+
+#[tokio::test]
+async fn test_update_config_forwards_to_monitor() {
+    let (ctx, logic) = setup_cached_env();
+    logic
+        .register(RegisterMonitorCmd {
+            config: make_test_config("update"),
+        })
+        .await
+        .unwrap();
+    logic
+        .get_config(RegistryGetConfigCmd {
+            unique_key: "update".into(),
+        })
+        .await
+        .unwrap();
+    assert!(ctx.cached_configs.borrow().contains_key("update"));
+
+    let response = logic
+        .update_config(RegistryUpdateConfigCmd {
+            unique_key: "update".into(),
+            update: UpdateConfigCmd {
+                dispatch_token_secret: Some("OTHER_PAT".into()),
+                ..Default::default()
+            },
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        response.config.request.dispatch_token_secret.as_deref(),
+        Some("OTHER_PAT")
+    );
+    // 更新后缓存失效
+    assert!(!ctx.cached_configs.borrow().contains_key("update"));
+
+    let missing = logic
+        .update_config(RegistryUpdateConfigCmd {
+            unique_key: "not-found".into(),
+            update: UpdateConfigCmd::default(),
+        })
+        .await
+        .unwrap();
+    assert!(missing.is_none());
+    assert!(
+        !ctx.log
+            .borrow()
+            .iter()
+            .any(|s| s.starts_with("monitor:update_config:not-found"))
+    );
+}
+
 #[tokio::test]
 async fn test_trigger_check_not_found() {
     let (ctx, logic) = setup_env();
@@ -416,3 +783,661 @@ async fn test_trigger_check_not_found() {
     let logs = ctx.log.borrow();
     assert!(!logs.iter().any(|s| s.starts_with("monitor:trigger_check")));
 }
+
+#[tokio::test]
+async fn test_set_enabled_hides_project_from_list() {
+    let (ctx, logic) = setup_env();
+
+    for key in ["enabled-a", "enabled-b"] {
+        logic
+            .register(RegisterMonitorCmd {
+                config: make_test_config(key),
+            })
+            .await
+            .unwrap();
+    }
+
+    let result = logic
+        .set_enabled(RegistrySetEnabledCmd {
+            unique_key: "enabled-a".into(),
+            enabled: false,
+        })
+        .await
+        .unwrap();
+    assert!(result);
+    assert!(
+        ctx.log
+            .borrow()
+            .iter()
+            .any(|s| s == "monitor:set_enabled:enabled-a:false")
+    );
+
+    // 禁用的项目仍然注册，但不出现在列表中
+    let list = logic.list(ListMonitorsCmd).await.unwrap();
+    let keys: Vec<_> = list.iter().map(|c| c.unique_key.as_str()).collect();
+    assert_eq!(keys, ["enabled-b"]);
+    assert!(
+        logic
+            .is_registered(IsRegisteredCmd {
+                unique_key: "enabled-a".into()
+            })
+            .await
+            .unwrap()
+    );
+
+    // 重新启用后恢复
+    logic
+        .set_enabled(RegistrySetEnabledCmd {
+            unique_key: "enabled-a".into(),
+            enabled: true,
+        })
+        .await
+        .unwrap();
+    assert_eq!(logic.list(ListMonitorsCmd).await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_set_enabled_not_found() {
+    let (ctx, logic) = setup_env();
+
+    let result = logic
+        .set_enabled(RegistrySetEnabledCmd {
+            unique_key: "missing".into(),
+            enabled: false,
+        })
+        .await
+        .unwrap();
+    assert!(!result);
+
+    // Verify monitor set_enabled NOT called
+    let logs = ctx.log.borrow();
+    assert!(!logs.iter().any(|s| s.starts_with("monitor:set_enabled")));
+}
+
+#[tokio::test]
+async fn test_list_page_boundaries_and_cursor() {
+    let (ctx, logic) = setup_env();
+
+    for i in 0..7 {
+        logic
+            .register(RegisterMonitorCmd {
+                config: make_test_config(&format!("page-{:02}", i)),
+            })
+            .await
+            .unwrap();
+    }
+
+    let page_keys = |page: &ProjectPage| -> Vec<String> {
+        page.items.iter().map(|c| c.unique_key.clone()).collect()
+    };
+
+    // 第一页
+    let first = logic
+        .list_page(ListMonitorsPageCmd {
+            cursor: None,
+            limit: 3,
+        })
+        .await
+        .unwrap();
+    assert_eq!(page_keys(&first), ["page-00", "page-01", "page-02"]);
+    assert_eq!(first.next_cursor.as_deref(), Some("page-02"));
+
+    // 仅查询本页的 Monitor
+    let get_config_calls = ctx
+        .log
+        .borrow()
+        .iter()
+        .filter(|s| s.starts_with("monitor:get_config"))
+        .count();
+    assert_eq!(get_config_calls, 3);
+
+    // 第二页
+    let second = logic
+        .list_page(ListMonitorsPageCmd {
+            cursor: first.next_cursor.clone(),
+            limit: 3,
+        })
+        .await
+        .unwrap();
+    assert_eq!(page_keys(&second), ["page-03", "page-04", "page-05"]);
+    assert_eq!(second.next_cursor.as_deref(), Some("page-05"));
+
+    // 最后一页不足 limit，没有下一页
+    let last = logic
+        .list_page(ListMonitorsPageCmd {
+            cursor: second.next_cursor.clone(),
+            limit: 3,
+        })
+        .await
+        .unwrap();
+    assert_eq!(page_keys(&last), ["page-06"]);
+    assert!(last.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn test_list_page_reports_failed_keys() {
+    let (ctx, logic) = setup_env();
+
+    for key in ["a-good", "b-corrupt", "c-good"] {
+        logic
+            .register(RegisterMonitorCmd {
+                config: make_test_config(key),
+            })
+            .await
+            .unwrap();
+    }
+    ctx.fail_get_config_keys
+        .borrow_mut()
+        .insert("b-corrupt".into());
+
+    let page = logic
+        .list_page(ListMonitorsPageCmd {
+            cursor: None,
+            limit: 3,
+        })
+        .await
+        .unwrap();
+    let keys: Vec<_> = page.items.iter().map(|c| c.unique_key.as_str()).collect();
+    assert_eq!(keys, ["a-good", "c-good"]);
+    assert_eq!(page.failed_keys, ["b-corrupt"]);
+}
+
+#[tokio::test]
+async fn test_list_page_exact_multiple_has_no_trailing_cursor() {
+    let (_, logic) = setup_env();
+
+    for i in 0..4 {
+        logic
+            .register(RegisterMonitorCmd {
+                config: make_test_config(&format!("exact-{}", i)),
+            })
+            .await
+            .unwrap();
+    }
+
+    let first = logic
+        .list_page(ListMonitorsPageCmd {
+            cursor: None,
+            limit: 2,
+        })
+        .await
+        .unwrap();
+    assert_eq!(first.next_cursor.as_deref(), Some("exact-1"));
+
+    let second = logic
+        .list_page(ListMonitorsPageCmd {
+            cursor: first.next_cursor,
+            limit: 2,
+        })
+        .await
+        .unwrap();
+    assert_eq!(second.items.len(), 2);
+    assert!(second.next_cursor.is_none());
+
+    // 游标指向已删除的 key 时仍从其之后继续
+    let resumed = logic
+        .list_page(ListMonitorsPageCmd {
+            cursor: Some("exact-0a".into()),
+            limit: 10,
+        })
+        .await
+        .unwrap();
+    let keys: Vec<_> = resumed
+        .items
+        .iter()
+        .map(|c| c.unique_key.as_str())
+        .collect();
+    assert_eq!(keys, ["exact-1", "exact-2", "exact-3"]);
+}
+
+#[tokio::test]
+async fn test_consume_rate_token_persists_bucket_per_label() {
+    let (ctx, logic) = setup_env();
+    let limit = RateLimit {
+        capacity: 1,
+        refill_per_minute: 1,
+    };
+    let cmd = |label: &str| ConsumeRateTokenCmd {
+        label: label.into(),
+        limit,
+    };
+
+    assert!(logic.consume_rate_token(cmd("ci")).await.unwrap());
+    assert!(!logic.consume_rate_token(cmd("ci")).await.unwrap());
+    assert!(logic.consume_rate_token(cmd("alice")).await.unwrap());
+
+    let buckets = ctx.rate_buckets.borrow();
+    assert!(buckets["ci"].tokens < 1.0);
+    // 令牌桶不应出现在 Monitor 列表中
+    assert!(ctx.storage_keys.borrow().is_empty());
+}
+
+#[tokio::test]
+async fn test_feed_skips_disabled_and_failed_monitors() {
+    let (ctx, logic) = setup_env();
+    for key in ["b-key", "a-key", "disabled-key", "broken-key"] {
+        let mut config = make_test_config(key);
+        config.request.base_config.upstream_repo = key.into();
+        config.enabled = key != "disabled-key";
+        logic.register(RegisterMonitorCmd { config }).await.unwrap();
+    }
+    ctx.fail_get_config_keys
+        .borrow_mut()
+        .insert("broken-key".into());
+
+    let feed = logic.feed(GetFeedCmd).await.unwrap();
+    let upstreams: Vec<&str> = feed.iter().map(|e| e.upstream.as_str()).collect();
+    assert_eq!(upstreams, ["owner/a-key", "owner/b-key"]);
+    assert_eq!(feed[0].current_version.as_deref(), Some("a-key-v1"));
+}
+
+#[tokio::test]
+async fn test_list_detailed_skips_disabled_and_failed_monitors() {
+    let (ctx, logic) = setup_env();
+    for key in ["b-key", "a-key", "disabled-key", "broken-key"] {
+        let mut config = make_test_config(key);
+        config.enabled = key != "disabled-key";
+        logic.register(RegisterMonitorCmd { config }).await.unwrap();
+    }
+    ctx.fail_get_config_keys
+        .borrow_mut()
+        .insert("broken-key".into());
+
+    let items = logic.list_detailed(ListDetailedCmd).await.unwrap();
+    let keys: Vec<&str> = items.iter().map(|i| i.config.unique_key.as_str()).collect();
+    assert_eq!(keys, ["a-key", "b-key"]);
+    assert_eq!(items[1].current_version.as_deref(), Some("b-key-v1"));
+}
+
+#[tokio::test]
+async fn test_audit_log_keeps_most_recent_events() {
+    let (ctx, logic) = setup_env();
+    for i in 0..MAX_AUDIT_EVENTS + 5 {
+        let event = AuditEvent {
+            at: Timestamp::new(i as i64),
+            actor: "ci".into(),
+            action: "create".into(),
+            target: format!("project-{}", i),
+            changes: Vec::new(),
+        };
+        logic
+            .append_audit_event(AppendAuditEventCmd { event })
+            .await
+            .unwrap();
+    }
+
+    let events = logic.get_audit_log(GetAuditLogCmd).await.unwrap();
+    assert_eq!(events.len(), MAX_AUDIT_EVENTS);
+    assert_eq!(events[0].target, "project-5");
+    assert_eq!(
+        events.last().unwrap().target,
+        format!("project-{}", MAX_AUDIT_EVENTS + 4)
+    );
+    // 审计日志不应出现在 Monitor 列表中
+    assert!(ctx.storage_keys.borrow().is_empty());
+}
+
+#[tokio::test]
+async fn test_switch_all_pauses_running_monitors() {
+    let (ctx, logic) = setup_env();
+    for key in ["project-a", "project-b"] {
+        let mut config = make_test_config(key);
+        config.state = MonitorState::running(verwatch_shared::Date::now_timestamp());
+        logic.register(RegisterMonitorCmd { config }).await.unwrap();
+    }
+    ctx.log.borrow_mut().clear();
+
+    let results = logic
+        .switch_all(RegistrySwitchAllCmd { paused: true })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        results.results,
+        vec![
+            ("project-a".to_string(), true),
+            ("project-b".to_string(), true)
+        ]
+    );
+    assert!(results.failed_keys.is_empty());
+    let log = ctx.log.borrow();
+    assert!(log.contains(&"monitor:switch:project-a:true".to_string()));
+    assert!(log.contains(&"monitor:switch:project-b:true".to_string()));
+}
+
+#[tokio::test]
+async fn test_switch_all_reports_noop_transitions() {
+    let (ctx, logic) = setup_env();
+    // make_test_config 默认为暂停状态
+    logic
+        .register(RegisterMonitorCmd {
+            config: make_test_config("already-paused"),
+        })
+        .await
+        .unwrap();
+    let mut running = make_test_config("running");
+    running.state = MonitorState::running(verwatch_shared::Date::now_timestamp());
+    logic
+        .register(RegisterMonitorCmd { config: running })
+        .await
+        .unwrap();
+
+    let results = logic
+        .switch_all(RegistrySwitchAllCmd { paused: true })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        results.results,
+        vec![
+            ("already-paused".to_string(), false),
+            ("running".to_string(), true)
+        ]
+    );
+    let log = ctx.log.borrow();
+    assert!(!log.contains(&"monitor:switch:already-paused:true".to_string()));
+}
+
+#[tokio::test]
+async fn test_switch_all_reports_failed_keys_separately() {
+    let (ctx, logic) = setup_env();
+    for key in ["broken", "healthy"] {
+        let mut config = make_test_config(key);
+        config.state = MonitorState::running(verwatch_shared::Date::now_timestamp());
+        logic.register(RegisterMonitorCmd { config }).await.unwrap();
+    }
+    ctx.fail_get_config_keys
+        .borrow_mut()
+        .insert("broken".to_string());
+
+    let report = logic
+        .switch_all(RegistrySwitchAllCmd { paused: true })
+        .await
+        .unwrap();
+
+    // 读取失败与已处于目标状态区分开
+    assert_eq!(report.results, vec![("healthy".to_string(), true)]);
+    assert_eq!(report.failed_keys, vec!["broken".to_string()]);
+}
+
+#[tokio::test]
+async fn test_list_respects_concurrency_limit() {
+    let (ctx, logic) = setup_env_with(MockEnv::new().with_var("MONITOR_LIST_CONCURRENCY", "3"));
+
+    for i in 0..25 {
+        logic
+            .register(RegisterMonitorCmd {
+                config: make_test_config(&format!("project-{}", i)),
+            })
+            .await
+            .unwrap();
+    }
+
+    let list = logic.list(ListMonitorsCmd).await.unwrap();
+    assert_eq!(list.len(), 25);
+    assert_eq!(ctx.max_in_flight.get(), 3);
+}
+
+#[tokio::test]
+async fn test_list_concurrency_defaults_when_unset_or_invalid() {
+    for env in [
+        MockEnv::new(),
+        MockEnv::new().with_var("MONITOR_LIST_CONCURRENCY", "0"),
+        MockEnv::new().with_var("MONITOR_LIST_CONCURRENCY", "abc"),
+    ] {
+        let (ctx, logic) = setup_env_with(env);
+        for i in 0..25 {
+            logic
+                .register(RegisterMonitorCmd {
+                    config: make_test_config(&format!("project-{}", i)),
+                })
+                .await
+                .unwrap();
+        }
+
+        let page = logic
+            .list_page(ListMonitorsPageCmd {
+                cursor: None,
+                limit: 25,
+            })
+            .await
+            .unwrap();
+        assert_eq!(page.items.len(), 25);
+        assert_eq!(ctx.max_in_flight.get(), DEFAULT_LIST_CONCURRENCY);
+    }
+}
+
+#[tokio::test]
+async fn test_audit_reports_rearmed_monitors() {
+    let (ctx, logic) = setup_env();
+    for key in ["c", "a", "b"] {
+        logic
+            .register(RegisterMonitorCmd {
+                config: make_test_config(key),
+            })
+            .await
+            .unwrap();
+    }
+    ctx.lost_alarm_keys
+        .borrow_mut()
+        .extend(["c".to_string(), "a".to_string()]);
+    // 单个 Monitor 失败不影响其余项目
+    ctx.fail_get_config_keys
+        .borrow_mut()
+        .insert("b".to_string());
+
+    let rearmed = logic.audit(RegistryAuditCmd).await.unwrap();
+    assert_eq!(rearmed, vec!["a".to_string(), "c".to_string()]);
+
+    // 再次检查时已无丢失的 Alarm
+    assert!(logic.audit(RegistryAuditCmd).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_setup_is_recorded_as_dead_letter() {
+    let (ctx, logic) = setup_env();
+    ctx.fail_setup_keys.borrow_mut().insert("broken".into());
+
+    let err = logic
+        .register(RegisterMonitorCmd {
+            config: make_test_config("broken"),
+        })
+        .await
+        .unwrap_err();
+    assert!(err.display_chain().contains("Simulated setup failure"));
+
+    // key 不会被记录到 Registry
+    assert!(
+        !logic
+            .is_registered(IsRegisteredCmd {
+                unique_key: "broken".into()
+            })
+            .await
+            .unwrap()
+    );
+    assert!(logic.list(ListMonitorsCmd).await.unwrap().is_empty());
+
+    let entries = logic.get_dead_letters(GetDeadLetterCmd).await.unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].unique_key, "broken");
+    assert!(entries[0].error.contains("Simulated setup failure"));
+    assert!(entries[0].at.as_millis() > 0);
+
+    // 成功的注册不产生记录，且记录条数有上限
+    logic
+        .register(RegisterMonitorCmd {
+            config: make_test_config("ok"),
+        })
+        .await
+        .unwrap();
+    for _ in 0..MAX_DEAD_LETTERS {
+        let _ = logic
+            .register(RegisterMonitorCmd {
+                config: make_test_config("broken"),
+            })
+            .await;
+    }
+    assert_eq!(ctx.dead_letters.borrow().len(), MAX_DEAD_LETTERS);
+}
+
+#[tokio::test]
+async fn test_failed_setup_dead_letter_survives_cache_failure() {
+    let (ctx, logic) = setup_env();
+    ctx.fail_setup_keys.borrow_mut().insert("broken".into());
+    ctx.fail_cache_delete.set(true);
+
+    let err = logic
+        .register(RegisterMonitorCmd {
+            config: make_test_config("broken"),
+        })
+        .await
+        .unwrap_err();
+
+    // 返回 setup 的原始错误，且仍写入注册失败记录
+    assert!(err.display_chain().contains("Simulated setup failure"));
+    let entries = logic.get_dead_letters(GetDeadLetterCmd).await.unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].unique_key, "broken");
+}
+
+// =========================================================
+// Config Cache Tests
+// =========================================================
+
+fn setup_cached_env() -> (
+    Rc<TestContext>,
+    ProjectRegistryLogic<TestStorage, MockEnv, TestMonitorClient>,
+) {
+    setup_env_with(MockEnv::new().with_var("CONFIG_CACHE_TTL_SECS", "60"))
+}
+
+fn get_config_calls(ctx: &TestContext) -> usize {
+    ctx.log
+        .borrow()
+        .iter()
+        .filter(|s| s.starts_with("monitor:get_config:"))
+        .count()
+}
+
+#[tokio::test]
+async fn test_list_serves_fresh_cache() {
+    let (ctx, logic) = setup_cached_env();
+    logic
+        .register(RegisterMonitorCmd {
+            config: make_test_config("cached"),
+        })
+        .await
+        .unwrap();
+
+    logic.list(ListMonitorsCmd).await.unwrap();
+    logic.list(ListMonitorsCmd).await.unwrap();
+    assert_eq!(get_config_calls(&ctx), 1);
+
+    // 过期的缓存重新查询 Monitor
+    ctx.cached_configs
+        .borrow_mut()
+        .get_mut("cached")
+        .unwrap()
+        .cached_at = Timestamp::new(0);
+    logic.list(ListMonitorsCmd).await.unwrap();
+    assert_eq!(get_config_calls(&ctx), 2);
+}
+
+#[tokio::test]
+async fn test_cache_disabled_by_default() {
+    let (ctx, logic) = setup_env();
+    logic
+        .register(RegisterMonitorCmd {
+            config: make_test_config("uncached"),
+        })
+        .await
+        .unwrap();
+
+    logic.list(ListMonitorsCmd).await.unwrap();
+    logic.list(ListMonitorsCmd).await.unwrap();
+    assert_eq!(get_config_calls(&ctx), 2);
+    assert!(ctx.cached_configs.borrow().is_empty());
+}
+
+#[tokio::test]
+async fn test_switch_invalidates_cached_state() {
+    let (_, logic) = setup_cached_env();
+    let mut config = make_test_config("switch-cache");
+    config.state = MonitorState::running(Timestamp::new(0));
+    logic.register(RegisterMonitorCmd { config }).await.unwrap();
+
+    // 填充缓存
+    let listed = logic.list(ListMonitorsCmd).await.unwrap();
+    assert!(listed[0].is_running());
+
+    logic
+        .switch_monitor(RegistrySwitchMonitorCmd {
+            unique_key: "switch-cache".into(),
+            paused: true,
+        })
+        .await
+        .unwrap();
+
+    // 切换后 list 立即反映暂停状态，而不是返回缓存中的运行状态
+    let listed = logic.list(ListMonitorsCmd).await.unwrap();
+    assert!(listed[0].state.is_paused());
+
+    logic
+        .switch_all(RegistrySwitchAllCmd { paused: false })
+        .await
+        .unwrap();
+    let listed = logic.list(ListMonitorsCmd).await.unwrap();
+    assert!(listed[0].is_running());
+}
+
+#[tokio::test]
+async fn test_unregister_and_set_enabled_invalidate_cache() {
+    let (ctx, logic) = setup_cached_env();
+    logic
+        .register(RegisterMonitorCmd {
+            config: make_test_config("toggle-cache"),
+        })
+        .await
+        .unwrap();
+    logic.list(ListMonitorsCmd).await.unwrap();
+    assert!(ctx.cached_configs.borrow().contains_key("toggle-cache"));
+
+    logic
+        .set_enabled(RegistrySetEnabledCmd {
+            unique_key: "toggle-cache".into(),
+            enabled: false,
+        })
+        .await
+        .unwrap();
+    assert!(logic.list(ListMonitorsCmd).await.unwrap().is_empty());
+
+    logic
+        .unregister(UnregisterMonitorCmd {
+            unique_key: "toggle-cache".into(),
+        })
+        .await
+        .unwrap();
+    assert!(!ctx.cached_configs.borrow().contains_key("toggle-cache"));
+}
+
+#[tokio::test]
+async fn test_force_dispatch_invalidates_cache() {
+    let (ctx, logic) = setup_cached_env();
+    logic
+        .register(RegisterMonitorCmd {
+            config: make_test_config("dispatch-cache"),
+        })
+        .await
+        .unwrap();
+    logic.list(ListMonitorsCmd).await.unwrap();
+    assert!(ctx.cached_configs.borrow().contains_key("dispatch-cache"));
+
+    // Dispatch 会更新 last_dispatch_at，缓存需要失效
+    logic
+        .force_dispatch(RegistryForceDispatchCmd {
+            unique_key: "dispatch-cache".into(),
+        })
+        .await
+        .unwrap();
+    assert!(!ctx.cached_configs.borrow().contains_key("dispatch-cache"));
+}
@@ -0,0 +1,67 @@
+// =========================================================
+// 子请求预算
+// =========================================================
+
+/// 单次调用 (一次 Alarm) 的默认子请求上限，与 Workers 免费计划一致
+pub const DEFAULT_SUBREQUEST_BUDGET: u32 = 50;
+
+/// 单次调用剩余的子请求数量
+///
+/// 超出 Workers 的子请求上限后 fetch 会直接失败。检查流程中必要的请求
+/// (获取上游版本、Dispatch) 只记账，非必要的请求 (通知) 在预算不足时跳过
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubrequestBudget {
+    remaining: u32,
+}
+
+impl SubrequestBudget {
+    pub fn new(limit: u32) -> Self {
+        Self { remaining: limit }
+    }
+
+    /// 记录 `n` 个必要的子请求，预算不足时照常发出 (剩余数量扣减到 0 为止)
+    pub fn spend(&mut self, n: u32) {
+        self.remaining = self.remaining.saturating_sub(n);
+    }
+
+    /// 为 `n` 个非必要的子请求申请预算，不足时返回 false 且不扣减
+    pub fn try_spend(&mut self, n: u32) -> bool {
+        if self.remaining < n {
+            return false;
+        }
+        self.remaining -= n;
+        true
+    }
+}
+
+impl Default for SubrequestBudget {
+    fn default() -> Self {
+        Self::new(DEFAULT_SUBREQUEST_BUDGET)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spend_saturates_at_zero() {
+        let mut budget = SubrequestBudget::new(2);
+        budget.spend(1);
+        assert_eq!(budget, SubrequestBudget::new(1));
+        budget.spend(5);
+        assert_eq!(budget, SubrequestBudget::new(0));
+    }
+
+    #[test]
+    fn test_try_spend_rejects_without_consuming() {
+        let mut budget = SubrequestBudget::new(2);
+        assert!(budget.try_spend(2));
+        assert!(!budget.try_spend(1));
+        assert_eq!(budget, SubrequestBudget::new(0));
+
+        let mut budget = SubrequestBudget::new(1);
+        assert!(!budget.try_spend(2));
+        assert_eq!(budget, SubrequestBudget::new(1));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::error::{WatchError, WatchResult};
+use flate2::Compression;
+use flate2::write::{GzEncoder, ZlibEncoder};
+use std::io::Write;
+
+// =========================================================
+// 响应压缩
+// =========================================================
+
+/// 响应体小于该字节数时不压缩 (压缩收益抵不上开销)
+pub const DEFAULT_COMPRESS_THRESHOLD: usize = 8 * 1024;
+
+/// 支持的 `Content-Encoding`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentEncoding {
+    Gzip,
+    /// HTTP 中的 `deflate` 即 zlib 格式
+    Deflate,
+}
+
+impl ContentEncoding {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ContentEncoding::Gzip => "gzip",
+            ContentEncoding::Deflate => "deflate",
+        }
+    }
+
+    /// 解析 `Accept-Encoding`，优先选择 gzip；`q=0` 视为不接受
+    pub fn negotiate(accept_encoding: Option<&str>) -> Option<Self> {
+        let accepted = |name: &str| {
+            accept_encoding.is_some_and(|header| {
+                header.split(',').any(|item| {
+                    let mut parts = item.split(';');
+                    let coding = parts.next().unwrap_or_default().trim();
+                    let rejected = parts.any(|p| {
+                        p.trim()
+                            .strip_prefix("q=")
+                            .and_then(|q| q.trim().parse::<f32>().ok())
+                            .is_some_and(|q| q <= 0.0)
+                    });
+                    coding.eq_ignore_ascii_case(name) && !rejected
+                })
+            })
+        };
+        if accepted("gzip") {
+            Some(ContentEncoding::Gzip)
+        } else if accepted("deflate") {
+            Some(ContentEncoding::Deflate)
+        } else {
+            None
+        }
+    }
+}
+
+/// 决定响应是否压缩：客户端接受且响应体达到阈值时返回使用的编码
+pub fn should_compress(
+    accept_encoding: Option<&str>,
+    body_len: usize,
+    threshold: usize,
+) -> Option<ContentEncoding> {
+    if body_len < threshold {
+        return None;
+    }
+    ContentEncoding::negotiate(accept_encoding)
+}
+
+/// 按指定编码压缩响应体
+pub fn compress(body: &[u8], encoding: ContentEncoding) -> WatchResult<Vec<u8>> {
+    let result = match encoding {
+        ContentEncoding::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(body).and_then(|_| encoder.finish())
+        }
+        ContentEncoding::Deflate => {
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(body).and_then(|_| encoder.finish())
+        }
+    };
+    result.map_err(|e| WatchError::serialization(e.to_string()).in_op("compress"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    #[test]
+    fn test_compress_above_threshold_skip_below() {
+        let gzip = Some("gzip, deflate, br");
+        assert_eq!(should_compress(gzip, 100, 1024), None);
+        assert_eq!(
+            should_compress(gzip, 1024, 1024),
+            Some(ContentEncoding::Gzip)
+        );
+        // 客户端未声明支持时不压缩
+        assert_eq!(should_compress(None, 4096, 1024), None);
+        assert_eq!(should_compress(Some("identity"), 4096, 1024), None);
+    }
+
+    #[test]
+    fn test_negotiate_encoding() {
+        assert_eq!(
+            ContentEncoding::negotiate(Some("deflate")),
+            Some(ContentEncoding::Deflate)
+        );
+        assert_eq!(
+            ContentEncoding::negotiate(Some("GZIP;q=0.5, deflate")),
+            Some(ContentEncoding::Gzip)
+        );
+        assert_eq!(
+            ContentEncoding::negotiate(Some("gzip;q=0, deflate")),
+            Some(ContentEncoding::Deflate)
+        );
+        assert_eq!(ContentEncoding::negotiate(Some("gzip;q=0")), None);
+    }
+
+    #[test]
+    fn test_gzip_round_trip() {
+        let body = "[{\"unique_key\":\"a\"}]".repeat(100);
+        let compressed = compress(body.as_bytes(), ContentEncoding::Gzip).unwrap();
+        assert!(compressed.len() < body.len());
+
+        let mut decoded = String::new();
+        GzDecoder::new(compressed.as_slice())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, body);
+    }
+}
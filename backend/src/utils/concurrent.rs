@@ -17,6 +17,9 @@ use std::task::{Context, Poll};
 ///
 /// # 返回
 /// - 所有 Future 结果的 Vec（保持顺序）
+///
+/// 扇出 DO 调用的场景应使用 [`join_all_bounded`]，以免超出子请求限制。
+#[allow(dead_code)]
 pub fn join_all<F>(futures: impl IntoIterator<Item = F>) -> JoinAll<F>
 where
     F: Future,
@@ -109,6 +112,26 @@ impl<F: Future> Future for JoinAll<F> {
 // 实现 Unpin，因为我们使用 Vec 并手动处理 Pin
 impl<F: Future> Unpin for JoinAll<F> {}
 
+// =============================================================================
+// 限流版本：同时最多运行 `limit` 个任务
+// =============================================================================
+
+/// 并发执行多个异步任务，但同时运行的任务数不超过 `limit`
+///
+/// 用于扇出大量 DO 调用的场景，避免超出 Worker 的子请求限制。
+/// 结果顺序与输入一致；`limit` 为 0 时按 1 处理。
+pub async fn join_all_bounded<F>(
+    futures: impl IntoIterator<Item = F>,
+    limit: usize,
+) -> Vec<F::Output>
+where
+    F: Future,
+{
+    use futures::stream::{self, StreamExt};
+
+    stream::iter(futures).buffered(limit.max(1)).collect().await
+}
+
 // =============================================================================
 // 简化版本：顺序执行（作为备选方案）
 // =============================================================================
@@ -0,0 +1,220 @@
+use crate::error::{WatchError, WatchResult};
+use crate::utils::github::gateway::DEFAULT_USER_AGENT;
+use crate::utils::github::release::{GitHubRelease, ReleasePayload};
+use crate::utils::provider::ReleaseProvider;
+use crate::utils::request::{HttpClient, HttpMethod, HttpRequest};
+use verwatch_shared::ComparisonMode;
+
+/// Gitea / Forgejo Releases 网关
+///
+/// API 与 GitHub 基本兼容，但实例地址由项目配置决定
+pub struct GiteaGateway<'a, C: HttpClient> {
+    client: &'a C,
+    base_url: &'a str,
+    token: Option<String>,
+    mode: ComparisonMode,
+    user_agent: String,
+}
+
+impl<'a, C: HttpClient> GiteaGateway<'a, C> {
+    pub fn new(
+        client: &'a C,
+        base_url: &'a str,
+        token: Option<String>,
+        mode: ComparisonMode,
+    ) -> Self {
+        Self {
+            client,
+            base_url,
+            token,
+            mode,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+        }
+    }
+
+    /// 设置请求使用的 User-Agent
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    fn latest_release_url(&self, owner: &str, repo: &str) -> String {
+        format!(
+            "{}/api/v1/repos/{}/{}/releases/latest",
+            self.base_url.trim_end_matches('/'),
+            owner,
+            repo
+        )
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<'a, C: HttpClient> ReleaseProvider for GiteaGateway<'a, C> {
+    async fn fetch_latest_release(&self, owner: &str, repo: &str) -> WatchResult<GitHubRelease> {
+        let repo_path = format!("{}/{}", owner, repo);
+        let url = self.latest_release_url(owner, repo);
+
+        let mut req =
+            HttpRequest::new(&url, HttpMethod::Get).with_header("User-Agent", &self.user_agent);
+        if let Some(token) = &self.token {
+            req = req.with_header("Authorization", &format!("token {}", token));
+        }
+
+        let resp = self
+            .client
+            .send(req)
+            .await
+            .map_err(|e| e.in_op_with("gitea.fetch", &repo_path))?;
+
+        if resp.status != 200 {
+            return Err(WatchError::external_api(format!(
+                "Upstream API Error {}: {}",
+                resp.status, url
+            ))
+            .in_op_with("gitea.fetch", &repo_path));
+        }
+
+        let payload: ReleasePayload = resp
+            .json()
+            .map_err(|e| e.in_op_with("gitea.parse", &repo_path))?;
+
+        payload.into_release(self.mode, &repo_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::github::release::ReleaseTimestamp;
+    use crate::utils::request::MockHttpClient;
+    use verwatch_shared::Date;
+
+    const LATEST_URL: &str = "https://codeberg.org/api/v1/repos/forgejo/forgejo/releases/latest";
+
+    fn mock_latest(client: &MockHttpClient) {
+        client.mock_response(
+            LATEST_URL,
+            200,
+            serde_json::json!({
+                "id": 1,
+                "tag_name": "v7.0.0",
+                "name": "v7.0.0",
+                "body": "Notes",
+                "html_url": "https://codeberg.org/forgejo/forgejo/releases/tag/v7.0.0",
+                "created_at": "2024-04-20T09:00:00Z",
+                "published_at": "2024-04-21T10:00:00Z"
+            }),
+        );
+    }
+
+    #[test]
+    fn test_latest_release_url_trims_trailing_slash() {
+        let client = MockHttpClient::new();
+        let gateway = GiteaGateway::new(
+            &client,
+            "https://codeberg.org/",
+            None,
+            ComparisonMode::PublishedAt,
+        );
+        assert_eq!(gateway.latest_release_url("forgejo", "forgejo"), LATEST_URL);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_latest_release_parses_gitea_shape() {
+        let client = MockHttpClient::new();
+        mock_latest(&client);
+        let gateway = GiteaGateway::new(
+            &client,
+            "https://codeberg.org",
+            Some("gitea-token".into()),
+            ComparisonMode::PublishedAt,
+        );
+
+        let release = gateway
+            .fetch_latest_release("forgejo", "forgejo")
+            .await
+            .unwrap();
+
+        assert_eq!(release.tag_name, "v7.0.0");
+        assert_eq!(
+            release.timestamp,
+            ReleaseTimestamp::Published(Date::parse_timestamp("2024-04-21T10:00:00Z").unwrap())
+        );
+        assert_eq!(
+            release.html_url.as_deref(),
+            Some("https://codeberg.org/forgejo/forgejo/releases/tag/v7.0.0")
+        );
+        assert_eq!(release.body.as_deref(), Some("Notes"));
+
+        let requests = client.requests.borrow();
+        let (_, _, headers, _) = &requests[0];
+        assert_eq!(
+            headers.get("Authorization").map(String::as_str),
+            Some("token gitea-token")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_requests_carry_configured_user_agent() {
+        let client = MockHttpClient::new();
+        mock_latest(&client);
+        let gateway = GiteaGateway::new(
+            &client,
+            "https://codeberg.org",
+            None,
+            ComparisonMode::PublishedAt,
+        )
+        .with_user_agent("verwatch (ops@example.com)");
+
+        gateway
+            .fetch_latest_release("forgejo", "forgejo")
+            .await
+            .unwrap();
+
+        let requests = client.requests.borrow();
+        let (_, _, headers, _) = &requests[0];
+        assert_eq!(
+            headers.get("User-Agent").map(String::as_str),
+            Some("verwatch (ops@example.com)")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_updated_mode_falls_back_to_created_at() {
+        let client = MockHttpClient::new();
+        mock_latest(&client);
+        let gateway = GiteaGateway::new(
+            &client,
+            "https://codeberg.org",
+            None,
+            ComparisonMode::UpdatedAt,
+        );
+
+        let release = gateway
+            .fetch_latest_release("forgejo", "forgejo")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            release.timestamp,
+            ReleaseTimestamp::Updated(Date::parse_timestamp("2024-04-20T09:00:00Z").unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_non_200_is_external_api_error() {
+        let client = MockHttpClient::new();
+        let gateway = GiteaGateway::new(
+            &client,
+            "https://codeberg.org",
+            None,
+            ComparisonMode::PublishedAt,
+        );
+
+        let err = gateway
+            .fetch_latest_release("forgejo", "forgejo")
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, crate::error::WatchErrorStatus::ExternalApi);
+    }
+}
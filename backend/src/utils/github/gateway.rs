@@ -1,11 +1,17 @@
 use crate::error::{WatchError, WatchResult};
-use crate::utils::github::release::{GitHubRelease, ReleaseTimestamp};
-use crate::utils::request::{HttpClient, HttpMethod, HttpRequest};
+use crate::utils::github::release::{GitHubRelease, ReleasePayload, ReleaseTimestamp};
+use crate::utils::provider::ReleaseProvider;
+use crate::utils::request::{HttpClient, HttpMethod, HttpRequest, HttpResponse};
 use serde::{Deserialize, Serialize};
-use verwatch_shared::{ComparisonMode, Date, ProjectConfig};
+use std::borrow::Cow;
+use verwatch_shared::{ComparisonMode, ProjectConfig, SourceKind};
 
 pub const GITHUB_API_VERSION: &str = "2022-11-28";
-const USER_AGENT: &str = "rust-watchdog-worker";
+/// 默认 User-Agent，可通过 `GITHUB_USER_AGENT` 覆盖
+pub const DEFAULT_USER_AGENT: &str = "rust-watchdog-worker";
+/// Dispatch 载荷中 notes 的默认最大字节数 (GitHub 拒绝超过 64KB 的载荷)
+pub const DEFAULT_NOTES_MAX_BYTES: usize = 8 * 1024;
+const NOTES_ELLIPSIS: &str = "…";
 
 // =========================================================
 // 数据结构: DispatchEvent
@@ -20,14 +26,19 @@ struct DispatchBody<'a> {
 #[derive(Serialize, Deserialize)]
 struct ClientPayload<'a> {
     version: &'a str,
+    notes: Option<&'a str>,
+    url: Option<&'a str>,
 }
 
 pub struct DispatchEvent<'a> {
     pub owner: &'a str,
     pub repo: &'a str,
     pub token: &'a str,
+    pub user_agent: &'a str,
     pub event_type: &'a str,
     pub version: &'a str,
+    pub notes: Option<&'a str>,
+    pub url: Option<&'a str>,
 }
 
 impl<'a> DispatchEvent<'a> {
@@ -41,11 +52,13 @@ impl<'a> DispatchEvent<'a> {
             event_type: self.event_type,
             client_payload: ClientPayload {
                 version: self.version,
+                notes: self.notes,
+                url: self.url,
             },
         };
 
         let req = HttpRequest::new(&url, HttpMethod::Post)
-            .with_header("User-Agent", USER_AGENT)
+            .with_header("User-Agent", self.user_agent)
             .with_header("Authorization", &format!("Bearer {}", self.token))
             .with_header("Accept", "application/vnd.github+json")
             .with_header("X-GitHub-Api-Version", GITHUB_API_VERSION)
@@ -77,6 +90,8 @@ pub struct GitHubGateway<'a, C: HttpClient> {
     client: &'a C,
     global_read_token: Option<String>,
     mode: ComparisonMode,
+    notes_max_bytes: usize,
+    user_agent: String,
 }
 
 impl<'a, C: HttpClient> GitHubGateway<'a, C> {
@@ -85,17 +100,40 @@ impl<'a, C: HttpClient> GitHubGateway<'a, C> {
             client,
             global_read_token,
             mode,
+            notes_max_bytes: DEFAULT_NOTES_MAX_BYTES,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
         }
     }
 
-    pub async fn fetch_latest_release(
+    /// 设置所有 GitHub 请求 (读取与 Dispatch) 使用的 User-Agent
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// 设置 Dispatch 载荷中 notes 的最大字节数
+    pub fn with_notes_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.notes_max_bytes = max_bytes;
+        self
+    }
+
+    /// 按来源类型获取上游最新版本
+    pub async fn fetch_latest(
         &self,
         owner: &str,
         repo: &str,
+        source: SourceKind,
     ) -> WatchResult<GitHubRelease> {
-        let repo_path = format!("{}/{}", owner, repo);
-        let url = format!("https://api.github.com/repos/{}/releases/latest", repo_path);
-        let mut req = HttpRequest::new(&url, HttpMethod::Get).with_header("User-Agent", USER_AGENT);
+        match source {
+            SourceKind::Releases => self.fetch_latest_release(owner, repo).await,
+            SourceKind::Tags => self.fetch_latest_tag(owner, repo).await,
+        }
+    }
+
+    /// 发送带鉴权的 GET 请求，统一处理限流与非 200 响应
+    async fn get(&self, url: &str, repo_path: &str) -> WatchResult<HttpResponse> {
+        let mut req =
+            HttpRequest::new(url, HttpMethod::Get).with_header("User-Agent", &self.user_agent);
 
         if let Some(token) = &self.global_read_token {
             req = req.with_header("Authorization", &format!("Bearer {}", token));
@@ -105,75 +143,531 @@ impl<'a, C: HttpClient> GitHubGateway<'a, C> {
             .client
             .send(req)
             .await
-            .map_err(|e| e.in_op_with("github.fetch", &repo_path))?;
+            .map_err(|e| e.in_op_with("github.fetch", repo_path))?;
+
+        // 被限流的 403：带上重置时间，便于从 Worker 日志中定位检查停滞的原因
+        if resp.status == 403 && resp.header("X-RateLimit-Remaining") == Some("0") {
+            let reset = resp.header("X-RateLimit-Reset").unwrap_or("unknown");
+            return Err(WatchError::rate_limited(format!(
+                "GitHub API rate limit exceeded (remaining 0), resets at {} (unix)",
+                reset
+            ))
+            .in_op_with("github.fetch.ratelimited", repo_path));
+        }
+
+        // 二级限流 (secondary rate limit) 直接返回 429
+        if resp.status == 429 {
+            return Err(WatchError::rate_limited(format!(
+                "GitHub API rate limit exceeded (429), retry after {}s",
+                resp.header("Retry-After").unwrap_or("unknown")
+            ))
+            .in_op_with("github.fetch.ratelimited", repo_path));
+        }
+
         if resp.status != 200 {
             return Err(WatchError::external_api(format!(
                 "Upstream API Error {}: {}",
                 resp.status, url
             ))
-            .in_op_with("github.fetch", &repo_path));
+            .in_op_with("github.fetch", repo_path));
         }
 
-        // 手动解析 JSON
+        Ok(resp)
+    }
+
+    pub async fn fetch_latest_release(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> WatchResult<GitHubRelease> {
+        let repo_path = format!("{}/{}", owner, repo);
+        let url = format!("https://api.github.com/repos/{}/releases/latest", repo_path);
+        let resp = self.get(&url, &repo_path).await?;
+
+        let payload: ReleasePayload = resp
+            .json()
+            .map_err(|e| e.in_op_with("github.parse", &repo_path))?;
+
+        payload.into_release(self.mode, &repo_path)
+    }
+
+    /// 获取最新的 Git Tag (适用于只打 Tag 不发 Release 的仓库)
+    ///
+    /// 取 `/tags` 返回的第一个 Tag，并以其提交时间作为比较时间戳：
+    /// PublishedAt 与 CreatedAt 使用 author 日期，UpdatedAt 使用 committer 日期
+    pub async fn fetch_latest_tag(&self, owner: &str, repo: &str) -> WatchResult<GitHubRelease> {
+        let repo_path = format!("{}/{}", owner, repo);
+
+        #[derive(Deserialize)]
+        struct TagCommit {
+            sha: String,
+        }
         #[derive(Deserialize)]
-        struct ReleaseResponse {
-            tag_name: String,
-            published_at: Option<String>,
-            updated_at: Option<String>,
+        struct TagResponse {
+            name: String,
+            commit: TagCommit,
         }
 
-        let root: ReleaseResponse = resp
+        let url = format!("https://api.github.com/repos/{}/tags?per_page=1", repo_path);
+        let tags: Vec<TagResponse> = self
+            .get(&url, &repo_path)
+            .await?
             .json()
-            .map_err(|e| e.in_op_with("github.parse", &repo_path))?;
+            .map_err(|e| e.in_op_with("github.parse.tags", &repo_path))?;
+        let tag = tags.into_iter().next().ok_or_else(|| {
+            WatchError::not_found("Upstream repository has no tags")
+                .in_op_with("github.fetch.tags", &repo_path)
+        })?;
 
-        // 1. 获取 tag_name
-        let tag_name = root.tag_name;
-
-        // 2. 根据 mode 获取对应时间字段，如果字段不存在则报错
-        let timestamp = match self.mode {
-            ComparisonMode::PublishedAt => {
-                let s = root.published_at.ok_or_else(|| {
-                    WatchError::external_api("Missing 'published_at' field required by config")
-                        .in_op_with("github.parse.published_at", &repo_path)
-                })?;
-                let t = Date::parse_timestamp(&s).ok_or_else(|| {
-                    WatchError::external_api("Invalid time format for 'published_at'")
-                        .in_op_with("github.parse.time", &repo_path)
-                })?;
-                ReleaseTimestamp::Published(t)
-            }
-            ComparisonMode::UpdatedAt => {
-                let s = root.updated_at.ok_or_else(|| {
-                    WatchError::external_api("Missing 'updated_at' field required by config")
-                        .in_op_with("github.parse.updated_at", &repo_path)
-                })?;
-                let t = Date::parse_timestamp(&s).ok_or_else(|| {
-                    WatchError::external_api("Invalid time format for 'updated_at'")
-                        .in_op_with("github.parse.time", &repo_path)
-                })?;
-                ReleaseTimestamp::Updated(t)
-            }
-        };
+        #[derive(Deserialize)]
+        struct Signature {
+            date: Option<String>,
+        }
+        #[derive(Deserialize)]
+        struct CommitDetail {
+            author: Option<Signature>,
+            committer: Option<Signature>,
+        }
+        #[derive(Deserialize)]
+        struct CommitResponse {
+            commit: CommitDetail,
+        }
+
+        let url = format!(
+            "https://api.github.com/repos/{}/commits/{}",
+            repo_path, tag.commit.sha
+        );
+        let commit: CommitResponse = self
+            .get(&url, &repo_path)
+            .await?
+            .json()
+            .map_err(|e| e.in_op_with("github.parse.commit", &repo_path))?;
+
+        let author_date = commit.commit.author.and_then(|s| s.date);
+        let timestamp = ReleaseTimestamp::from_fields(
+            self.mode,
+            author_date.clone(),
+            commit.commit.committer.and_then(|s| s.date),
+            author_date,
+            &repo_path,
+        )?;
 
         Ok(GitHubRelease {
-            tag_name,
+            html_url: Some(format!(
+                "https://github.com/{}/releases/tag/{}",
+                repo_path, tag.name
+            )),
+            tag_name: tag.name,
             timestamp,
+            body: None,
         })
     }
 
     pub async fn trigger_dispatch(
         &self,
         config: &ProjectConfig,
-        version: &str,
+        release: &GitHubRelease,
         token: &str,
     ) -> WatchResult<()> {
+        let notes = release
+            .body
+            .as_deref()
+            .map(|body| truncate_notes(body, self.notes_max_bytes));
+
+        // 只影响载荷，版本记录与比较仍使用原始 tag_name
+        let version = if config.request.strip_tag_prefix {
+            release.version_without_prefix()
+        } else {
+            &release.tag_name
+        };
+
         let event = DispatchEvent {
             owner: &config.request.base_config.my_owner,
             repo: &config.request.base_config.my_repo,
             token,
+            user_agent: &self.user_agent,
             event_type: "upstream_update",
             version,
+            notes: notes.as_deref(),
+            url: release.html_url.as_deref(),
         };
         event.send(self.client).await
     }
 }
+
+#[async_trait::async_trait(?Send)]
+impl<'a, C: HttpClient> ReleaseProvider for GitHubGateway<'a, C> {
+    async fn fetch_latest_release(&self, owner: &str, repo: &str) -> WatchResult<GitHubRelease> {
+        GitHubGateway::fetch_latest_release(self, owner, repo).await
+    }
+
+    async fn fetch_latest(
+        &self,
+        owner: &str,
+        repo: &str,
+        source: SourceKind,
+    ) -> WatchResult<GitHubRelease> {
+        GitHubGateway::fetch_latest(self, owner, repo, source).await
+    }
+}
+
+/// 将 notes 截断到 `max_bytes` 以内 (按字符边界)，截断时追加省略号
+fn truncate_notes(notes: &str, max_bytes: usize) -> Cow<'_, str> {
+    if notes.len() <= max_bytes {
+        return Cow::Borrowed(notes);
+    }
+
+    let mut end = max_bytes.saturating_sub(NOTES_ELLIPSIS.len());
+    while !notes.is_char_boundary(end) {
+        end -= 1;
+    }
+    Cow::Owned(format!("{}{}", &notes[..end], NOTES_ELLIPSIS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::request::MockHttpClient;
+    use verwatch_shared::Date;
+
+    const LATEST_URL: &str = "https://api.github.com/repos/owner/repo/releases/latest";
+    const DISPATCH_URL: &str = "https://api.github.com/repos/me/fork/dispatches";
+
+    fn dispatch_config() -> ProjectConfig {
+        ProjectConfig::new(verwatch_shared::CreateProjectRequest {
+            base_config: verwatch_shared::BaseConfig {
+                upstream_owner: "owner".to_string(),
+                upstream_repo: "repo".to_string(),
+                my_owner: "me".to_string(),
+                my_repo: "fork".to_string(),
+            },
+            time_config: verwatch_shared::TimeConfig::default(),
+            initial_delay: verwatch_shared::DurationSecs::from_secs(0),
+            dispatch_token_secret: None,
+            comparison_mode: ComparisonMode::PublishedAt,
+            ..Default::default()
+        })
+    }
+
+    fn release_with_body(body: &str) -> GitHubRelease {
+        GitHubRelease {
+            tag_name: "v1.2.3".to_string(),
+            timestamp: ReleaseTimestamp::Published(verwatch_shared::Timestamp::new(0)),
+            html_url: Some("https://github.com/owner/repo/releases/tag/v1.2.3".to_string()),
+            body: Some(body.to_string()),
+        }
+    }
+
+    fn sent_payload(client: &MockHttpClient) -> serde_json::Value {
+        let requests = client.requests.borrow();
+        let (_, _, _, body) = requests
+            .iter()
+            .find(|(url, _, _, _)| url == DISPATCH_URL)
+            .expect("dispatch request not sent");
+        let body: serde_json::Value = serde_json::from_str(body.as_deref().unwrap()).unwrap();
+        body["client_payload"].clone()
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_payload_includes_notes_and_url() {
+        let client = MockHttpClient::new();
+        client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+        let gateway = GitHubGateway::new(&client, None, ComparisonMode::PublishedAt);
+
+        gateway
+            .trigger_dispatch(&dispatch_config(), &release_with_body("## Changes"), "pat")
+            .await
+            .unwrap();
+
+        let payload = sent_payload(&client);
+        assert_eq!(payload["version"], "v1.2.3");
+        assert_eq!(payload["notes"], "## Changes");
+        assert_eq!(
+            payload["url"],
+            "https://github.com/owner/repo/releases/tag/v1.2.3"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_payload_strips_tag_prefix_when_enabled() {
+        for (strip, expected) in [(true, "1.2.3"), (false, "v1.2.3")] {
+            let client = MockHttpClient::new();
+            client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+            let gateway = GitHubGateway::new(&client, None, ComparisonMode::PublishedAt);
+            let mut config = dispatch_config();
+            config.request.strip_tag_prefix = strip;
+
+            gateway
+                .trigger_dispatch(&config, &release_with_body("notes"), "pat")
+                .await
+                .unwrap();
+
+            let payload = sent_payload(&client);
+            assert_eq!(payload["version"], expected);
+            // Release 链接仍指向原始 Tag
+            assert_eq!(
+                payload["url"],
+                "https://github.com/owner/repo/releases/tag/v1.2.3"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_payload_truncates_long_notes() {
+        let client = MockHttpClient::new();
+        client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+        let gateway =
+            GitHubGateway::new(&client, None, ComparisonMode::PublishedAt).with_notes_max_bytes(16);
+
+        gateway
+            .trigger_dispatch(
+                &dispatch_config(),
+                &release_with_body(&"变更".repeat(20)),
+                "pat",
+            )
+            .await
+            .unwrap();
+
+        let payload = sent_payload(&client);
+        let notes = payload["notes"].as_str().unwrap();
+        assert!(notes.len() <= 16);
+        assert!(notes.ends_with('…'));
+        assert!(notes.starts_with("变更"));
+        assert_eq!(
+            payload["url"],
+            "https://github.com/owner/repo/releases/tag/v1.2.3"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_requests_carry_configured_user_agent() {
+        let client = MockHttpClient::new();
+        client.mock_response(
+            LATEST_URL,
+            200,
+            serde_json::json!({
+                "tag_name": "v1.0.0",
+                "published_at": "2024-01-15T10:30:00Z"
+            }),
+        );
+        client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+        let gateway = GitHubGateway::new(&client, None, ComparisonMode::PublishedAt)
+            .with_user_agent("verwatch (ops@example.com)");
+
+        gateway.fetch_latest_release("owner", "repo").await.unwrap();
+        gateway
+            .trigger_dispatch(&dispatch_config(), &release_with_body("notes"), "pat")
+            .await
+            .unwrap();
+
+        let requests = client.requests.borrow();
+        assert_eq!(requests.len(), 2);
+        for (url, _, headers, _) in requests.iter() {
+            assert_eq!(
+                headers.get("User-Agent").map(String::as_str),
+                Some("verwatch (ops@example.com)"),
+                "unexpected User-Agent for {}",
+                url
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_user_agent() {
+        let client = MockHttpClient::new();
+        client.mock_response(DISPATCH_URL, 204, serde_json::json!(null));
+        let gateway = GitHubGateway::new(&client, None, ComparisonMode::PublishedAt);
+
+        gateway
+            .trigger_dispatch(&dispatch_config(), &release_with_body("notes"), "pat")
+            .await
+            .unwrap();
+
+        let requests = client.requests.borrow();
+        assert_eq!(
+            requests[0].2.get("User-Agent").map(String::as_str),
+            Some(DEFAULT_USER_AGENT)
+        );
+    }
+
+    #[test]
+    fn test_truncate_notes_keeps_short_text() {
+        assert_eq!(truncate_notes("short", 8), "short");
+        assert_eq!(truncate_notes("exactly8", 8), "exactly8");
+        assert_eq!(truncate_notes("longer text", 8), "longe…");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_rate_limited_reports_reset_time() {
+        let client = MockHttpClient::new();
+        client.mock_response_with_headers(
+            LATEST_URL,
+            403,
+            serde_json::json!({ "message": "API rate limit exceeded" }),
+            &[
+                ("X-RateLimit-Remaining", "0"),
+                ("X-RateLimit-Reset", "1700000000"),
+            ],
+        );
+        let gateway = GitHubGateway::new(&client, None, ComparisonMode::PublishedAt);
+
+        let err = gateway
+            .fetch_latest_release("owner", "repo")
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.status, crate::error::WatchErrorStatus::RateLimited);
+        assert!(err.message().contains("1700000000"));
+        assert!(
+            err.spans()
+                .iter()
+                .any(|s| s.operation == "github.fetch.ratelimited")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_429_is_rate_limited() {
+        let client = MockHttpClient::new();
+        client.mock_response_with_headers(
+            LATEST_URL,
+            429,
+            serde_json::json!({ "message": "secondary rate limit" }),
+            &[("Retry-After", "60")],
+        );
+        let gateway = GitHubGateway::new(&client, None, ComparisonMode::PublishedAt);
+
+        let err = gateway
+            .fetch_latest_release("owner", "repo")
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.status, crate::error::WatchErrorStatus::RateLimited);
+        assert_eq!(err.status_code(), 429);
+        assert!(err.message().contains("60"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_plain_403_is_generic_error() {
+        let client = MockHttpClient::new();
+        client.mock_response(
+            LATEST_URL,
+            403,
+            serde_json::json!({ "message": "Forbidden" }),
+        );
+        let gateway = GitHubGateway::new(&client, None, ComparisonMode::PublishedAt);
+
+        let err = gateway
+            .fetch_latest_release("owner", "repo")
+            .await
+            .unwrap_err();
+
+        assert!(err.message().contains("Upstream API Error 403"));
+    }
+
+    const TAGS_URL: &str = "https://api.github.com/repos/owner/repo/tags?per_page=1";
+    const COMMIT_URL: &str = "https://api.github.com/repos/owner/repo/commits/abc123";
+
+    fn mock_tag_and_commit(client: &MockHttpClient) {
+        client.mock_response(
+            TAGS_URL,
+            200,
+            serde_json::json!([
+                { "name": "v2.0.0", "commit": { "sha": "abc123", "url": "" } }
+            ]),
+        );
+        client.mock_response(
+            COMMIT_URL,
+            200,
+            serde_json::json!({
+                "sha": "abc123",
+                "commit": {
+                    "author": { "date": "2024-01-15T10:30:00Z" },
+                    "committer": { "date": "2024-01-16T08:00:00Z" }
+                }
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_latest_tag_uses_commit_date() {
+        let client = MockHttpClient::new();
+        mock_tag_and_commit(&client);
+        let gateway = GitHubGateway::new(&client, None, ComparisonMode::PublishedAt);
+
+        let release = gateway
+            .fetch_latest("owner", "repo", SourceKind::Tags)
+            .await
+            .unwrap();
+
+        assert_eq!(release.tag_name, "v2.0.0");
+        assert_eq!(
+            release.timestamp,
+            ReleaseTimestamp::Published(Date::parse_timestamp("2024-01-15T10:30:00Z").unwrap())
+        );
+        assert_eq!(
+            release.html_url.as_deref(),
+            Some("https://github.com/owner/repo/releases/tag/v2.0.0")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_latest_tag_updated_mode_uses_committer_date() {
+        let client = MockHttpClient::new();
+        mock_tag_and_commit(&client);
+        let gateway = GitHubGateway::new(&client, None, ComparisonMode::UpdatedAt);
+
+        let release = gateway.fetch_latest_tag("owner", "repo").await.unwrap();
+
+        assert_eq!(
+            release.timestamp,
+            ReleaseTimestamp::Updated(Date::parse_timestamp("2024-01-16T08:00:00Z").unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_latest_tag_created_mode_uses_author_date() {
+        let client = MockHttpClient::new();
+        mock_tag_and_commit(&client);
+        let gateway = GitHubGateway::new(&client, None, ComparisonMode::CreatedAt);
+
+        let release = gateway.fetch_latest_tag("owner", "repo").await.unwrap();
+
+        assert_eq!(
+            release.timestamp,
+            ReleaseTimestamp::Created(Date::parse_timestamp("2024-01-15T10:30:00Z").unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_latest_tag_empty_is_not_found() {
+        let client = MockHttpClient::new();
+        client.mock_response(TAGS_URL, 200, serde_json::json!([]));
+        let gateway = GitHubGateway::new(&client, None, ComparisonMode::PublishedAt);
+
+        let err = gateway.fetch_latest_tag("owner", "repo").await.unwrap_err();
+        assert_eq!(err.status, crate::error::WatchErrorStatus::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_latest_defaults_to_releases() {
+        let client = MockHttpClient::new();
+        client.mock_response(
+            LATEST_URL,
+            200,
+            serde_json::json!({
+                "tag_name": "v1.0.0",
+                "published_at": "2024-01-15T10:30:00Z",
+                "html_url": "https://github.com/owner/repo/releases/tag/v1.0.0",
+                "body": "notes"
+            }),
+        );
+        let gateway = GitHubGateway::new(&client, None, ComparisonMode::PublishedAt);
+
+        let release = gateway
+            .fetch_latest("owner", "repo", SourceKind::default())
+            .await
+            .unwrap();
+        assert_eq!(release.tag_name, "v1.0.0");
+        assert_eq!(release.body.as_deref(), Some("notes"));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::error::{WatchError, WatchResult};
 use serde::{Deserialize, Serialize};
-use verwatch_shared::Timestamp;
+use verwatch_shared::{ComparisonMode, Date, Timestamp};
 
 // =========================================================
 // 1. Enum & Struct
@@ -10,12 +10,84 @@ use verwatch_shared::Timestamp;
 pub enum ReleaseTimestamp {
     Published(Timestamp),
     Updated(Timestamp),
+    Created(Timestamp),
+}
+
+impl ReleaseTimestamp {
+    /// 按比较模式选取对应的 RFC 3339 时间字段并解析
+    ///
+    /// `published`/`updated`/`created` 分别对应 PublishedAt/UpdatedAt/CreatedAt 模式所需的原始字段，
+    /// 所需字段缺失或格式错误时返回 ExternalApi 错误
+    pub fn from_fields(
+        mode: ComparisonMode,
+        published: Option<String>,
+        updated: Option<String>,
+        created: Option<String>,
+        repo_path: &str,
+    ) -> WatchResult<Self> {
+        let (field, value) = match mode {
+            ComparisonMode::PublishedAt => ("published_at", published),
+            ComparisonMode::UpdatedAt => ("updated_at", updated),
+            ComparisonMode::CreatedAt => ("created_at", created),
+        };
+        let s = value.ok_or_else(|| {
+            WatchError::external_api(format!("Missing '{}' field required by config", field))
+                .in_op_with(format!("release.parse.{}", field), repo_path)
+        })?;
+        let t = Date::parse_timestamp(&s).ok_or_else(|| {
+            WatchError::external_api(format!("Invalid time format for '{}'", field))
+                .in_op_with("release.parse.time", repo_path)
+        })?;
+        Ok(match mode {
+            ComparisonMode::PublishedAt => ReleaseTimestamp::Published(t),
+            ComparisonMode::UpdatedAt => ReleaseTimestamp::Updated(t),
+            ComparisonMode::CreatedAt => ReleaseTimestamp::Created(t),
+        })
+    }
+}
+
+/// GitHub 兼容的 Release JSON (GitHub / Gitea / Forgejo 共用)
+///
+/// Gitea 不提供 `updated_at`，UpdatedAt 模式下回退到 `created_at`
+#[derive(Deserialize)]
+pub(crate) struct ReleasePayload {
+    tag_name: String,
+    published_at: Option<String>,
+    updated_at: Option<String>,
+    created_at: Option<String>,
+    html_url: Option<String>,
+    body: Option<String>,
+}
+
+impl ReleasePayload {
+    /// 按比较模式转换为 GitHubRelease
+    pub fn into_release(self, mode: ComparisonMode, repo_path: &str) -> WatchResult<GitHubRelease> {
+        let timestamp = ReleaseTimestamp::from_fields(
+            mode,
+            self.published_at,
+            self.updated_at.or_else(|| self.created_at.clone()),
+            self.created_at,
+            repo_path,
+        )?;
+        Ok(GitHubRelease {
+            tag_name: self.tag_name,
+            timestamp,
+            html_url: self.html_url,
+            body: self.body,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GitHubRelease {
     pub tag_name: String,
     pub timestamp: ReleaseTimestamp,
+    /// Release 页面地址
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    /// Release 说明正文，仅用于 Dispatch 载荷，不持久化 (避免超出 DO 单值大小限制)
+    #[serde(skip)]
+    pub body: Option<String>,
 }
 
 impl GitHubRelease {
@@ -33,6 +105,9 @@ impl GitHubRelease {
             (ReleaseTimestamp::Updated(t_new), ReleaseTimestamp::Updated(t_old)) => {
                 Ok(t_new > t_old)
             }
+            (ReleaseTimestamp::Created(t_new), ReleaseTimestamp::Created(t_old)) => {
+                Ok(t_new > t_old)
+            }
             // 类型不匹配，视为逻辑错误（可能是配置被修改了，或者数据脏了）
             _ => Err(WatchError::invalid_input(format!(
                 "Comparison mode mismatch: New is {:?}, but Current is {:?}",
@@ -41,4 +116,96 @@ impl GitHubRelease {
             .in_op("release.compare")),
         }
     }
+
+    /// 去掉开头的 `v`/`V` 后的 tag_name
+    pub fn version_without_prefix(&self) -> &str {
+        self.tag_name
+            .strip_prefix(['v', 'V'])
+            .unwrap_or(&self.tag_name)
+    }
+
+    /// 将 tag_name 按 semver 解析 (忽略开头的 `v`/`V`)，无法解析时返回 None
+    pub fn semver(&self) -> Option<semver::Version> {
+        semver::Version::parse(self.version_without_prefix()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload() -> ReleasePayload {
+        serde_json::from_value(serde_json::json!({
+            "tag_name": "v1.0.0",
+            "created_at": "2024-01-10T00:00:00Z",
+            "published_at": "2024-01-15T10:30:00Z",
+            "updated_at": "2024-02-01T00:00:00Z"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_created_mode_parses_created_at() {
+        let release = payload()
+            .into_release(ComparisonMode::CreatedAt, "owner/repo")
+            .unwrap();
+        assert_eq!(
+            release.timestamp,
+            ReleaseTimestamp::Created(Date::parse_timestamp("2024-01-10T00:00:00Z").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_created_mode_missing_field_is_error() {
+        let payload: ReleasePayload = serde_json::from_value(serde_json::json!({
+            "tag_name": "v1.0.0",
+            "published_at": "2024-01-15T10:30:00Z"
+        }))
+        .unwrap();
+        let err = payload
+            .into_release(ComparisonMode::CreatedAt, "owner/repo")
+            .unwrap_err();
+        assert!(err.to_string().contains("created_at"));
+    }
+
+    #[test]
+    fn test_is_newer_than_created() {
+        let old = payload()
+            .into_release(ComparisonMode::CreatedAt, "owner/repo")
+            .unwrap();
+        let mut new = old.clone();
+        new.timestamp =
+            ReleaseTimestamp::Created(Date::parse_timestamp("2024-03-01T00:00:00Z").unwrap());
+        assert!(new.is_newer_than(&old).unwrap());
+        assert!(!old.is_newer_than(&new).unwrap());
+    }
+
+    #[test]
+    fn test_semver_strips_v_prefix() {
+        let mut release = payload()
+            .into_release(ComparisonMode::PublishedAt, "owner/repo")
+            .unwrap();
+        assert_eq!(release.semver(), Some(semver::Version::new(1, 0, 0)));
+
+        release.tag_name = "2.3.4-rc.1".into();
+        assert_eq!(
+            release.semver(),
+            Some(semver::Version::parse("2.3.4-rc.1").unwrap())
+        );
+
+        release.tag_name = "nightly-20240115".into();
+        assert_eq!(release.semver(), None);
+    }
+
+    #[test]
+    fn test_is_newer_than_mode_mismatch_is_error() {
+        // 切换比较模式后，已存储的旧模式版本无法比较，由检查流程视为新版本
+        let published = payload()
+            .into_release(ComparisonMode::PublishedAt, "owner/repo")
+            .unwrap();
+        let created = payload()
+            .into_release(ComparisonMode::CreatedAt, "owner/repo")
+            .unwrap();
+        assert!(created.is_newer_than(&published).is_err());
+    }
 }
@@ -0,0 +1,260 @@
+use crate::error::{WatchError, WatchResult};
+use crate::utils::github::gateway::DEFAULT_USER_AGENT;
+use crate::utils::github::release::{GitHubRelease, ReleaseTimestamp};
+use crate::utils::provider::ReleaseProvider;
+use crate::utils::request::{HttpClient, HttpMethod, HttpRequest};
+use serde::Deserialize;
+use verwatch_shared::ComparisonMode;
+
+const GITLAB_API_BASE: &str = "https://gitlab.com/api/v4";
+
+/// GitLab Releases 网关
+///
+/// 时间字段映射：PublishedAt 使用 `released_at`，UpdatedAt 使用 `created_at`
+pub struct GitLabGateway<'a, C: HttpClient> {
+    client: &'a C,
+    token: Option<String>,
+    mode: ComparisonMode,
+    user_agent: String,
+}
+
+impl<'a, C: HttpClient> GitLabGateway<'a, C> {
+    pub fn new(client: &'a C, token: Option<String>, mode: ComparisonMode) -> Self {
+        Self {
+            client,
+            token,
+            mode,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+        }
+    }
+
+    /// 设置请求使用的 User-Agent
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// GitLab 以 URL 编码的完整路径作为项目 ID (支持子群组，如 `group/sub/repo`)
+    fn project_id(owner: &str, repo: &str) -> String {
+        format!("{}/{}", owner, repo).replace('/', "%2F")
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<'a, C: HttpClient> ReleaseProvider for GitLabGateway<'a, C> {
+    async fn fetch_latest_release(&self, owner: &str, repo: &str) -> WatchResult<GitHubRelease> {
+        let repo_path = format!("{}/{}", owner, repo);
+        // GitLab 默认按 released_at 倒序返回
+        let url = format!(
+            "{}/projects/{}/releases?per_page=1",
+            GITLAB_API_BASE,
+            Self::project_id(owner, repo)
+        );
+
+        let mut req =
+            HttpRequest::new(&url, HttpMethod::Get).with_header("User-Agent", &self.user_agent);
+        if let Some(token) = &self.token {
+            req = req.with_header("PRIVATE-TOKEN", token);
+        }
+
+        let resp = self
+            .client
+            .send(req)
+            .await
+            .map_err(|e| e.in_op_with("gitlab.fetch", &repo_path))?;
+
+        if resp.status != 200 {
+            return Err(WatchError::external_api(format!(
+                "Upstream API Error {}: {}",
+                resp.status, url
+            ))
+            .in_op_with("gitlab.fetch", &repo_path));
+        }
+
+        #[derive(Deserialize)]
+        struct ReleaseLinks {
+            #[serde(rename = "self")]
+            self_url: Option<String>,
+        }
+        #[derive(Deserialize)]
+        struct ReleaseResponse {
+            tag_name: String,
+            released_at: Option<String>,
+            created_at: Option<String>,
+            description: Option<String>,
+            #[serde(rename = "_links")]
+            links: Option<ReleaseLinks>,
+        }
+
+        let releases: Vec<ReleaseResponse> = resp
+            .json()
+            .map_err(|e| e.in_op_with("gitlab.parse", &repo_path))?;
+        let release = releases.into_iter().next().ok_or_else(|| {
+            WatchError::not_found("Upstream project has no releases")
+                .in_op_with("gitlab.fetch", &repo_path)
+        })?;
+
+        let timestamp = ReleaseTimestamp::from_fields(
+            self.mode,
+            release.released_at,
+            release.created_at.clone(),
+            release.created_at,
+            &repo_path,
+        )?;
+
+        Ok(GitHubRelease {
+            tag_name: release.tag_name,
+            timestamp,
+            html_url: release.links.and_then(|l| l.self_url),
+            body: release.description,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::WatchErrorStatus;
+    use crate::utils::request::MockHttpClient;
+    use verwatch_shared::{Date, SourceKind};
+
+    const RELEASES_URL: &str =
+        "https://gitlab.com/api/v4/projects/group%2Fproject/releases?per_page=1";
+
+    fn mock_releases(client: &MockHttpClient) {
+        client.mock_response(
+            RELEASES_URL,
+            200,
+            serde_json::json!([{
+                "name": "Release 1.2.0",
+                "tag_name": "v1.2.0",
+                "description": "Changelog",
+                "created_at": "2024-02-01T08:00:00.000Z",
+                "released_at": "2024-01-31T12:00:00.000Z",
+                "_links": {
+                    "self": "https://gitlab.com/group/project/-/releases/v1.2.0"
+                }
+            }]),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_latest_release_parses_gitlab_shape() {
+        let client = MockHttpClient::new();
+        mock_releases(&client);
+        let gateway = GitLabGateway::new(&client, None, ComparisonMode::PublishedAt);
+
+        let release = gateway
+            .fetch_latest_release("group", "project")
+            .await
+            .unwrap();
+
+        assert_eq!(release.tag_name, "v1.2.0");
+        assert_eq!(
+            release.timestamp,
+            ReleaseTimestamp::Published(Date::parse_timestamp("2024-01-31T12:00:00Z").unwrap())
+        );
+        assert_eq!(
+            release.html_url.as_deref(),
+            Some("https://gitlab.com/group/project/-/releases/v1.2.0")
+        );
+        assert_eq!(release.body.as_deref(), Some("Changelog"));
+    }
+
+    #[tokio::test]
+    async fn test_updated_mode_uses_created_at() {
+        let client = MockHttpClient::new();
+        mock_releases(&client);
+        let gateway = GitLabGateway::new(&client, None, ComparisonMode::UpdatedAt);
+
+        let release = gateway
+            .fetch_latest_release("group", "project")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            release.timestamp,
+            ReleaseTimestamp::Updated(Date::parse_timestamp("2024-02-01T08:00:00Z").unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_token_is_sent_as_private_token() {
+        let client = MockHttpClient::new();
+        mock_releases(&client);
+        let gateway =
+            GitLabGateway::new(&client, Some("glpat".into()), ComparisonMode::PublishedAt);
+
+        gateway
+            .fetch_latest_release("group", "project")
+            .await
+            .unwrap();
+
+        let requests = client.requests.borrow();
+        let (_, _, headers, _) = &requests[0];
+        assert_eq!(
+            headers.get("PRIVATE-TOKEN").map(String::as_str),
+            Some("glpat")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_requests_carry_configured_user_agent() {
+        let client = MockHttpClient::new();
+        mock_releases(&client);
+        let gateway = GitLabGateway::new(&client, None, ComparisonMode::PublishedAt)
+            .with_user_agent("verwatch (ops@example.com)");
+
+        gateway
+            .fetch_latest_release("group", "project")
+            .await
+            .unwrap();
+
+        let requests = client.requests.borrow();
+        let (_, _, headers, _) = &requests[0];
+        assert_eq!(
+            headers.get("User-Agent").map(String::as_str),
+            Some("verwatch (ops@example.com)")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subgroup_path_is_encoded() {
+        let client = MockHttpClient::new();
+        let gateway = GitLabGateway::new(&client, None, ComparisonMode::PublishedAt);
+
+        let _ = gateway.fetch_latest_release("group/sub", "project").await;
+
+        let requests = client.requests.borrow();
+        assert_eq!(
+            requests[0].0,
+            "https://gitlab.com/api/v4/projects/group%2Fsub%2Fproject/releases?per_page=1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_empty_release_list_is_not_found() {
+        let client = MockHttpClient::new();
+        client.mock_response(RELEASES_URL, 200, serde_json::json!([]));
+        let gateway = GitLabGateway::new(&client, None, ComparisonMode::PublishedAt);
+
+        let err = gateway
+            .fetch_latest_release("group", "project")
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_tags_source_is_unsupported() {
+        let client = MockHttpClient::new();
+        let gateway = GitLabGateway::new(&client, None, ComparisonMode::PublishedAt);
+
+        let err = gateway
+            .fetch_latest("group", "project", SourceKind::Tags)
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::InvalidInput);
+        assert!(client.requests.borrow().is_empty());
+    }
+}
@@ -0,0 +1,84 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// =========================================================
+// 日志级别
+// =========================================================
+
+/// 日志级别，由 `LOG_LEVEL` 变量控制 (`error` / `warn` / `info`)
+///
+/// 级别越低越重要：设置为 `warn` 时只输出 `warn` 与 `error`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum LogLevel {
+    Error = 0,
+    Warn = 1,
+    Info = 2,
+}
+
+/// 未设置或无法识别 `LOG_LEVEL` 时的级别
+pub const DEFAULT_LOG_LEVEL: LogLevel = LogLevel::Info;
+
+impl LogLevel {
+    /// 解析 `LOG_LEVEL` 的值 (不区分大小写)，未设置或无法识别时使用默认级别
+    pub fn from_var(value: Option<&str>) -> Self {
+        match value.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+            Some("error") => LogLevel::Error,
+            Some("warn" | "warning") => LogLevel::Warn,
+            Some("info") => LogLevel::Info,
+            _ => DEFAULT_LOG_LEVEL,
+        }
+    }
+
+    /// 在当前级别下是否输出 `level` 级别的日志
+    pub fn allows(self, level: LogLevel) -> bool {
+        level <= self
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => LogLevel::Error,
+            1 => LogLevel::Warn,
+            _ => LogLevel::Info,
+        }
+    }
+}
+
+/// 当前生效的级别
+///
+/// 日志宏无法访问请求上下文，因此在每次请求与 Alarm 入口处设置；
+/// Worker 的 isolate 为单线程，不存在并发请求互相覆盖的问题
+static CURRENT_LEVEL: AtomicU8 = AtomicU8::new(DEFAULT_LOG_LEVEL as u8);
+
+pub fn set_level(level: LogLevel) {
+    CURRENT_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// 当前级别下是否输出 `level` 级别的日志，供日志宏调用
+pub fn enabled(level: LogLevel) -> bool {
+    LogLevel::from_u8(CURRENT_LEVEL.load(Ordering::Relaxed)).allows(level)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_var() {
+        assert_eq!(LogLevel::from_var(Some("WARN")), LogLevel::Warn);
+        assert_eq!(LogLevel::from_var(Some(" error ")), LogLevel::Error);
+        assert_eq!(LogLevel::from_var(Some("debug")), DEFAULT_LOG_LEVEL);
+        assert_eq!(LogLevel::from_var(None), DEFAULT_LOG_LEVEL);
+    }
+
+    #[test]
+    fn test_level_filtering() {
+        assert!(LogLevel::Warn.allows(LogLevel::Error));
+        assert!(LogLevel::Warn.allows(LogLevel::Warn));
+        assert!(!LogLevel::Warn.allows(LogLevel::Info));
+
+        assert!(LogLevel::Error.allows(LogLevel::Error));
+        assert!(!LogLevel::Error.allows(LogLevel::Warn));
+
+        assert!(LogLevel::Info.allows(LogLevel::Info));
+    }
+}
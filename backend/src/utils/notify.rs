@@ -0,0 +1,807 @@
+use crate::error::{WatchError, WatchResult};
+use crate::utils::budget::SubrequestBudget;
+use crate::utils::github::release::GitHubRelease;
+use crate::utils::request::{HttpClient, HttpMethod, HttpRequest};
+use serde::Serialize;
+use verwatch_shared::{CreateProjectRequest, TelegramConfig, WebhookConfig};
+
+// =========================================================
+// 通知分发
+// =========================================================
+
+/// 一次成功 Dispatch 后发送的通知内容
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotifyEvent<'a> {
+    /// 上游仓库 `owner/repo`
+    pub repo: String,
+    /// 接收 Dispatch 的仓库 `owner/repo`
+    pub target: String,
+    pub version: &'a str,
+    pub old_version: Option<&'a str>,
+    /// Release 页面地址 (Tag 来源没有)
+    pub release_url: Option<&'a str>,
+}
+
+impl<'a> NotifyEvent<'a> {
+    pub fn new(
+        request: &CreateProjectRequest,
+        release: &'a GitHubRelease,
+        old_version: Option<&'a str>,
+    ) -> Self {
+        let base = &request.base_config;
+        Self {
+            repo: format!("{}/{}", base.upstream_owner, base.upstream_repo),
+            target: format!("{}/{}", base.my_owner, base.my_repo),
+            version: &release.tag_name,
+            old_version,
+            release_url: release.html_url.as_deref(),
+        }
+    }
+
+    /// 纯文本消息，供聊天类通知使用
+    pub fn message(&self) -> String {
+        format!(
+            "🚀 {} released {}, dispatched to {}",
+            self.repo, self.version, self.target
+        )
+    }
+
+    fn template_vars(&self) -> TemplateVars<'_> {
+        TemplateVars {
+            repo: &self.repo,
+            version: self.version,
+            old_version: self.old_version.unwrap_or(""),
+        }
+    }
+}
+
+/// 通知渠道
+#[async_trait::async_trait(?Send)]
+pub trait Notifier {
+    /// 渠道名称，用于日志与错误追踪
+    fn name(&self) -> &'static str;
+
+    async fn notify(&self, event: &NotifyEvent<'_>) -> WatchResult<()>;
+}
+
+/// [`CompositeNotifier::notify_all`] 的结果
+#[derive(Debug, Default)]
+pub struct NotifyReport {
+    /// 发送失败的渠道名称与错误
+    pub failures: Vec<(&'static str, WatchError)>,
+    /// 因子请求预算不足而跳过的渠道名称
+    pub skipped: Vec<&'static str>,
+}
+
+/// 组合多个通知渠道：依次发送，单个渠道失败不影响其他渠道
+#[derive(Default)]
+pub struct CompositeNotifier<'a> {
+    sinks: Vec<Box<dyn Notifier + 'a>>,
+}
+
+impl<'a> CompositeNotifier<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 按项目配置构建：依次加入已配置的 Discord、Slack、Telegram 与通用 Webhook
+    ///
+    /// `secret` 按名称读取 Secret，用于解析 Telegram Bot Token
+    pub fn from_request<C: HttpClient>(
+        client: &'a C,
+        request: &'a CreateProjectRequest,
+        secret: impl Fn(&str) -> Option<String>,
+    ) -> Self {
+        let mut notifier = Self::new();
+        if let Some(url) = &request.notify_webhook {
+            notifier.push(DiscordNotifier::new(client, url));
+        }
+        if let Some(url) = &request.slack_webhook {
+            notifier.push(SlackNotifier::new(client, url));
+        }
+        if let Some(config) = &request.telegram {
+            notifier.push(TelegramNotifier::new(
+                client,
+                config,
+                secret(&config.bot_token_secret),
+            ));
+        }
+        if let Some(config) = &request.webhook {
+            notifier.push(WebhookNotifier::new(client, config));
+        }
+        notifier
+    }
+
+    pub fn push(&mut self, sink: impl Notifier + 'a) {
+        self.sinks.push(Box::new(sink));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sinks.is_empty()
+    }
+
+    /// 依次向各渠道发送，每个渠道占用一个子请求；预算耗尽后跳过其余渠道
+    pub async fn notify_all(
+        &self,
+        event: &NotifyEvent<'_>,
+        budget: &mut SubrequestBudget,
+    ) -> NotifyReport {
+        let mut report = NotifyReport::default();
+        for sink in &self.sinks {
+            if !budget.try_spend(1) {
+                report.skipped.push(sink.name());
+                continue;
+            }
+            if let Err(e) = sink.notify(event).await {
+                report.failures.push((sink.name(), e));
+            }
+        }
+        report
+    }
+}
+
+/// Discord 渠道
+pub struct DiscordNotifier<'a, C> {
+    client: &'a C,
+    webhook: DiscordWebhook<'a>,
+}
+
+impl<'a, C: HttpClient> DiscordNotifier<'a, C> {
+    pub fn new(client: &'a C, url: &'a str) -> Self {
+        Self {
+            client,
+            webhook: DiscordWebhook::new(url),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<C: HttpClient> Notifier for DiscordNotifier<'_, C> {
+    fn name(&self) -> &'static str {
+        "discord"
+    }
+
+    async fn notify(&self, event: &NotifyEvent<'_>) -> WatchResult<()> {
+        self.webhook.send(self.client, &event.message()).await
+    }
+}
+
+/// 通用 Webhook 渠道 (JSON 模板)
+pub struct WebhookNotifier<'a, C> {
+    client: &'a C,
+    webhook: TemplateWebhook<'a>,
+}
+
+impl<'a, C: HttpClient> WebhookNotifier<'a, C> {
+    pub fn new(client: &'a C, config: &'a WebhookConfig) -> Self {
+        Self {
+            client,
+            webhook: TemplateWebhook::new(config),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<C: HttpClient> Notifier for WebhookNotifier<'_, C> {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn notify(&self, event: &NotifyEvent<'_>) -> WatchResult<()> {
+        self.webhook.send(self.client, &event.template_vars()).await
+    }
+}
+
+// =========================================================
+// Discord Webhook
+// =========================================================
+
+#[derive(Serialize)]
+struct DiscordMessage<'a> {
+    content: &'a str,
+}
+
+/// Discord Webhook 通知
+pub struct DiscordWebhook<'a> {
+    pub url: &'a str,
+}
+
+impl<'a> DiscordWebhook<'a> {
+    pub fn new(url: &'a str) -> Self {
+        Self { url }
+    }
+
+    pub async fn send<C: HttpClient>(&self, client: &C, content: &str) -> WatchResult<()> {
+        let req = HttpRequest::new(self.url, HttpMethod::Post)
+            .with_header("Content-Type", "application/json")
+            .with_json_body(&DiscordMessage { content })?;
+
+        let resp = client
+            .send(req)
+            .await
+            .map_err(|e| e.in_op("notify.discord.send"))?;
+
+        // Discord 成功时返回 204 (或带 ?wait=true 时返回 200)
+        if !(200..300).contains(&resp.status) {
+            return Err(WatchError::external_api(format!(
+                "Discord webhook failed with status: {}",
+                resp.status
+            ))
+            .in_op("notify.discord"));
+        }
+        Ok(())
+    }
+}
+
+// =========================================================
+// Slack Incoming Webhook
+// =========================================================
+
+#[derive(Serialize)]
+struct SlackMessage<'a> {
+    text: &'a str,
+}
+
+/// Slack Incoming Webhook 渠道
+pub struct SlackNotifier<'a, C> {
+    client: &'a C,
+    url: &'a str,
+}
+
+impl<'a, C: HttpClient> SlackNotifier<'a, C> {
+    pub fn new(client: &'a C, url: &'a str) -> Self {
+        Self { client, url }
+    }
+}
+
+/// Slack 消息正文：仓库、旧版本 → 新版本，以及 Release 链接 (如有)
+fn slack_text(event: &NotifyEvent) -> String {
+    let mut text = match event.old_version {
+        Some(old) => format!(
+            "🚀 {}: {} → {}, dispatched to {}",
+            event.repo, old, event.version, event.target
+        ),
+        None => event.message(),
+    };
+    if let Some(url) = event.release_url {
+        text.push_str(&format!("\n<{}|Release notes>", url));
+    }
+    text
+}
+
+#[async_trait::async_trait(?Send)]
+impl<C: HttpClient> Notifier for SlackNotifier<'_, C> {
+    fn name(&self) -> &'static str {
+        "slack"
+    }
+
+    async fn notify(&self, event: &NotifyEvent<'_>) -> WatchResult<()> {
+        let text = slack_text(event);
+        let req = HttpRequest::new(self.url, HttpMethod::Post)
+            .with_header("Content-Type", "application/json")
+            .with_json_body(&SlackMessage { text: &text })?;
+
+        let resp = self
+            .client
+            .send(req)
+            .await
+            .map_err(|e| e.in_op("notify.slack.send"))?;
+
+        // Slack 成功时返回 200 与纯文本 "ok"
+        if !(200..300).contains(&resp.status) {
+            return Err(WatchError::external_api(format!(
+                "Slack webhook failed with status: {}",
+                resp.status
+            ))
+            .in_op("notify.slack"));
+        }
+        Ok(())
+    }
+}
+
+// =========================================================
+// Telegram Bot
+// =========================================================
+
+const TELEGRAM_API_BASE: &str = "https://api.telegram.org";
+
+#[derive(Serialize)]
+struct TelegramMessage<'a> {
+    chat_id: &'a str,
+    text: &'a str,
+}
+
+/// Telegram Bot 渠道，Token 在构建时由 Secret 名称解析
+pub struct TelegramNotifier<'a, C> {
+    client: &'a C,
+    config: &'a TelegramConfig,
+    bot_token: Option<String>,
+}
+
+impl<'a, C: HttpClient> TelegramNotifier<'a, C> {
+    pub fn new(client: &'a C, config: &'a TelegramConfig, bot_token: Option<String>) -> Self {
+        Self {
+            client,
+            config,
+            bot_token,
+        }
+    }
+}
+
+/// `sendMessage` 接口地址
+fn telegram_send_url(bot_token: &str) -> String {
+    format!("{}/bot{}/sendMessage", TELEGRAM_API_BASE, bot_token)
+}
+
+/// 从 Telegram 的错误响应中读取 `parameters.retry_after`
+fn telegram_retry_after(body: &str) -> Option<u64> {
+    let value: serde_json::Value = serde_json::from_str(body).ok()?;
+    value["parameters"]["retry_after"].as_u64()
+}
+
+#[async_trait::async_trait(?Send)]
+impl<C: HttpClient> Notifier for TelegramNotifier<'_, C> {
+    fn name(&self) -> &'static str {
+        "telegram"
+    }
+
+    async fn notify(&self, event: &NotifyEvent<'_>) -> WatchResult<()> {
+        let bot_token = self.bot_token.as_deref().ok_or_else(|| {
+            WatchError::not_found(format!("Secret '{}' missing", self.config.bot_token_secret))
+                .in_op("notify.telegram")
+        })?;
+
+        let text = event.message();
+        let req = HttpRequest::new(&telegram_send_url(bot_token), HttpMethod::Post)
+            .with_header("Content-Type", "application/json")
+            .with_json_body(&TelegramMessage {
+                chat_id: &self.config.chat_id,
+                text: &text,
+            })?;
+
+        let resp = self
+            .client
+            .send(req)
+            .await
+            // Token 是 URL 的一部分，而 URL 会写入 http 追踪细节，记录日志前需抹去
+            .map_err(|e| e.redact(bot_token).in_op("notify.telegram.send"))?;
+
+        // 被限流时不重试，跳过本条通知
+        if resp.status == 429 {
+            let retry_after = telegram_retry_after(&resp.body)
+                .map(|secs| format!(", retry after {}s", secs))
+                .unwrap_or_default();
+            return Err(WatchError::rate_limited(format!(
+                "Telegram rate limited, message skipped{}",
+                retry_after
+            ))
+            .in_op("notify.telegram"));
+        }
+        if !(200..300).contains(&resp.status) {
+            return Err(WatchError::external_api(format!(
+                "Telegram sendMessage failed with status: {}",
+                resp.status
+            ))
+            .in_op("notify.telegram"));
+        }
+        Ok(())
+    }
+}
+
+// =========================================================
+// 通用 Webhook (JSON 模板)
+// =========================================================
+
+/// 模板变量
+pub struct TemplateVars<'a> {
+    pub repo: &'a str,
+    pub version: &'a str,
+    pub old_version: &'a str,
+}
+
+/// 替换模板中的 `{repo}`、`{version}`、`{old_version}` 占位符
+///
+/// 模板本身是 JSON，代入的值会按 JSON 字符串内容转义，
+/// 因此占位符应写在引号内，例如 `{"text": "{repo} -> {version}"}`
+pub fn render_template(template: &str, vars: &TemplateVars) -> String {
+    fn escape(value: &str) -> String {
+        let quoted = serde_json::to_string(value).unwrap_or_default();
+        quoted[1..quoted.len() - 1].to_string()
+    }
+
+    template
+        .replace("{repo}", &escape(vars.repo))
+        .replace("{old_version}", &escape(vars.old_version))
+        .replace("{version}", &escape(vars.version))
+}
+
+/// 按模板发送通用 Webhook
+pub struct TemplateWebhook<'a> {
+    pub config: &'a WebhookConfig,
+}
+
+impl<'a> TemplateWebhook<'a> {
+    pub fn new(config: &'a WebhookConfig) -> Self {
+        Self { config }
+    }
+
+    pub async fn send<C: HttpClient>(
+        &self,
+        client: &C,
+        vars: &TemplateVars<'_>,
+    ) -> WatchResult<()> {
+        let req = HttpRequest::new(&self.config.url, HttpMethod::Post)
+            .with_header("Content-Type", "application/json")
+            .with_body(render_template(&self.config.template, vars));
+
+        let resp = client
+            .send(req)
+            .await
+            .map_err(|e| e.in_op("notify.webhook.send"))?;
+
+        if !(200..300).contains(&resp.status) {
+            return Err(WatchError::external_api(format!(
+                "Webhook failed with status: {}",
+                resp.status
+            ))
+            .in_op("notify.webhook"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::request::{HttpResponse, MockHttpClient};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// 记录收到的事件
+    struct MockNotifier {
+        events: Rc<RefCell<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl Notifier for MockNotifier {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        async fn notify(&self, event: &NotifyEvent<'_>) -> WatchResult<()> {
+            self.events.borrow_mut().push(event.version.to_string());
+            Ok(())
+        }
+    }
+
+    /// 总是失败的渠道
+    struct FailingNotifier;
+
+    #[async_trait::async_trait(?Send)]
+    impl Notifier for FailingNotifier {
+        fn name(&self) -> &'static str {
+            "failing"
+        }
+
+        async fn notify(&self, _event: &NotifyEvent<'_>) -> WatchResult<()> {
+            Err(WatchError::external_api("sink down").in_op("notify.failing"))
+        }
+    }
+
+    fn event() -> NotifyEvent<'static> {
+        NotifyEvent {
+            repo: "owner/repo".into(),
+            target: "me/fork".into(),
+            version: "v2.0.0",
+            old_version: Some("v1.0.0"),
+            release_url: Some("https://github.com/owner/repo/releases/tag/v2.0.0"),
+        }
+    }
+
+    const SLACK_URL: &str = "https://hooks.slack.com/services/T000/B000/XXXX";
+
+    #[tokio::test]
+    async fn test_slack_notifier_posts_text_payload() {
+        let client = MockHttpClient::new();
+        client.mock_response(SLACK_URL, 200, serde_json::json!("ok"));
+
+        SlackNotifier::new(&client, SLACK_URL)
+            .notify(&event())
+            .await
+            .unwrap();
+
+        let requests = client.requests.borrow();
+        let (url, method, headers, body) = &requests[0];
+        assert_eq!(url, SLACK_URL);
+        assert_eq!(method, "Post");
+        assert_eq!(
+            headers.get("Content-Type").map(String::as_str),
+            Some("application/json")
+        );
+        let body: serde_json::Value = serde_json::from_str(body.as_deref().unwrap()).unwrap();
+        assert_eq!(body.as_object().unwrap().len(), 1);
+        assert_eq!(
+            body["text"],
+            "🚀 owner/repo: v1.0.0 → v2.0.0, dispatched to me/fork\n\
+             <https://github.com/owner/repo/releases/tag/v2.0.0|Release notes>"
+        );
+    }
+
+    fn telegram_config() -> TelegramConfig {
+        TelegramConfig {
+            bot_token_secret: "TG_BOT_TOKEN".into(),
+            chat_id: "-100123".into(),
+        }
+    }
+
+    #[test]
+    fn test_telegram_send_url() {
+        assert_eq!(
+            telegram_send_url("123:abc"),
+            "https://api.telegram.org/bot123:abc/sendMessage"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_telegram_notifier_posts_chat_id_and_text() {
+        let url = telegram_send_url("123:abc");
+        let client = MockHttpClient::new();
+        client.mock_response(&url, 200, serde_json::json!({ "ok": true }));
+        let config = telegram_config();
+
+        TelegramNotifier::new(&client, &config, Some("123:abc".into()))
+            .notify(&event())
+            .await
+            .unwrap();
+
+        let requests = client.requests.borrow();
+        let (sent_url, method, _, body) = &requests[0];
+        assert_eq!(sent_url, &url);
+        assert_eq!(method, "Post");
+        let body: serde_json::Value = serde_json::from_str(body.as_deref().unwrap()).unwrap();
+        assert_eq!(body["chat_id"], "-100123");
+        assert_eq!(body["text"], event().message());
+    }
+
+    /// 模拟网络故障：与 `WorkerHttpClient` 一样在错误追踪中携带完整 URL
+    struct FailingClient;
+
+    #[async_trait::async_trait(?Send)]
+    impl HttpClient for FailingClient {
+        async fn send(&self, req: HttpRequest) -> WatchResult<HttpResponse> {
+            Err(
+                WatchError::external_api(format!("Network error: {}", req.url))
+                    .in_op_with("http.fetch", req.url),
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn test_telegram_notifier_error_hides_bot_token() {
+        let config = telegram_config();
+
+        let err = TelegramNotifier::new(&FailingClient, &config, Some("123:abc".into()))
+            .notify(&event())
+            .await
+            .unwrap_err();
+
+        let message = err.to_string();
+        assert!(!message.contains("123:abc"), "{}", message);
+        assert!(message.contains("http.fetch(https://api.telegram.org/bot<redacted>/sendMessage)"));
+        assert!(message.contains("notify.telegram.send"));
+    }
+
+    #[tokio::test]
+    async fn test_telegram_notifier_missing_secret() {
+        let client = MockHttpClient::new();
+        let config = telegram_config();
+
+        let err = TelegramNotifier::new(&client, &config, None)
+            .notify(&event())
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, crate::error::WatchErrorStatus::NotFound);
+        assert!(err.to_string().contains("TG_BOT_TOKEN"));
+        assert!(client.requests.borrow().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_telegram_notifier_rate_limited_is_skipped() {
+        let client = MockHttpClient::new();
+        client.mock_response(
+            &telegram_send_url("123:abc"),
+            429,
+            serde_json::json!({
+                "ok": false,
+                "error_code": 429,
+                "parameters": { "retry_after": 17 }
+            }),
+        );
+        let config = telegram_config();
+
+        let err = TelegramNotifier::new(&client, &config, Some("123:abc".into()))
+            .notify(&event())
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, crate::error::WatchErrorStatus::RateLimited);
+        assert!(err.to_string().contains("retry after 17s"));
+    }
+
+    #[tokio::test]
+    async fn test_slack_notifier_error_status() {
+        let client = MockHttpClient::new();
+        client.mock_response(SLACK_URL, 404, serde_json::json!("no_team"));
+
+        let err = SlackNotifier::new(&client, SLACK_URL)
+            .notify(&event())
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, crate::error::WatchErrorStatus::ExternalApi);
+    }
+
+    #[tokio::test]
+    async fn test_composite_failing_sink_does_not_block_others() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut notifier = CompositeNotifier::new();
+        notifier.push(FailingNotifier);
+        notifier.push(MockNotifier {
+            events: events.clone(),
+        });
+        notifier.push(MockNotifier {
+            events: events.clone(),
+        });
+
+        let report = notifier
+            .notify_all(&event(), &mut SubrequestBudget::default())
+            .await;
+        let failures = report.failures;
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, "failing");
+        assert_eq!(*events.borrow(), ["v2.0.0", "v2.0.0"]);
+    }
+
+    #[tokio::test]
+    async fn test_composite_skips_sinks_beyond_budget() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut notifier = CompositeNotifier::new();
+        for _ in 0..10 {
+            notifier.push(MockNotifier {
+                events: events.clone(),
+            });
+        }
+
+        let mut budget = SubrequestBudget::new(3);
+        let report = notifier.notify_all(&event(), &mut budget).await;
+        assert_eq!(events.borrow().len(), 3);
+        assert_eq!(report.skipped, ["mock"; 7]);
+        assert!(report.failures.is_empty());
+        assert_eq!(budget, SubrequestBudget::new(0));
+    }
+
+    #[tokio::test]
+    async fn test_composite_from_request_builds_configured_sinks() {
+        let client = MockHttpClient::new();
+        client.mock_response(WEBHOOK_URL, 204, serde_json::json!(null));
+        client.mock_response("https://example.com/hook", 500, serde_json::json!({}));
+
+        let mut request = CreateProjectRequest::default();
+        assert!(CompositeNotifier::from_request(&client, &request, |_| None).is_empty());
+
+        request.notify_webhook = Some(WEBHOOK_URL.into());
+        request.webhook = Some(WebhookConfig {
+            url: "https://example.com/hook".into(),
+            template: r#"{"v":"{version}"}"#.into(),
+        });
+        let notifier = CompositeNotifier::from_request(&client, &request, |_| None);
+        let failures = notifier
+            .notify_all(&event(), &mut SubrequestBudget::default())
+            .await
+            .failures;
+
+        // Discord 成功，通用 Webhook 失败，两者都已发送
+        assert_eq!(client.requests.borrow().len(), 2);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, "webhook");
+    }
+
+    fn vars() -> TemplateVars<'static> {
+        TemplateVars {
+            repo: "owner/repo",
+            version: "v2.0.0",
+            old_version: "v1.0.0",
+        }
+    }
+
+    #[test]
+    fn test_render_template_substitutes_all_placeholders() {
+        let out = render_template(
+            r#"{"repo":"{repo}","to":"{version}","from":"{old_version}"}"#,
+            &vars(),
+        );
+        assert_eq!(
+            out,
+            r#"{"repo":"owner/repo","to":"v2.0.0","from":"v1.0.0"}"#
+        );
+    }
+
+    #[test]
+    fn test_render_template_repeated_and_unknown_placeholders() {
+        let out = render_template("{version} {version} {unknown}", &vars());
+        assert_eq!(out, "v2.0.0 v2.0.0 {unknown}");
+    }
+
+    #[test]
+    fn test_render_template_escapes_json() {
+        let vars = TemplateVars {
+            repo: "owner/repo",
+            version: "v\"2\"",
+            old_version: "",
+        };
+        let out = render_template(r#"{"v":"{version}","old":"{old_version}"}"#, &vars);
+        let parsed: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(parsed["v"], "v\"2\"");
+        assert_eq!(parsed["old"], "");
+    }
+
+    #[tokio::test]
+    async fn test_template_webhook_sends_rendered_body() {
+        let client = MockHttpClient::new();
+        client.mock_response("https://example.com/hook", 200, serde_json::json!({}));
+        let config = WebhookConfig {
+            url: "https://example.com/hook".into(),
+            template: r#"{"text":"{repo} {old_version} -> {version}"}"#.into(),
+        };
+
+        TemplateWebhook::new(&config)
+            .send(&client, &vars())
+            .await
+            .unwrap();
+
+        let requests = client.requests.borrow();
+        let (_, _, headers, body) = &requests[0];
+        assert_eq!(
+            headers.get("Content-Type").map(String::as_str),
+            Some("application/json")
+        );
+        assert_eq!(
+            body.as_deref(),
+            Some(r#"{"text":"owner/repo v1.0.0 -> v2.0.0"}"#)
+        );
+    }
+
+    const WEBHOOK_URL: &str = "https://discord.com/api/webhooks/1/token";
+
+    #[tokio::test]
+    async fn test_discord_webhook_posts_content() {
+        let client = MockHttpClient::new();
+        client.mock_response(WEBHOOK_URL, 204, serde_json::json!(null));
+
+        DiscordWebhook::new(WEBHOOK_URL)
+            .send(&client, "hello")
+            .await
+            .unwrap();
+
+        let requests = client.requests.borrow();
+        let (url, method, headers, body) = &requests[0];
+        assert_eq!(url, WEBHOOK_URL);
+        assert_eq!(method, "Post");
+        assert_eq!(
+            headers.get("Content-Type").map(String::as_str),
+            Some("application/json")
+        );
+        let body: serde_json::Value = serde_json::from_str(body.as_deref().unwrap()).unwrap();
+        assert_eq!(body["content"], "hello");
+    }
+
+    #[tokio::test]
+    async fn test_discord_webhook_error_status() {
+        let client = MockHttpClient::new();
+        client.mock_response(WEBHOOK_URL, 400, serde_json::json!({}));
+
+        let err = DiscordWebhook::new(WEBHOOK_URL)
+            .send(&client, "hello")
+            .await
+            .unwrap_err();
+        assert_eq!(err.status, crate::error::WatchErrorStatus::ExternalApi);
+    }
+}
@@ -0,0 +1,31 @@
+use crate::error::{WatchError, WatchResult};
+use crate::utils::github::release::GitHubRelease;
+use verwatch_shared::SourceKind;
+
+/// 上游版本来源抽象
+///
+/// 不同托管平台 (GitHub / GitLab ...) 只需实现获取最新 Release 的逻辑，
+/// 统一返回 `GitHubRelease` 以复用版本比较与 Dispatch 流程
+#[async_trait::async_trait(?Send)]
+pub trait ReleaseProvider {
+    /// 获取最新 Release
+    async fn fetch_latest_release(&self, owner: &str, repo: &str) -> WatchResult<GitHubRelease>;
+
+    /// 按来源类型获取最新版本
+    ///
+    /// 默认仅支持 Releases，平台支持 Tags 时应覆盖此方法
+    async fn fetch_latest(
+        &self,
+        owner: &str,
+        repo: &str,
+        source: SourceKind,
+    ) -> WatchResult<GitHubRelease> {
+        match source {
+            SourceKind::Releases => self.fetch_latest_release(owner, repo).await,
+            SourceKind::Tags => Err(WatchError::invalid_input(
+                "Source kind 'tags' is not supported by this provider",
+            )
+            .in_op_with("provider.fetch_latest", format!("{}/{}", owner, repo))),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use verwatch_shared::Timestamp;
+
+// =========================================================
+// 令牌桶限流
+// =========================================================
+
+/// 令牌桶参数
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RateLimit {
+    /// 桶容量 (允许的突发请求数)
+    pub capacity: u32,
+    /// 每分钟补充的令牌数
+    pub refill_per_minute: u32,
+}
+
+/// 令牌桶状态 (持久化在 Registry DO 中)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RateBucket {
+    pub tokens: f64,
+    pub updated_at: Timestamp,
+}
+
+impl RateBucket {
+    /// 创建一个满的令牌桶
+    pub fn full(limit: &RateLimit, now: Timestamp) -> Self {
+        Self {
+            tokens: limit.capacity as f64,
+            updated_at: now,
+        }
+    }
+
+    /// 计算 `now` 时刻补充后的令牌数 (不超过容量)
+    ///
+    /// 时钟回拨时不补充也不扣减
+    pub fn refilled(&self, limit: &RateLimit, now: Timestamp) -> f64 {
+        let elapsed_ms = (now.as_millis() - self.updated_at.as_millis()).max(0) as f64;
+        let added = elapsed_ms * limit.refill_per_minute as f64 / 60_000.0;
+        (self.tokens + added).min(limit.capacity as f64)
+    }
+
+    /// 补充令牌后尝试取走一个，返回是否放行
+    pub fn try_take(&mut self, limit: &RateLimit, now: Timestamp) -> bool {
+        let tokens = self.refilled(limit, now);
+        self.updated_at = now.max(self.updated_at);
+        if tokens >= 1.0 {
+            self.tokens = tokens - 1.0;
+            true
+        } else {
+            self.tokens = tokens;
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: RateLimit = RateLimit {
+        capacity: 3,
+        refill_per_minute: 60,
+    };
+
+    #[test]
+    fn test_refill_is_proportional_to_elapsed_time() {
+        let bucket = RateBucket {
+            tokens: 0.0,
+            updated_at: Timestamp::new(0),
+        };
+        assert_eq!(bucket.refilled(&LIMIT, Timestamp::new(0)), 0.0);
+        assert_eq!(bucket.refilled(&LIMIT, Timestamp::new(500)), 0.5);
+        assert_eq!(bucket.refilled(&LIMIT, Timestamp::new(2_000)), 2.0);
+    }
+
+    #[test]
+    fn test_refill_is_capped_at_capacity() {
+        let bucket = RateBucket {
+            tokens: 2.5,
+            updated_at: Timestamp::new(0),
+        };
+        assert_eq!(bucket.refilled(&LIMIT, Timestamp::new(60_000)), 3.0);
+    }
+
+    #[test]
+    fn test_refill_ignores_clock_going_backwards() {
+        let bucket = RateBucket {
+            tokens: 1.0,
+            updated_at: Timestamp::new(10_000),
+        };
+        assert_eq!(bucket.refilled(&LIMIT, Timestamp::new(5_000)), 1.0);
+    }
+
+    #[test]
+    fn test_try_take_exhausts_then_recovers() {
+        let now = Timestamp::new(0);
+        let mut bucket = RateBucket::full(&LIMIT, now);
+        assert!(bucket.try_take(&LIMIT, now));
+        assert!(bucket.try_take(&LIMIT, now));
+        assert!(bucket.try_take(&LIMIT, now));
+        assert!(!bucket.try_take(&LIMIT, now));
+
+        // 1 秒后补充 1 个令牌
+        let later = Timestamp::new(1_000);
+        assert!(bucket.try_take(&LIMIT, later));
+        assert!(!bucket.try_take(&LIMIT, later));
+    }
+}
@@ -1,17 +1,27 @@
 use crate::error::{WatchError, WatchResult};
 use serde::{Serialize, de::DeserializeOwned};
 use std::collections::HashMap;
+use std::future::Future;
 use std::time::Duration;
 use worker::{Delay, Fetch, Headers, Request, RequestInit, wasm_bindgen};
 
 #[cfg(test)]
 use std::cell::RefCell;
+#[cfg(test)]
+use std::collections::VecDeque;
 
 // =========================================================
 // 常量定义
 // =========================================================
 
+/// 限流响应未携带 Retry-After 时首次重试前的等待秒数 (之后按指数增长)
 const RATE_LIMIT_WAIT_SECONDS: u64 = 120;
+/// 单个请求重试等待的总时长上限，避免超出 Worker 的执行时间预算
+const MAX_TOTAL_WAIT_SECONDS: u64 = 300;
+/// 默认最大重试次数
+pub const DEFAULT_MAX_RETRIES: u32 = 1;
+/// 只有发往 GitHub API 的请求才按限流响应重试
+const GITHUB_API_PREFIX: &str = "https://api.github.com/";
 
 // =========================================================
 // 核心抽象层 (HTTP Interface Abstraction)
@@ -23,6 +33,15 @@ pub enum HttpMethod {
     Post,
     Put,
     Delete,
+    Patch,
+    Head,
+}
+
+impl HttpMethod {
+    /// 该方法是否允许携带请求体 (Fetch 会拒绝带 Body 的 GET/HEAD 请求)
+    pub fn allows_body(self) -> bool {
+        !matches!(self, HttpMethod::Get | HttpMethod::Head)
+    }
 }
 
 impl From<HttpMethod> for worker::Method {
@@ -32,6 +51,8 @@ impl From<HttpMethod> for worker::Method {
             HttpMethod::Post => worker::Method::Post,
             HttpMethod::Put => worker::Method::Put,
             HttpMethod::Delete => worker::Method::Delete,
+            HttpMethod::Patch => worker::Method::Patch,
+            HttpMethod::Head => worker::Method::Head,
         }
     }
 }
@@ -60,6 +81,12 @@ impl HttpRequest {
         self
     }
 
+    /// 设置原始请求体 (调用方负责设置对应的 Content-Type)
+    pub fn with_body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
     pub fn with_json_body<T: Serialize>(mut self, body: &T) -> WatchResult<Self> {
         self.body = Some(
             serde_json_wasm::to_string(body)
@@ -71,10 +98,19 @@ impl HttpRequest {
 
 pub struct HttpResponse {
     pub status: u16,
+    /// 响应头 (key 统一为小写)
+    pub headers: HashMap<String, String>,
     pub body: String,
 }
 
 impl HttpResponse {
+    /// 获取响应头 (大小写不敏感)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(|v| v.as_str())
+    }
+
     pub fn json<T: DeserializeOwned>(&self) -> WatchResult<T> {
         serde_json_wasm::from_str(&self.body)
             .map_err(|e| WatchError::serialization(e.to_string()).in_op("http.json"))
@@ -91,79 +127,175 @@ pub trait HttpClient {
 // =========================================================
 
 #[derive(Clone)]
-pub struct WorkerHttpClient;
+pub struct WorkerHttpClient {
+    /// 遇到限流响应 (403/429) 时的最大重试次数
+    pub max_retries: u32,
+}
+
+impl Default for WorkerHttpClient {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+        }
+    }
+}
+
+impl WorkerHttpClient {
+    /// 读取 `HTTP_MAX_RETRIES` 变量作为限流重试次数，未配置或无效时使用默认值
+    pub fn from_env(env: &worker::Env) -> Self {
+        let max_retries = env
+            .var("HTTP_MAX_RETRIES")
+            .ok()
+            .and_then(|v| v.to_string().trim().parse::<u32>().ok())
+            .unwrap_or(DEFAULT_MAX_RETRIES);
+        Self::default().with_max_retries(max_retries)
+    }
+
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+}
 
 #[async_trait::async_trait(?Send)]
 impl HttpClient for WorkerHttpClient {
     async fn send(&self, req: HttpRequest) -> WatchResult<HttpResponse> {
-        // 使用循环处理重试逻辑
-        let mut retry_count = 0;
-        // 限制最大重试次数防止死循环，这里设为 1 次，即等待后重试一次
-        const MAX_RETRIES: i32 = 1;
-        let url_for_context = req.url.clone();
-
-        loop {
-            let headers = Headers::new();
-            // 使用引用遍历，避免消耗 req.headers
-            for (k, v) in &req.headers {
-                headers.set(k, v).map_err(|e| {
-                    WatchError::from(e).in_op_with("http.headers", &url_for_context)
-                })?;
-            }
+        send_with_retry(&FetchClient, req, self.max_retries, |wait| {
+            Delay::from(wait)
+        })
+        .await
+    }
+}
 
-            let mut init = RequestInit {
-                method: req.method.into(),
-                headers,
-                ..Default::default()
-            };
+/// 单次发出请求的 Fetch 客户端 (不含重试)
+struct FetchClient;
 
-            if let Some(body_str) = &req.body {
-                init.body = Some(wasm_bindgen::JsValue::from_str(body_str));
-            }
+#[async_trait::async_trait(?Send)]
+impl HttpClient for FetchClient {
+    async fn send(&self, req: HttpRequest) -> WatchResult<HttpResponse> {
+        let url_for_context = req.url.as_str();
 
-            let worker_req = Request::new_with_init(&req.url, &init)
-                .map_err(|e| WatchError::from(e).in_op_with("http.request", &url_for_context))?;
-            let mut response = Fetch::Request(worker_req).send().await.map_err(|e| {
-                WatchError::external_api(e.to_string()).in_op_with("http.fetch", &url_for_context)
-            })?;
-            let status = response.status_code();
-
-            // 检查 403 和 Rate Limit
-            if status == 403 && retry_count < MAX_RETRIES {
-                let remaining = response
-                    .headers()
-                    .get("X-RateLimit-Remaining")
-                    .map_err(|e| WatchError::from(e).in_op("http.rate_limit_header"))?;
-                if let Some(val) = remaining {
-                    // 如果剩余次数为 0，说明被限流
-                    if val == "0" {
-                        retry_count += 1;
-                        // 等待指定时间
-                        Delay::from(Duration::from_secs(RATE_LIMIT_WAIT_SECONDS)).await;
-                        // 继续下一次循环进行重试
-                        continue;
-                    }
-                }
-            }
+        let headers = Headers::new();
+        for (k, v) in &req.headers {
+            headers
+                .set(k, v)
+                .map_err(|e| WatchError::from(e).in_op_with("http.headers", url_for_context))?;
+        }
 
-            // 正常返回（成功或非 Rate Limit 的错误）
-            let body = response
-                .text()
-                .await
-                .map_err(|e| WatchError::from(e).in_op_with("http.body", &url_for_context))?;
-            return Ok(HttpResponse { status, body });
+        let mut init = RequestInit {
+            method: req.method.into(),
+            headers,
+            ..Default::default()
+        };
+
+        if let Some(body_str) = req.body.as_ref().filter(|_| req.method.allows_body()) {
+            init.body = Some(wasm_bindgen::JsValue::from_str(body_str));
+        }
+
+        let worker_req = Request::new_with_init(&req.url, &init)
+            .map_err(|e| WatchError::from(e).in_op_with("http.request", url_for_context))?;
+        let mut response = Fetch::Request(worker_req).send().await.map_err(|e| {
+            WatchError::external_api(e.to_string()).in_op_with("http.fetch", url_for_context)
+        })?;
+
+        let headers = response
+            .headers()
+            .entries()
+            .map(|(k, v)| (k.to_ascii_lowercase(), v))
+            .collect();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| WatchError::from(e).in_op_with("http.body", url_for_context))?;
+        Ok(HttpResponse {
+            status: response.status_code(),
+            headers,
+            body,
+        })
+    }
+}
+
+/// 发送请求，遇到限流响应时等待后重试
+///
+/// 仅对 GitHub API 生效：通知渠道 (Discord/Slack/Telegram/Webhook) 被限流时直接跳过，
+/// 不应占用检查的执行时间，也不应发出子请求预算之外的额外请求
+///
+/// 与具体的传输和等待方式解耦，以便使用 MockHttpClient 测试重试逻辑
+async fn send_with_retry<C, S, F>(
+    client: &C,
+    req: HttpRequest,
+    max_retries: u32,
+    sleep: S,
+) -> WatchResult<HttpResponse>
+where
+    C: HttpClient,
+    S: Fn(Duration) -> F,
+    F: Future<Output = ()>,
+{
+    let max_retries = if req.url.starts_with(GITHUB_API_PREFIX) {
+        max_retries
+    } else {
+        0
+    };
+    let mut attempt = 0;
+    let mut waited = Duration::ZERO;
+    loop {
+        let response = client.send(req.clone()).await?;
+        if attempt >= max_retries {
+            return Ok(response);
         }
+        let Some(wait) = rate_limit_wait(&response, attempt) else {
+            return Ok(response);
+        };
+        // 超出总等待预算时不再重试，直接返回限流响应
+        if waited + wait > Duration::from_secs(MAX_TOTAL_WAIT_SECONDS) {
+            return Ok(response);
+        }
+        sleep(wait).await;
+        waited += wait;
+        attempt += 1;
+    }
+}
+
+/// 判断响应是否为限流，返回重试前需要等待的时长
+///
+/// 优先使用 `Retry-After` (秒数，次级限流会携带)，
+/// 否则在 `X-RateLimit-Remaining` 为 0 时按指数退避等待
+fn rate_limit_wait(response: &HttpResponse, attempt: u32) -> Option<Duration> {
+    if !matches!(response.status, 403 | 429) {
+        return None;
+    }
+    if let Some(secs) = response
+        .header("Retry-After")
+        .and_then(|v| v.trim().parse::<u64>().ok())
+    {
+        return Some(Duration::from_secs(secs));
+    }
+    if response.header("X-RateLimit-Remaining") == Some("0") {
+        let factor = 1u64.checked_shl(attempt).unwrap_or(u64::MAX);
+        return Some(Duration::from_secs(
+            RATE_LIMIT_WAIT_SECONDS.saturating_mul(factor),
+        ));
     }
+    None
 }
 
 // =========================================================
 // 测试工具: MockHttpClient
 // =========================================================
 
+#[cfg(test)]
+type MockResponse = (u16, HashMap<String, String>, String);
+
+/// `mock_sequence` 的单项: (Status, Response Body, Response Headers)
+#[cfg(test)]
+type MockReply<'a> = (u16, serde_json::Value, &'a [(&'a str, &'a str)]);
+
 #[cfg(test)]
 pub struct MockHttpClient {
-    // (URL, (Status, Response Body))
-    responses: RefCell<HashMap<String, (u16, String)>>,
+    // (URL, 依次返回的 (Status, Response Headers, Response Body))
+    // 序列只剩最后一项时重复返回该项
+    responses: RefCell<HashMap<String, VecDeque<MockResponse>>>,
     // 记录发出的请求 (URL, Method, Headers, Body)
     // 更新：添加 Headers 记录
     pub requests: RefCell<Vec<(String, String, HashMap<String, String>, Option<String>)>>,
@@ -178,11 +310,43 @@ impl MockHttpClient {
         }
     }
 
-    // pub fn mock_response(&self, url: &str, status: u16, body: serde_json::Value) {
-    //     self.responses
-    //         .borrow_mut()
-    //         .insert(url.to_string(), (status, body.to_string()));
-    // }
+    pub fn mock_response(&self, url: &str, status: u16, body: serde_json::Value) {
+        self.mock_response_with_headers(url, status, body, &[]);
+    }
+
+    pub fn mock_response_with_headers(
+        &self,
+        url: &str,
+        status: u16,
+        body: serde_json::Value,
+        headers: &[(&str, &str)],
+    ) {
+        let headers = headers
+            .iter()
+            .map(|(k, v)| (k.to_ascii_lowercase(), v.to_string()))
+            .collect();
+        self.responses.borrow_mut().insert(
+            url.to_string(),
+            VecDeque::from([(status, headers, body.to_string())]),
+        );
+    }
+
+    /// 为同一 URL 设置按顺序返回的响应序列 (用完后重复最后一项)
+    pub fn mock_sequence(&self, url: &str, responses: &[MockReply<'_>]) {
+        let sequence = responses
+            .iter()
+            .map(|(status, body, headers)| {
+                let headers = headers
+                    .iter()
+                    .map(|(k, v)| (k.to_ascii_lowercase(), v.to_string()))
+                    .collect();
+                (*status, headers, body.to_string())
+            })
+            .collect();
+        self.responses
+            .borrow_mut()
+            .insert(url.to_string(), sequence);
+    }
 }
 
 #[cfg(test)]
@@ -196,17 +360,195 @@ impl HttpClient for MockHttpClient {
             req.body.clone(),
         ));
 
-        let responses = self.responses.borrow();
-        if let Some((status, body)) = responses.get(&req.url) {
+        let mut responses = self.responses.borrow_mut();
+        let next = responses.get_mut(&req.url).and_then(|sequence| {
+            if sequence.len() > 1 {
+                sequence.pop_front()
+            } else {
+                sequence.front().cloned()
+            }
+        });
+        if let Some((status, headers, body)) = next {
             Ok(HttpResponse {
-                status: *status,
-                body: body.clone(),
+                status,
+                headers,
+                body,
             })
         } else {
             Ok(HttpResponse {
                 status: 404,
+                headers: HashMap::new(),
                 body: "Not Found".to_string(),
             })
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patch_request_method_conversion() {
+        let req = HttpRequest::new("https://api.github.com/repos/o/r", HttpMethod::Patch)
+            .with_body(r#"{"name":"r"}"#);
+        assert!(req.method.allows_body());
+        assert_eq!(worker::Method::from(req.method), worker::Method::Patch);
+        assert_eq!(worker::Method::from(HttpMethod::Head), worker::Method::Head);
+        assert!(!HttpMethod::Head.allows_body());
+        assert!(!HttpMethod::Get.allows_body());
+    }
+
+    const URL: &str = "https://api.github.com/repos/o/r/releases/latest";
+
+    #[tokio::test]
+    async fn test_mocked_etag_header_is_readable() {
+        let client = MockHttpClient::new();
+        client.mock_response_with_headers(
+            URL,
+            200,
+            serde_json::json!({"tag_name": "v1"}),
+            &[("ETag", "W/\"abc\""), ("X-RateLimit-Remaining", "59")],
+        );
+
+        let res = client
+            .send(HttpRequest::new(URL, HttpMethod::Get))
+            .await
+            .unwrap();
+        // 响应头按小写保存，读取时大小写不敏感
+        assert_eq!(res.header("etag"), Some("W/\"abc\""));
+        assert_eq!(res.header("ETag"), Some("W/\"abc\""));
+        assert_eq!(res.header("x-ratelimit-remaining"), Some("59"));
+        assert_eq!(res.header("Last-Modified"), None);
+    }
+
+    #[tokio::test]
+    async fn test_mock_sequence_consumed_in_order() {
+        let client = MockHttpClient::new();
+        client.mock_sequence(
+            URL,
+            &[
+                (304, serde_json::json!(null), &[("ETag", "\"a\"")]),
+                (200, serde_json::json!({"tag_name": "v1"}), &[]),
+                (200, serde_json::json!({"tag_name": "v2"}), &[]),
+            ],
+        );
+
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            let res = client
+                .send(HttpRequest::new(URL, HttpMethod::Get))
+                .await
+                .unwrap();
+            seen.push((res.status, res.body));
+        }
+        assert_eq!(seen[0].0, 304);
+        assert_eq!(seen[1], (200, r#"{"tag_name":"v1"}"#.to_string()));
+        // 序列用完后重复最后一项
+        assert_eq!(seen[2], (200, r#"{"tag_name":"v2"}"#.to_string()));
+        assert_eq!(seen[3], seen[2]);
+
+        // 未设置的 URL 返回 404
+        let res = client
+            .send(HttpRequest::new("https://example.com", HttpMethod::Get))
+            .await
+            .unwrap();
+        assert_eq!(res.status, 404);
+    }
+
+    /// 使用 MockHttpClient 运行重试循环，返回 (最终状态码, 每次等待的秒数)
+    async fn run_with_retry(client: &MockHttpClient, max_retries: u32) -> (u16, Vec<u64>) {
+        run_with_retry_at(client, URL, max_retries).await
+    }
+
+    async fn run_with_retry_at(
+        client: &MockHttpClient,
+        url: &str,
+        max_retries: u32,
+    ) -> (u16, Vec<u64>) {
+        let waits = RefCell::new(Vec::new());
+        let response = send_with_retry(
+            client,
+            HttpRequest::new(url, HttpMethod::Get),
+            max_retries,
+            |wait| {
+                waits.borrow_mut().push(wait.as_secs());
+                async {}
+            },
+        )
+        .await
+        .unwrap();
+        (response.status, waits.into_inner())
+    }
+
+    #[tokio::test]
+    async fn test_retry_honors_retry_after() {
+        let client = MockHttpClient::new();
+        client.mock_sequence(
+            URL,
+            &[
+                (403, serde_json::json!({}), &[("Retry-After", "7")]),
+                (200, serde_json::json!({"tag_name": "v1"}), &[]),
+            ],
+        );
+
+        let (status, waits) = run_with_retry(&client, 2).await;
+        assert_eq!(status, 200);
+        assert_eq!(waits, [7]);
+        assert_eq!(client.requests.borrow().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_exponential_backoff_and_budget() {
+        let exhausted: &[(&str, &str)] = &[("X-RateLimit-Remaining", "0")];
+        let client = MockHttpClient::new();
+        client.mock_sequence(URL, &[(403, serde_json::json!({}), exhausted)]);
+
+        // 120s + 240s 超出总等待预算，第二次重试不再进行
+        let (status, waits) = run_with_retry(&client, 3).await;
+        assert_eq!(status, 403);
+        assert_eq!(waits, [RATE_LIMIT_WAIT_SECONDS]);
+        assert_eq!(client.requests.borrow().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_for_plain_forbidden_or_when_disabled() {
+        let client = MockHttpClient::new();
+        client.mock_response(URL, 403, serde_json::json!({"message": "Forbidden"}));
+        let (status, waits) = run_with_retry(&client, 1).await;
+        assert_eq!(status, 403);
+        assert!(waits.is_empty());
+
+        let client = MockHttpClient::new();
+        client.mock_sequence(
+            URL,
+            &[(429, serde_json::json!({}), &[("Retry-After", "1")])],
+        );
+        let (status, waits) = run_with_retry(&client, 0).await;
+        assert_eq!(status, 429);
+        assert!(waits.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_for_notification_sinks() {
+        for url in [
+            "https://discord.com/api/webhooks/1/abc",
+            "https://api.telegram.org/bot123:abc/sendMessage",
+            "https://example.com/hook",
+        ] {
+            let client = MockHttpClient::new();
+            client.mock_sequence(
+                url,
+                &[
+                    (429, serde_json::json!({}), &[("Retry-After", "5")]),
+                    (200, serde_json::json!({}), &[]),
+                ],
+            );
+
+            let (status, waits) = run_with_retry_at(&client, url, 2).await;
+            assert_eq!(status, 429, "{url}");
+            assert!(waits.is_empty(), "{url}");
+            assert_eq!(client.requests.borrow().len(), 1, "{url}");
+        }
+    }
+}
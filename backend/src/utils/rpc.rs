@@ -1,8 +1,29 @@
 use crate::error::{WatchError, WatchResult};
 
+use futures::future::{Either, select};
 use serde::{Serialize, de::DeserializeOwned};
 use std::future::Future;
-use worker::{Headers, Method, Request, RequestInit, Response, Stub, wasm_bindgen::JsValue};
+use std::pin::pin;
+use std::time::Duration;
+use worker::{Delay, Headers, Method, Request, RequestInit, Response, Stub, wasm_bindgen::JsValue};
+
+/// RPC 调用的默认超时时间
+pub const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 瞬时错误 (非结构化的 5xx 响应) 的默认重试次数
+pub const DEFAULT_RPC_RETRIES: u32 = 2;
+
+/// 两次重试之间的固定间隔
+const RPC_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// 读取 `RPC_TIMEOUT_SECS` 变量作为 RPC 超时，未配置或无效时使用默认值
+pub fn rpc_timeout(env: &worker::Env) -> Duration {
+    env.var("RPC_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.to_string().trim().parse::<u64>().ok())
+        .filter(|&secs| secs > 0)
+        .map_or(DEFAULT_RPC_TIMEOUT, Duration::from_secs)
+}
 
 // =========================================================
 // 核心 Trait 定义
@@ -15,6 +36,11 @@ pub trait ApiRequest: Serialize + DeserializeOwned {
     type Response: Serialize + DeserializeOwned;
     /// DO 内部路由路径
     const PATH: &'static str;
+    /// 是否为长耗时命令 (批量 fan-out、检查/分发流程)
+    ///
+    /// 这类命令的耗时随项目数量或上游限流等待增长，客户端超时会在 DO 仍在执行时
+    /// 提前返回错误，因此不施加 `RpcClient` 的超时
+    const LONG_RUNNING: bool = false;
 }
 
 // =========================================================
@@ -25,16 +51,28 @@ pub struct RpcClient {
     stub: Stub,
     // e.g. "http://monitor" or "http://registry"
     base_url: String,
+    timeout: Duration,
+    /// 遇到瞬时错误时的最大重试次数
+    max_retries: u32,
 }
 
 impl RpcClient {
-    pub fn new(stub: Stub, base_url: &str) -> Self {
+    /// `max_retries` 为瞬时错误 (如 DO 休眠唤醒期间的 5xx) 的重试次数，0 表示不重试
+    pub fn new(stub: Stub, base_url: &str, max_retries: u32) -> Self {
         Self {
             stub,
             base_url: base_url.to_string(),
+            timeout: DEFAULT_RPC_TIMEOUT,
+            max_retries,
         }
     }
 
+    /// 覆盖默认的 RPC 超时时间
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
     /// 发送强类型请求并获取解析后的响应
     pub async fn send<T: ApiRequest>(&self, req: &T) -> WatchResult<T::Response> {
         // 1. 序列化请求
@@ -42,6 +80,22 @@ impl RpcClient {
             WatchError::serialization(e.to_string()).in_op_with("rpc.serialize", T::PATH)
         })?;
 
+        // 瞬时错误按固定间隔重试，业务错误与超时直接返回
+        with_retry(
+            self.max_retries,
+            || async {
+                self.attempt::<T>(&body)
+                    .await
+                    .unwrap_or_else(|e| Attempt::Done(Err(e)))
+            },
+            || Delay::from(RPC_RETRY_DELAY),
+            T::PATH,
+        )
+        .await
+    }
+
+    /// 发送一次请求 (Request 的 Body 只能消费一次，因此每次尝试都重新构造)
+    async fn attempt<T: ApiRequest>(&self, body: &str) -> WatchResult<Attempt<T::Response>> {
         // 2. 构造 Headers
         let headers = Headers::new();
         headers
@@ -51,18 +105,21 @@ impl RpcClient {
         // 3. 构造 Request
         let mut init = RequestInit::new();
         init.with_method(Method::Post).with_headers(headers);
-        init.with_body(Some(JsValue::from_str(&body)));
+        init.with_body(Some(JsValue::from_str(body)));
 
         let url = format!("{}{}", self.base_url, T::PATH);
         let request = Request::new_with_init(&url, &init)
             .map_err(|e| WatchError::from(e).in_op_with("rpc.request", T::PATH))?;
 
-        // 4. 发送请求 (RPC 调用)
-        let mut response = self
-            .stub
-            .fetch_with_request(request)
-            .await
-            .map_err(|e| WatchError::from(e).in_op_with("rpc.fetch", T::PATH))?;
+        // 4. 发送请求 (RPC 调用)，与超时计时器竞争，避免 DO 挂起时阻塞调用方
+        let fetch = async {
+            self.stub
+                .fetch_with_request(request)
+                .await
+                .map_err(|e| WatchError::from(e).in_op_with("rpc.fetch", T::PATH))
+        };
+        let timer = timeout_for::<T>(self.timeout).map(Delay::from);
+        let mut response = race_optional_timeout(fetch, timer, T::PATH).await?;
 
         // 5. 检查状态码
         if response.status_code() != 200 {
@@ -88,20 +145,101 @@ impl RpcClient {
             }
 
             // Fallback: 统一封装为 WatchError::Store
-            return Err(WatchError::store(format!(
+            let err = WatchError::store(format!(
                 "RPC Error [{}]: {}",
                 response.status_code(),
                 error_text
             ))
-            .in_op_with("rpc.call", T::PATH));
+            .in_op_with("rpc.call", T::PATH);
+
+            // 非结构化的 5xx 通常来自平台 (如 DO 唤醒失败)，可以重试
+            if !is_rpc_error && response.status_code() >= 500 {
+                return Ok(Attempt::Transient(err));
+            }
+            return Err(err);
         }
 
         // 6. 反序列化响应
         let data = response
             .json::<T::Response>()
             .await
-            .map_err(|e| WatchError::from(e).in_op_with("rpc.deserialize", T::PATH))?;
-        Ok(data)
+            .map_err(|e| WatchError::from(e).in_op_with("rpc.deserialize", T::PATH));
+        Ok(Attempt::Done(data))
+    }
+}
+
+/// 单次 RPC 尝试的结果
+pub(crate) enum Attempt<T> {
+    /// 最终结果 (成功、业务错误或超时)，不再重试
+    Done(WatchResult<T>),
+    /// 瞬时错误，可以重试
+    Transient(WatchError),
+}
+
+/// 反复执行 `attempt`，遇到瞬时错误时等待 `delay` 后重试，最多重试 `max_retries` 次
+///
+/// 与 `race_timeout` 一样以 Future 形式注入计时器，测试中可替换为立即完成的 Future
+pub(crate) async fn with_retry<T, A, AF, D, DF>(
+    max_retries: u32,
+    mut attempt: A,
+    mut delay: D,
+    path: &str,
+) -> WatchResult<T>
+where
+    A: FnMut() -> AF,
+    AF: Future<Output = Attempt<T>>,
+    D: FnMut() -> DF,
+    DF: Future<Output = ()>,
+{
+    let mut retries = 0;
+    loop {
+        match attempt().await {
+            Attempt::Done(result) => return result,
+            Attempt::Transient(e) if retries >= max_retries => {
+                return Err(e.in_op_with("rpc.retry", format!("{} x{}", path, retries)));
+            }
+            Attempt::Transient(_) => {
+                retries += 1;
+                delay().await;
+            }
+        }
+    }
+}
+
+/// 计算命令实际使用的超时：长耗时命令不设超时
+pub(crate) fn timeout_for<T: ApiRequest>(timeout: Duration) -> Option<Duration> {
+    (!T::LONG_RUNNING).then_some(timeout)
+}
+
+/// 有计时器时与之竞争，否则直接等待 `fut` 完成
+pub(crate) async fn race_optional_timeout<T, F, D>(
+    fut: F,
+    timer: Option<D>,
+    path: &str,
+) -> WatchResult<T>
+where
+    F: Future<Output = WatchResult<T>>,
+    D: Future<Output = ()>,
+{
+    match timer {
+        Some(timer) => race_timeout(fut, timer, path).await,
+        None => fut.await,
+    }
+}
+
+/// 令 `fut` 与 `timer` 竞争：`timer` 先完成时返回带 `rpc.timeout` 追踪的错误
+///
+/// 计时器以 Future 形式注入，生产环境使用 `worker::Delay`，测试中可替换为任意 Future
+pub(crate) async fn race_timeout<T, F, D>(fut: F, timer: D, path: &str) -> WatchResult<T>
+where
+    F: Future<Output = WatchResult<T>>,
+    D: Future<Output = ()>,
+{
+    match select(pin!(fut), pin!(timer)).await {
+        Either::Left((result, _)) => result,
+        Either::Right(((), _)) => {
+            Err(WatchError::store("RPC timeout").in_op_with("rpc.timeout", path))
+        }
     }
 }
 
@@ -167,3 +305,145 @@ impl RpcHandler {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::WatchErrorStatus;
+
+    #[tokio::test]
+    async fn test_race_timeout_returns_result_when_fast() {
+        let result = race_timeout(
+            async { Ok(42) },
+            tokio::time::sleep(Duration::from_secs(10)),
+            "/fast",
+        )
+        .await;
+        assert_eq!(result.unwrap(), 42);
+    }
+
+    #[tokio::test]
+    async fn test_race_timeout_expires_on_slow_stub() {
+        // 模拟挂起的 DO：fetch 永不返回
+        let slow = std::future::pending::<WatchResult<u32>>();
+        let err = race_timeout(slow, tokio::time::sleep(Duration::from_millis(5)), "/slow")
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.status, WatchErrorStatus::Store);
+        assert_eq!(err.message(), "RPC timeout");
+        let span = &err.spans()[0];
+        assert_eq!(span.operation, "rpc.timeout");
+        assert_eq!(span.detail.as_deref(), Some("/slow"));
+    }
+
+    #[tokio::test]
+    async fn test_long_fan_out_is_not_cut_off() {
+        use crate::repository::protocol::{RegistrySwitchAllCmd, RegistryTriggerCheckCmd};
+
+        // 超时远小于 fan-out 耗时，长耗时命令仍应等到结果
+        let timeout = Duration::from_millis(1);
+        let slow_fan_out = async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(7)
+        };
+        let timer = timeout_for::<RegistrySwitchAllCmd>(timeout).map(tokio::time::sleep);
+        let result = race_optional_timeout(slow_fan_out, timer, RegistrySwitchAllCmd::PATH).await;
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(timeout_for::<RegistryTriggerCheckCmd>(timeout), None);
+    }
+
+    #[tokio::test]
+    async fn test_regular_command_keeps_timeout() {
+        use crate::repository::protocol::IsRegisteredCmd;
+
+        let timeout = Duration::from_millis(5);
+        assert_eq!(timeout_for::<IsRegisteredCmd>(timeout), Some(timeout));
+
+        let slow = std::future::pending::<WatchResult<bool>>();
+        let timer = timeout_for::<IsRegisteredCmd>(timeout).map(tokio::time::sleep);
+        let err = race_optional_timeout(slow, timer, IsRegisteredCmd::PATH)
+            .await
+            .unwrap_err();
+        assert_eq!(err.spans()[0].operation, "rpc.timeout");
+    }
+
+    /// 按顺序返回预设结果的模拟 Stub，并记录调用次数
+    struct FlakyStub {
+        responses: std::cell::RefCell<Vec<Attempt<u32>>>,
+        calls: std::cell::Cell<u32>,
+    }
+
+    impl FlakyStub {
+        fn new(mut responses: Vec<Attempt<u32>>) -> Self {
+            responses.reverse();
+            Self {
+                responses: std::cell::RefCell::new(responses),
+                calls: std::cell::Cell::new(0),
+            }
+        }
+
+        async fn fetch(&self) -> Attempt<u32> {
+            self.calls.set(self.calls.get() + 1);
+            self.responses.borrow_mut().pop().expect("unexpected call")
+        }
+    }
+
+    fn transient() -> Attempt<u32> {
+        Attempt::Transient(WatchError::store("RPC Error [503]: waking up"))
+    }
+
+    #[tokio::test]
+    async fn test_retry_fails_once_then_succeeds() {
+        let stub = FlakyStub::new(vec![transient(), Attempt::Done(Ok(7))]);
+        let result = with_retry(2, || stub.fetch(), || async {}, "/flaky").await;
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(stub.calls.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_after_max_retries() {
+        let stub = FlakyStub::new(vec![transient(), transient(), transient()]);
+        let err = with_retry(2, || stub.fetch(), || async {}, "/down")
+            .await
+            .unwrap_err();
+        assert_eq!(stub.calls.get(), 3);
+        assert_eq!(err.status, WatchErrorStatus::Store);
+        let span = err.spans().last().unwrap();
+        assert_eq!(span.operation, "rpc.retry");
+        assert_eq!(span.detail.as_deref(), Some("/down x2"));
+    }
+
+    #[tokio::test]
+    async fn test_retry_never_retries_business_errors() {
+        let stub = FlakyStub::new(vec![Attempt::Done(Err(WatchError::conflict("paused")))]);
+        let err = with_retry(2, || stub.fetch(), || async {}, "/conflict")
+            .await
+            .unwrap_err();
+        assert_eq!(stub.calls.get(), 1);
+        assert_eq!(err.status, WatchErrorStatus::Conflict);
+    }
+
+    #[tokio::test]
+    async fn test_retry_disabled_with_zero_retries() {
+        let stub = FlakyStub::new(vec![transient()]);
+        assert!(
+            with_retry(0, || stub.fetch(), || async {}, "/once")
+                .await
+                .is_err()
+        );
+        assert_eq!(stub.calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_race_timeout_propagates_inner_error() {
+        let err = race_timeout::<u32, _, _>(
+            async { Err(WatchError::not_found("missing")) },
+            tokio::time::sleep(Duration::from_secs(10)),
+            "/err",
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.status, WatchErrorStatus::NotFound);
+    }
+}
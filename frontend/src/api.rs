@@ -1,34 +1,142 @@
-use crate::web::HttpClient;
+use crate::web::{HttpClient, HttpResponse};
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex, MutexGuard};
 
 use verwatch_shared::{
-    CreateProjectRequest, DeleteTarget, ProjectConfig,
-    protocol::{PopProjectRequest, SwitchMonitorRequest, TriggerCheckRequest},
+    CheckEvent, CreateProjectRequest, DeleteTarget, ProjectConfig, ProjectWithState,
+    protocol::{
+        HealthStatus, PopProjectRequest, SessionInfo, SwitchMonitorRequest, TriggerCheckRequest,
+        UpdateProjectRequest, UpdateProjectResponse,
+    },
 };
 
 // 辅助函数：序列化 JSON
 use crate::serde_helper;
 
+/// 后端在错误响应中设置的 Header，Body 为 JSON 格式的 ErrorResponse
+const RPC_ERROR_HEADER: &str = "X-Rpc-Error";
+
+/// API 调用错误
+///
+/// 需要特殊交互的错误单独成为变体 (对应后端的 `WatchErrorStatus`)，其余按状态码归入 `Http`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    /// 401：密钥无效或已失效，需要重新登录
+    Unauthorized(String),
+    /// 404：资源不存在
+    NotFound(String),
+    /// 409：资源冲突 (如项目已存在)
+    Conflict(String),
+    /// 429：触发限流，需要稍后再试
+    RateLimited(String),
+    /// 其他 HTTP 错误
+    Http { status: u16, message: String },
+    /// 网络或序列化等本地错误
+    Local(String),
+}
+
+impl ApiError {
+    fn local(message: impl ToString) -> Self {
+        ApiError::Local(message.to_string())
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            ApiError::Unauthorized(message)
+            | ApiError::NotFound(message)
+            | ApiError::Conflict(message)
+            | ApiError::RateLimited(message)
+            | ApiError::Http { message, .. }
+            | ApiError::Local(message) => message,
+        }
+    }
+
+    /// 从失败的响应中提取错误信息
+    async fn from_response(res: HttpResponse, context: &str) -> Self {
+        let status = res.status();
+        let is_rpc_error = res.header(RPC_ERROR_HEADER).is_some();
+        let text = res.text().await.unwrap_or_default();
+        let body = from_json::<ErrorBody>(text.trim()).ok();
+        Self::from_parts(status, body, text.trim(), is_rpc_error, context)
+    }
+
+    /// 按状态分类错误
+    ///
+    /// 优先使用后端结构化 ErrorResponse 中的 `status` 与消息，其次使用纯文本 Body，
+    /// 都没有时回退为 "`context`: 状态码"
+    fn from_parts(
+        status: u16,
+        body: Option<ErrorBody>,
+        text: &str,
+        is_rpc_error: bool,
+        context: &str,
+    ) -> Self {
+        let (kind, message) = match body {
+            Some(body) => (body.status, body.message),
+            None if !text.is_empty() && !is_rpc_error => (None, text.to_string()),
+            None => (None, format!("{}: {}", context, status)),
+        };
+        match (kind.as_deref(), status) {
+            (Some("Unauthorized"), _) | (None, 401) => ApiError::Unauthorized(message),
+            (Some("NotFound"), _) | (None, 404) => ApiError::NotFound(message),
+            (Some("Conflict"), _) | (None, 409) => ApiError::Conflict(message),
+            (Some("RateLimited"), _) | (None, 429) => ApiError::RateLimited(message),
+            _ => ApiError::Http { status, message },
+        }
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+/// 后端 ErrorResponse 中前端关心的部分
+#[derive(Deserialize)]
+struct ErrorBody {
+    /// `WatchErrorStatus` 的变体名，如 `Conflict`
+    #[serde(default)]
+    status: Option<String>,
+    message: String,
+}
+
 // 辅助函数：序列化 JSON
-fn to_json<T: Serialize>(value: &T) -> Result<String, String> {
-    serde_helper::to_json_string(value).map_err(|e| e.to_string())
+fn to_json<T: Serialize>(value: &T) -> Result<String, ApiError> {
+    serde_helper::to_json_string(value).map_err(ApiError::local)
 }
 
 // 辅助函数：反序列化 JSON
-fn from_json<T: for<'de> Deserialize<'de>>(text: &str) -> Result<T, String> {
-    serde_helper::from_json_string(text).map_err(|e| e.to_string())
+fn from_json<T: for<'de> Deserialize<'de>>(text: &str) -> Result<T, ApiError> {
+    serde_helper::from_json_string(text).map_err(ApiError::local)
 }
 
-#[derive(Clone, PartialEq)]
+/// 上一次获取到的项目列表及其 ETag
+type ProjectListCache = Option<(String, Vec<ProjectConfig>)>;
+
+#[derive(Clone)]
 pub struct VerWatchApi {
     pub base_url: String,
     pub secret: String,
+    /// 各克隆之间共享，列表未变化 (304) 时直接复用
+    projects_cache: Arc<Mutex<ProjectListCache>>,
+}
+
+// 缓存不影响实例的身份，只比较连接信息
+impl PartialEq for VerWatchApi {
+    fn eq(&self, other: &Self) -> bool {
+        self.base_url == other.base_url && self.secret == other.secret
+    }
 }
 
 impl VerWatchApi {
     pub fn new(base_url: String, secret: String) -> Self {
         let base_url = base_url.trim_end_matches('/').to_string();
-        Self { base_url, secret }
+        Self {
+            base_url,
+            secret,
+            projects_cache: Arc::default(),
+        }
     }
 
     fn url(&self, path: &str) -> String {
@@ -39,25 +147,69 @@ impl VerWatchApi {
         }
     }
 
+    fn cached_projects(&self) -> MutexGuard<'_, ProjectListCache> {
+        // 缓存只在单个函数内短暂持有，不会跨 await，锁中毒时沿用内部数据即可
+        self.projects_cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     /// 获取项目列表
-    pub async fn get_projects(&self) -> Result<Vec<ProjectConfig>, String> {
+    ///
+    /// 携带上一次的 ETag 发起条件请求，后端返回 304 时直接复用缓存，不再解析
+    pub async fn get_projects(&self) -> Result<Vec<ProjectConfig>, ApiError> {
         let url = self.url("/api/projects");
+        let cached_etag = self
+            .cached_projects()
+            .as_ref()
+            .map(|(etag, _)| etag.clone());
+        let mut request = HttpClient::get(&url).header("X-Auth-Key", &self.secret);
+        if let Some(etag) = &cached_etag {
+            request = request.header("If-None-Match", etag);
+        }
+        let res = request.send().await.map_err(ApiError::local)?;
+
+        if res.status() == 304
+            && let Some((_, projects)) = self.cached_projects().as_ref()
+        {
+            return Ok(projects.clone());
+        }
+
+        if !res.ok() {
+            return Err(ApiError::from_response(res, "获取项目失败").await);
+        }
+
+        let etag = res.header("ETag");
+        let text = res.text().await.map_err(ApiError::local)?;
+        let projects: Vec<ProjectConfig> = from_json(&text)?;
+        *self.cached_projects() = etag.map(|etag| (etag, projects.clone()));
+        Ok(projects)
+    }
+
+    /// 获取项目列表及各项目已记录的版本状态
+    ///
+    /// 后端需要逐个查询 Monitor，比 `get_projects` 慢，且不支持条件请求
+    pub async fn get_projects_detailed(&self) -> Result<Vec<ProjectWithState>, ApiError> {
+        let url = self.url("/api/projects/detailed");
         let res = HttpClient::get(&url)
             .header("X-Auth-Key", &self.secret)
             .send()
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(ApiError::local)?;
 
         if !res.ok() {
-            return Err(format!("获取项目失败: {}", res.status()));
+            return Err(ApiError::from_response(res, "获取版本状态失败").await);
         }
 
-        let text = res.text().await.map_err(|e| e.to_string())?;
+        let text = res.text().await.map_err(ApiError::local)?;
         from_json(&text)
     }
 
     /// 添加项目
-    pub async fn add_project(&self, config: CreateProjectRequest) -> Result<ProjectConfig, String> {
+    pub async fn add_project(
+        &self,
+        config: CreateProjectRequest,
+    ) -> Result<ProjectConfig, ApiError> {
         let url = self.url("/api/projects");
         let body = to_json(&config)?;
         let res = HttpClient::post(&url)
@@ -66,18 +218,41 @@ impl VerWatchApi {
             .body(body)
             .send()
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(ApiError::local)?;
+
+        if !res.ok() {
+            return Err(ApiError::from_response(res, "添加项目失败").await);
+        }
+
+        let text = res.text().await.map_err(ApiError::local)?;
+        from_json(&text)
+    }
+
+    /// 更新项目的可变字段
+    pub async fn update_project(
+        &self,
+        req: UpdateProjectRequest,
+    ) -> Result<UpdateProjectResponse, ApiError> {
+        let url = self.url("/api/projects");
+        let body = to_json(&req)?;
+        let res = HttpClient::put(&url)
+            .header("X-Auth-Key", &self.secret)
+            .header("Content-Type", "application/json")
+            .body(body)
+            .send()
+            .await
+            .map_err(ApiError::local)?;
 
         if !res.ok() {
-            return Err(format!("添加项目失败: {}", res.status()));
+            return Err(ApiError::from_response(res, "更新项目失败").await);
         }
 
-        let text = res.text().await.map_err(|e| e.to_string())?;
+        let text = res.text().await.map_err(ApiError::local)?;
         from_json(&text)
     }
 
     /// 删除项目
-    pub async fn delete_project(&self, id: String) -> Result<bool, String> {
+    pub async fn delete_project(&self, id: String) -> Result<bool, ApiError> {
         let url = self.url("/api/projects");
         let target = DeleteTarget { id };
         let body = to_json(&target)?;
@@ -87,18 +262,88 @@ impl VerWatchApi {
             .body(body)
             .send()
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(ApiError::local)?;
 
         match res.status() {
             204 => Ok(true),
             404 => Ok(false),
-            _ => Err(format!("删除项目失败: {}", res.status())),
+            _ => Err(ApiError::from_response(res, "删除项目失败").await),
+        }
+    }
+
+    /// 测试连接：携带密钥请求健康检查，后端不可达或密钥错误时返回错误
+    pub async fn ping(&self) -> Result<HealthStatus, ApiError> {
+        let url = self.url("/api/health");
+        let res = HttpClient::get(&url)
+            .header("X-Auth-Key", &self.secret)
+            .send()
+            .await
+            .map_err(ApiError::local)?;
+
+        if !res.ok() {
+            return Err(ApiError::from_response(res, "连接测试失败").await);
+        }
+
+        let text = res.text().await.map_err(ApiError::local)?;
+        from_json(&text)
+    }
+
+    /// 查询当前密钥对应的角色 (Admin 或只读 Viewer)
+    pub async fn whoami(&self) -> Result<SessionInfo, ApiError> {
+        let url = self.url("/api/whoami");
+        let res = HttpClient::get(&url)
+            .header("X-Auth-Key", &self.secret)
+            .send()
+            .await
+            .map_err(ApiError::local)?;
+
+        if !res.ok() {
+            return Err(ApiError::from_response(res, "获取角色失败").await);
+        }
+
+        let text = res.text().await.map_err(ApiError::local)?;
+        from_json(&text)
+    }
+
+    /// 按 unique_key 获取单个项目
+    pub async fn get_project_one(&self, id: &str) -> Result<ProjectConfig, ApiError> {
+        let id = js_sys::encode_uri_component(id);
+        let url = self.url(&format!("/api/projects/one?id={}", id));
+        let res = HttpClient::get(&url)
+            .header("X-Auth-Key", &self.secret)
+            .send()
+            .await
+            .map_err(ApiError::local)?;
+
+        if !res.ok() {
+            return Err(ApiError::from_response(res, "获取项目失败").await);
         }
+
+        let text = res.text().await.map_err(ApiError::local)?;
+        from_json(&text)
+    }
+
+    /// 获取项目最近的检查历史 (最新的在最后)
+    pub async fn get_history(&self, unique_key: &str) -> Result<Vec<CheckEvent>, ApiError> {
+        let unique_key = js_sys::encode_uri_component(unique_key);
+        let url = self.url(&format!("/api/projects/history?unique_key={}", unique_key));
+        let res = HttpClient::get(&url)
+            .header("X-Auth-Key", &self.secret)
+            .send()
+            .await
+            .map_err(ApiError::local)?;
+
+        if !res.ok() {
+            return Err(ApiError::from_response(res, "获取检查历史失败").await);
+        }
+
+        let text = res.text().await.map_err(ApiError::local)?;
+        from_json(&text)
     }
 
     // 弹出项目（删除并返回）
     #[allow(dead_code)]
-    pub async fn pop_project(&self, id: String) -> Result<Option<ProjectConfig>, String> {
+    pub async fn pop_project(&self, id: String) -> Result<Option<ProjectConfig>, ApiError> {
         let url = self.url("/api/projects/pop");
         let target = PopProjectRequest { id };
         let body = to_json(&target)?;
@@ -108,18 +353,18 @@ impl VerWatchApi {
             .body(body)
             .send()
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(ApiError::local)?;
 
         if !res.ok() {
-            return Err(format!("弹出项目失败: {}", res.status()));
+            return Err(ApiError::from_response(res, "弹出项目失败").await);
         }
 
-        let text = res.text().await.map_err(|e| e.to_string())?;
+        let text = res.text().await.map_err(ApiError::local)?;
         from_json(&text)
     }
 
     /// 切换监控状态 (Start/Stop)
-    pub async fn switch_monitor(&self, unique_key: String, paused: bool) -> Result<bool, String> {
+    pub async fn switch_monitor(&self, unique_key: String, paused: bool) -> Result<bool, ApiError> {
         let url = self.url("/api/projects/switch");
         let payload = SwitchMonitorRequest { unique_key, paused };
         let body = to_json(&payload)?;
@@ -129,18 +374,18 @@ impl VerWatchApi {
             .body(body)
             .send()
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(ApiError::local)?;
 
         if !res.ok() {
-            return Err(format!("切换状态失败: {}", res.status()));
+            return Err(ApiError::from_response(res, "切换状态失败").await);
         }
 
-        let text = res.text().await.map_err(|e| e.to_string())?;
+        let text = res.text().await.map_err(ApiError::local)?;
         from_json(&text)
     }
 
     /// 触发立即检查
-    pub async fn trigger_check(&self, unique_key: String) -> Result<(), String> {
+    pub async fn trigger_check(&self, unique_key: String) -> Result<(), ApiError> {
         let url = self.url("/api/projects/trigger");
         let payload = TriggerCheckRequest { unique_key };
         let body = to_json(&payload)?;
@@ -150,12 +395,81 @@ impl VerWatchApi {
             .body(body)
             .send()
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(ApiError::local)?;
 
         if !res.ok() {
-            return Err(format!("触发检查失败: {}", res.status()));
+            return Err(ApiError::from_response(res, "触发检查失败").await);
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(status: u16, text: &str) -> ApiError {
+        let body = serde_json::from_str::<ErrorBody>(text).ok();
+        ApiError::from_parts(status, body, text, false, "请求失败")
+    }
+
+    #[test]
+    fn test_error_response_status_maps_to_variant() {
+        let cases = [
+            (
+                401,
+                "Unauthorized",
+                ApiError::Unauthorized("bad key".into()),
+            ),
+            (404, "NotFound", ApiError::NotFound("bad key".into())),
+            (409, "Conflict", ApiError::Conflict("bad key".into())),
+            (429, "RateLimited", ApiError::RateLimited("bad key".into())),
+            (
+                400,
+                "InvalidInput",
+                ApiError::Http {
+                    status: 400,
+                    message: "bad key".into(),
+                },
+            ),
+        ];
+        for (status, kind, expected) in cases {
+            let text = format!(
+                r#"{{"status":"{}","message":"bad key","spans":[{{"operation":"auth"}}]}}"#,
+                kind
+            );
+            assert_eq!(parse(status, &text), expected);
+        }
+    }
+
+    #[test]
+    fn test_body_status_takes_precedence_over_http_status() {
+        // 后端错误响应的 Body 带有状态，以其为准
+        let err = parse(500, r#"{"status":"Conflict","message":"exists"}"#);
+        assert_eq!(err, ApiError::Conflict("exists".into()));
+    }
+
+    #[test]
+    fn test_plain_text_and_empty_bodies_fall_back_to_http_status() {
+        assert_eq!(
+            parse(429, "Too Many Requests"),
+            ApiError::RateLimited("Too Many Requests".into())
+        );
+        assert_eq!(
+            parse(401, ""),
+            ApiError::Unauthorized("请求失败: 401".into())
+        );
+        assert_eq!(
+            parse(502, ""),
+            ApiError::Http {
+                status: 502,
+                message: "请求失败: 502".into(),
+            }
+        );
+
+        // RPC 错误的非 JSON Body 不直接展示
+        let err = ApiError::from_parts(500, None, "<html>", true, "请求失败");
+        assert_eq!(err.message(), "请求失败: 500");
+    }
+}
@@ -4,10 +4,14 @@
 //! 路由服务通过注入的认证信号来检查认证状态。
 
 use crate::api::VerWatchApi;
-use crate::web::LocalStorage;
+use crate::web::{LocalStorage, push_recent};
 use leptos::prelude::*;
+use verwatch_shared::protocol::Role;
 
 const STORAGE_URL_KEY: &str = "verwatch_url";
+const STORAGE_URL_HISTORY_KEY: &str = "verwatch_url_history";
+/// 最多保留的历史后端 URL 数量
+const URL_HISTORY_CAP: usize = 5;
 
 /// 认证状态
 #[derive(Clone, Default)]
@@ -16,10 +20,14 @@ pub struct AuthState {
     pub api: Option<VerWatchApi>,
     /// 是否已认证
     pub is_authenticated: bool,
+    /// 当前密钥的角色 (登录时查询，未知时按只读处理)
+    pub role: Role,
     /// 是否正在加载
     pub is_loading: bool,
     /// 后端 URL（用于 UI 显示和自动填充）
     pub backend_url: String,
+    /// 最近使用过的后端 URL（最新的在前，不含密钥）
+    pub recent_urls: Vec<String>,
 }
 
 /// 认证上下文
@@ -62,6 +70,7 @@ pub fn init_auth(ctx: &AuthContext) {
         if let Some(url) = LocalStorage::get(STORAGE_URL_KEY) {
             state.backend_url = url;
         }
+        state.recent_urls = LocalStorage::get_json(STORAGE_URL_HISTORY_KEY).unwrap_or_default();
     });
 }
 
@@ -79,9 +88,17 @@ pub async fn login(ctx: &AuthContext, url: String, secret: String) -> bool {
 
     // 验证凭据是否有效
     if api.get_projects().await.is_ok() {
+        // 查询失败 (如旧版后端没有该接口) 时按只读处理
+        let role = api.whoami().await.map(|s| s.role).unwrap_or_default();
+
         // 成功：只保存 URL 到 LocalStorage 以便下次自动填充，但不保存 Secret
         LocalStorage::set(STORAGE_URL_KEY, &url);
 
+        let mut recent_urls: Vec<String> =
+            LocalStorage::get_json(STORAGE_URL_HISTORY_KEY).unwrap_or_default();
+        push_recent(&mut recent_urls, &url, URL_HISTORY_CAP);
+        LocalStorage::set_json(STORAGE_URL_HISTORY_KEY, &recent_urls);
+
         // 确保清除旧的 Secret (如果存在)
         LocalStorage::delete("verwatch_secret");
 
@@ -89,7 +106,9 @@ pub async fn login(ctx: &AuthContext, url: String, secret: String) -> bool {
         ctx.set_state.update(|state| {
             state.api = Some(api);
             state.backend_url = url;
+            state.recent_urls = recent_urls;
             state.is_authenticated = true;
+            state.role = role;
         });
         true
     } else {
@@ -104,6 +123,7 @@ pub fn logout(ctx: &AuthContext) {
     ctx.set_state.update(|state| {
         state.api = None;
         state.is_authenticated = false;
+        state.role = Role::default();
         // 保留 URL 方便下次登录
     });
     // 注意：不需要手动导航，路由服务会监听认证状态变化并自动重定向
@@ -16,6 +16,7 @@ use basic_info_form::BasicInfoForm;
 use form_state::FormState;
 use time_config_section::TimeConfigSection;
 
+use crate::api::ApiError;
 use crate::components::icons::Plus;
 use leptos::prelude::*;
 use verwatch_shared::CreateProjectRequest;
@@ -26,8 +27,13 @@ use verwatch_shared::CreateProjectRequest;
 /// - 模态框的开关控制
 /// - 协调子组件
 /// - 处理表单提交
+///
+/// `on_add` 收到请求与完成回调：成功后关闭并清空表单；项目已存在 (409) 时保持打开并标出重复的仓库；
+/// 其他错误关闭对话框以显示通知，保留已填写的内容
 #[component]
-pub fn AddProjectDialog(#[prop(into)] on_add: Callback<CreateProjectRequest>) -> impl IntoView {
+pub fn AddProjectDialog(
+    #[prop(into)] on_add: Callback<(CreateProjectRequest, Callback<Result<(), ApiError>>)>,
+) -> impl IntoView {
     // 模态框状态
     let (open, set_open) = signal(false);
     let (loading, set_loading) = signal(false);
@@ -54,14 +60,24 @@ pub fn AddProjectDialog(#[prop(into)] on_add: Callback<CreateProjectRequest>) ->
     // 提交处理（简化，逻辑移到了 FormState::to_request）
     let on_submit = move |ev: leptos::web_sys::SubmitEvent| {
         ev.prevent_default();
-        set_loading.set(true);
-
-        let req = form_state.to_request();
-        on_add.run(req);
 
-        set_open.set(false);
-        set_loading.set(false);
-        form_state.reset();
+        // 校验失败时保持对话框打开，错误显示在对应字段下方
+        let Some(req) = form_state.validated_request() else {
+            return;
+        };
+        set_loading.set(true);
+        let done = Callback::new(move |result: Result<(), ApiError>| {
+            set_loading.set(false);
+            match result {
+                Ok(()) => {
+                    set_open.set(false);
+                    form_state.reset();
+                }
+                Err(ApiError::Conflict(message)) => form_state.mark_duplicate(&message),
+                Err(_) => set_open.set(false),
+            }
+        });
+        on_add.run((req, done));
     };
 
     view! {
@@ -8,6 +8,20 @@ use verwatch_shared::ComparisonMode;
 
 use super::form_state::FormState;
 
+/// 字段下方的行内校验错误，无错误时不渲染
+#[component]
+pub fn FieldError(state: FormState, field: &'static str) -> impl IntoView {
+    move || {
+        state.field_error(field).map(|message| {
+            view! {
+                <label class="label">
+                    <span class="label-text-alt text-error">{message}</span>
+                </label>
+            }
+        })
+    }
+}
+
 /// 基础信息表单组件
 ///
 /// 显示上游/本地仓库配置、比对模式和 Token 密钥输入。
@@ -26,7 +40,9 @@ pub fn BasicInfoForm(state: FormState) -> impl IntoView {
                     on:input=move |ev| state.u_owner.set(event_target_value(&ev))
                     prop:value=move || state.u_owner.get()
                     class="input input-bordered w-full"
+                    class:input-error=move || state.field_error("upstream_owner").is_some()
                 />
+                <FieldError state=state field="upstream_owner" />
             </div>
             <div class="form-control">
                 <label for="u_repo" class="label">
@@ -38,7 +54,9 @@ pub fn BasicInfoForm(state: FormState) -> impl IntoView {
                     on:input=move |ev| state.u_repo.set(event_target_value(&ev))
                     prop:value=move || state.u_repo.get()
                     class="input input-bordered w-full"
+                    class:input-error=move || state.field_error("upstream_repo").is_some()
                 />
+                <FieldError state=state field="upstream_repo" />
             </div>
         </div>
 
@@ -54,7 +72,9 @@ pub fn BasicInfoForm(state: FormState) -> impl IntoView {
                     on:input=move |ev| state.m_owner.set(event_target_value(&ev))
                     prop:value=move || state.m_owner.get()
                     class="input input-bordered w-full"
+                    class:input-error=move || state.field_error("my_owner").is_some()
                 />
+                <FieldError state=state field="my_owner" />
             </div>
             <div class="form-control">
                 <label for="m_repo" class="label">
@@ -66,7 +86,9 @@ pub fn BasicInfoForm(state: FormState) -> impl IntoView {
                     on:input=move |ev| state.m_repo.set(event_target_value(&ev))
                     prop:value=move || state.m_repo.get()
                     class="input input-bordered w-full"
+                    class:input-error=move || state.field_error("my_repo").is_some()
                 />
+                <FieldError state=state field="my_repo" />
             </div>
         </div>
 
@@ -79,11 +101,12 @@ pub fn BasicInfoForm(state: FormState) -> impl IntoView {
                 class="select select-bordered w-full"
                 on:change=move |ev| {
                     let val = event_target_value(&ev);
-                    if val == "updated_at" {
-                        state.comp_mode.set(ComparisonMode::UpdatedAt);
-                    } else {
-                        state.comp_mode.set(ComparisonMode::PublishedAt);
-                    }
+                    let mode = match val.as_str() {
+                        "updated_at" => ComparisonMode::UpdatedAt,
+                        "created_at" => ComparisonMode::CreatedAt,
+                        _ => ComparisonMode::PublishedAt,
+                    };
+                    state.comp_mode.set(mode);
                 }
             >
                 <option
@@ -98,6 +121,12 @@ pub fn BasicInfoForm(state: FormState) -> impl IntoView {
                 >
                     "更新时间"
                 </option>
+                <option
+                    value="created_at"
+                    selected=move || state.comp_mode.get() == ComparisonMode::CreatedAt
+                >
+                    "创建时间 (不受编辑影响)"
+                </option>
             </select>
         </div>
 
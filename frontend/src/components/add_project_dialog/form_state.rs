@@ -4,10 +4,20 @@
 //! - 数据的持有
 //! - 数据的重置
 //! - 数据到请求对象的转换
+//! - 提交前的校验与错误展示
 
 use leptos::prelude::*;
+use verwatch_shared::validate::{ValidationError, validate_create_request};
 use verwatch_shared::{BaseConfig, ComparisonMode, CreateProjectRequest, DurationSecs, TimeConfig};
 
+/// 检查间隔预设：(显示名称, 数值, 单位)
+pub const INTERVAL_PRESETS: &[(&str, u64, &str)] = &[
+    ("每15分钟", 15, "minutes"),
+    ("每小时", 1, "hours"),
+    ("每6小时", 6, "hours"),
+    ("每天", 24, "hours"),
+];
+
 /// 表单状态结构体
 ///
 /// 使用 `RwSignal` 因为它实现了 `Copy` trait，非常适合作为 Props 在组件间传递。
@@ -25,7 +35,12 @@ pub struct FormState {
     pub use_custom_time: RwSignal<bool>,
     pub check_interval_val: RwSignal<u64>,
     pub check_interval_unit: RwSignal<String>,
+    /// 是否手动输入检查间隔 (否则使用预设)
+    pub custom_interval: RwSignal<bool>,
     pub retry_interval_seconds: RwSignal<u64>,
+
+    // 上一次提交时的校验错误 (再次提交时整体替换，`reset` 时清空)
+    pub errors: RwSignal<Vec<ValidationError>>,
 }
 
 impl FormState {
@@ -41,11 +56,16 @@ impl FormState {
             use_custom_time: RwSignal::new(false),
             check_interval_val: RwSignal::new(1),
             check_interval_unit: RwSignal::new("hours".to_string()),
+            custom_interval: RwSignal::new(false),
             retry_interval_seconds: RwSignal::new(10),
+            errors: RwSignal::new(Vec::new()),
         }
     }
 
     /// 重置表单到初始状态
+    ///
+    /// 校验错误也一并清空。只在提交成功后调用：取消或关闭对话框不会重置，
+    /// 重新打开时保留已填写的内容和上一次的错误提示，直到再次提交
     pub fn reset(&self) {
         self.u_owner.set(String::new());
         self.u_repo.set(String::new());
@@ -56,7 +76,75 @@ impl FormState {
         self.use_custom_time.set(false);
         self.check_interval_val.set(1);
         self.check_interval_unit.set("hours".to_string());
+        self.custom_interval.set(false);
         self.retry_interval_seconds.set(10);
+        self.errors.set(Vec::new());
+    }
+
+    /// 应用检查间隔预设，并退出手动输入
+    pub fn apply_interval_preset(&self, index: usize) {
+        if let Some(&(_, value, unit)) = INTERVAL_PRESETS.get(index) {
+            self.check_interval_val.set(value);
+            self.check_interval_unit.set(unit.to_string());
+            self.custom_interval.set(false);
+        }
+    }
+
+    /// 切换到手动输入，保留当前 (上一次预设的) 数值与单位
+    pub fn use_custom_interval(&self) {
+        self.custom_interval.set(true);
+    }
+
+    /// 当前选中的预设下标，手动输入时为 `None`
+    pub fn selected_interval_preset(&self) -> Option<usize> {
+        if self.custom_interval.get() {
+            return None;
+        }
+        let value = self.check_interval_val.get();
+        let unit = self.check_interval_unit.get();
+        INTERVAL_PRESETS
+            .iter()
+            .position(|&(_, v, u)| v == value && u == unit)
+    }
+
+    /// 转换并校验请求，校验失败时记录错误并返回 `None`
+    pub fn validated_request(&self) -> Option<CreateProjectRequest> {
+        let req = self.to_request();
+        match validate_create_request(&req) {
+            Ok(()) => {
+                self.errors.set(Vec::new());
+                Some(req)
+            }
+            Err(errors) => {
+                self.errors.set(errors);
+                None
+            }
+        }
+    }
+
+    /// 后端报告项目已存在时，在上游与目标仓库字段下标出重复
+    pub fn mark_duplicate(&self, message: &str) {
+        self.errors.set(
+            ["upstream_repo", "my_repo"]
+                .into_iter()
+                .map(|field| ValidationError {
+                    field,
+                    message: message.to_string(),
+                })
+                .collect(),
+        );
+    }
+
+    /// 指定字段的校验错误信息 (多条以分号连接)
+    pub fn field_error(&self, field: &str) -> Option<String> {
+        self.errors.with(|errors| {
+            let messages: Vec<&str> = errors
+                .iter()
+                .filter(|e| e.field == field)
+                .map(|e| e.message.as_str())
+                .collect();
+            (!messages.is_empty()).then(|| messages.join("; "))
+        })
     }
 
     /// 将表单状态转换为 API 请求对象
@@ -69,14 +157,16 @@ impl FormState {
         };
 
         let time_config = if self.use_custom_time.get() {
-            let multiplier = if self.check_interval_unit.get() == "minutes" {
-                60
+            let value = self.check_interval_val.get();
+            let check_interval = if self.check_interval_unit.get() == "minutes" {
+                DurationSecs::from_minutes(value)
             } else {
-                3600
+                DurationSecs::from_hours(value)
             };
             TimeConfig {
-                check_interval: DurationSecs::from_secs(self.check_interval_val.get() * multiplier),
+                check_interval,
                 retry_interval: DurationSecs::from_secs(self.retry_interval_seconds.get()),
+                ..TimeConfig::default()
             }
         } else {
             TimeConfig::default()
@@ -93,6 +183,7 @@ impl FormState {
             initial_delay: DurationSecs::from_secs(0),
             comparison_mode: self.comp_mode.get(),
             dispatch_token_secret: secret_opt,
+            ..Default::default()
         }
     }
 }
@@ -102,3 +193,57 @@ impl Default for FormState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validation_errors_until_reset() {
+        let state = FormState::new();
+        state.u_owner.set("owner".into());
+        assert!(state.validated_request().is_none());
+        assert!(state.field_error("upstream_owner").is_none());
+        assert!(state.field_error("upstream_repo").is_some());
+
+        state.reset();
+        assert!(state.errors.with(Vec::is_empty));
+
+        for field in [state.u_owner, state.u_repo, state.m_owner, state.m_repo] {
+            field.set("x".into());
+        }
+        state.m_repo.set("y".into());
+        assert!(state.validated_request().is_some());
+        assert!(state.errors.with(Vec::is_empty));
+    }
+
+    #[test]
+    fn test_custom_interval_keeps_last_preset() {
+        let state = FormState::new();
+        state.use_custom_time.set(true);
+        assert_eq!(state.selected_interval_preset(), Some(1));
+
+        state.apply_interval_preset(0);
+        assert_eq!(state.selected_interval_preset(), Some(0));
+        assert_eq!(
+            state.to_request().time_config.check_interval,
+            DurationSecs::from_minutes(15)
+        );
+
+        // 切换到自定义后沿用上一次预设的数值
+        state.use_custom_interval();
+        assert_eq!(state.selected_interval_preset(), None);
+        assert_eq!(state.check_interval_val.get(), 15);
+        assert_eq!(state.check_interval_unit.get(), "minutes");
+        assert_eq!(
+            state.to_request().time_config.check_interval,
+            DurationSecs::from_minutes(15)
+        );
+
+        state.apply_interval_preset(3);
+        assert_eq!(
+            state.to_request().time_config.check_interval,
+            DurationSecs::from_days(1)
+        );
+    }
+}
@@ -1,15 +1,16 @@
 //! 时间配置表单组件
 //!
 //! 负责处理与时间配置相关的特定 UI 逻辑，
-//! 包括条件渲染和单位选择（小时/分钟）。
+//! 包括条件渲染、检查间隔预设和单位选择（小时/分钟）。
 
 use leptos::prelude::*;
 
-use super::form_state::FormState;
+use super::basic_info_form::FieldError;
+use super::form_state::{FormState, INTERVAL_PRESETS};
 
 /// 时间配置表单组件
 ///
-/// 显示自定义时间配置开关，以及检查间隔和重试间隔的输入。
+/// 显示自定义时间配置开关，以及检查间隔 (预设或自定义) 和重试间隔的输入。
 #[component]
 pub fn TimeConfigSection(state: FormState) -> impl IntoView {
     view! {
@@ -28,38 +29,70 @@ pub fn TimeConfigSection(state: FormState) -> impl IntoView {
         {move || if state.use_custom_time.get() {
             view! {
                 <div class="grid grid-cols-2 gap-4 bg-base-200 p-4 rounded-lg">
-                    // 检查间隔输入
+                    // 检查间隔：预设或手动输入
                     <div class="form-control">
                         <label class="label">
                             <span class="label-text">"检查间隔"</span>
                         </label>
-                        <div class="join">
-                            <input type="number" min="1" required
-                                class="input input-bordered join-item w-full"
-                                prop:value=move || state.check_interval_val.get()
-                                on:input=move |ev| {
-                                    if let Ok(val) = event_target_value(&ev).parse::<u64>() {
-                                        state.check_interval_val.set(val);
-                                    }
+                        <select class="select select-bordered w-full"
+                            on:change=move |ev| {
+                                match event_target_value(&ev).parse::<usize>() {
+                                    Ok(index) => state.apply_interval_preset(index),
+                                    Err(_) => state.use_custom_interval(),
                                 }
-                            />
-                            <select class="select select-bordered join-item"
-                                on:change=move |ev| state.check_interval_unit.set(event_target_value(&ev))
+                            }
+                        >
+                            {INTERVAL_PRESETS
+                                .iter()
+                                .enumerate()
+                                .map(|(index, &(label, _, _))| {
+                                    view! {
+                                        <option
+                                            value=index.to_string()
+                                            selected=move || state.selected_interval_preset() == Some(index)
+                                        >
+                                            {label}
+                                        </option>
+                                    }
+                                })
+                                .collect_view()}
+                            <option
+                                value="custom"
+                                selected=move || state.selected_interval_preset().is_none()
                             >
-                                <option
-                                    value="hours"
-                                    selected=move || state.check_interval_unit.get() == "hours"
-                                >
-                                    "小时"
-                                </option>
-                                <option
-                                    value="minutes"
-                                    selected=move || state.check_interval_unit.get() == "minutes"
+                                "自定义"
+                            </option>
+                        </select>
+                        <Show when=move || state.selected_interval_preset().is_none()>
+                            <div class="join mt-2">
+                                <input type="number" min="1" required
+                                    class="input input-bordered join-item w-full"
+                                    prop:value=move || state.check_interval_val.get()
+                                    on:input=move |ev| {
+                                        if let Ok(val) = event_target_value(&ev).parse::<u64>() {
+                                            state.check_interval_val.set(val);
+                                        }
+                                    }
+                                />
+                                <select class="select select-bordered join-item"
+                                    on:change=move |ev| state.check_interval_unit.set(event_target_value(&ev))
                                 >
-                                    "分钟"
-                                </option>
-                            </select>
-                        </div>
+                                    <option
+                                        value="hours"
+                                        selected=move || state.check_interval_unit.get() == "hours"
+                                    >
+                                        "小时"
+                                    </option>
+                                    <option
+                                        value="minutes"
+                                        selected=move || state.check_interval_unit.get() == "minutes"
+                                    >
+                                        "分钟"
+                                    </option>
+                                </select>
+                            </div>
+                        </Show>
+                        <FieldError state=state field="check_interval" />
                     </div>
                     // 重试间隔输入
                     <div class="form-control">
@@ -75,6 +108,7 @@ pub fn TimeConfigSection(state: FormState) -> impl IntoView {
                                 }
                             }
                         />
+                        <FieldError state=state field="retry_interval" />
                     </div>
                 </div>
             }.into_any()
@@ -1,43 +1,82 @@
-use crate::api::VerWatchApi;
-use crate::auth::{logout, use_auth};
+use crate::api::{ApiError, VerWatchApi};
+use crate::auth::{AuthContext, logout, use_auth};
 use crate::components::add_project_dialog::AddProjectDialog;
+use crate::components::delete_confirm_dialog::{DeleteConfirmDialog, DeleteTarget};
 use crate::components::icons::*;
 use crate::web::Interval;
+use crate::web::route::AppRoute;
+use crate::web::router::Link;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
-use verwatch_shared::{CreateProjectRequest, Date, MonitorState, ProjectConfig};
-use wasm_bindgen::prelude::*;
-
-// JS 格式化函数绑定 (定义在 index.html)
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_name = formatCountdown)]
-    fn format_countdown(secs: f64) -> String;
-}
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use verwatch_shared::{
+    CreateProjectRequest, Date, DurationSecs, MonitorState, ProjectConfig, ProjectFilter,
+    Timestamp,
+    protocol::{Role, UpdateProjectRequest},
+};
 
 // --- Logic Layer: Dashboard Store ---
 
 #[derive(Clone)]
 pub struct DashboardStore {
     pub projects: Signal<Vec<ProjectConfig>>,
+    /// 各项目已记录的版本 (unique_key -> Tag)，尚未记录版本的项目不在其中
+    pub versions: Signal<HashMap<String, String>>,
     pub loading: Signal<bool>,
     pub tick: Signal<u64>,
     pub notification: Signal<Option<(String, bool)>>,
+    /// 当前密钥是否为 Admin；只读 (Viewer) 或角色未知时隐藏所有变更操作
+    pub is_admin: Signal<bool>,
     // Actions
     pub refresh: Callback<()>,
-    pub add_project: Callback<CreateProjectRequest>,
+    /// 添加项目 (请求, 完成回调)
+    pub add_project: Callback<(CreateProjectRequest, Callback<Result<(), ApiError>>)>,
     pub delete_project: Callback<String>,
     pub switch_monitor: Callback<(String, bool)>,
     pub trigger_check: Callback<String>,
+    /// 修改检查间隔 (unique_key, 新间隔)
+    pub update_project: Callback<(String, DurationSecs)>,
 }
 
 // --- API Action Runner: 消除重复的 API 调用逻辑 ---
 
+/// 触发限流后暂停发起变更操作的时长
+const RATE_LIMIT_COOLDOWN: DurationSecs = DurationSecs::from_secs(10);
+
+/// 错误通知的文案
+fn error_notice(error_prefix: &str, e: &ApiError) -> String {
+    match e {
+        ApiError::Unauthorized(_) => "密钥已失效，请重新登录".to_string(),
+        ApiError::RateLimited(_) => format!(
+            "{}: 请求过于频繁，请 {} 后再试",
+            error_prefix,
+            RATE_LIMIT_COOLDOWN.humanize()
+        ),
+        _ => format!("{}: {}", error_prefix, e),
+    }
+}
+
+/// 显示错误通知；密钥失效时注销，由路由跳转回登录页
+fn report_api_error(
+    auth: AuthContext,
+    set_notification: WriteSignal<Option<(String, bool)>>,
+    error_prefix: &str,
+    e: &ApiError,
+) {
+    if matches!(e, ApiError::Unauthorized(_)) {
+        logout(&auth);
+    }
+    set_notification.set(Some((error_notice(error_prefix, e), true)));
+}
+
 #[derive(Clone, Copy)]
 struct ApiActionRunner {
-    auth_state: ReadSignal<crate::auth::AuthState>,
+    auth: AuthContext,
     set_notification: WriteSignal<Option<(String, bool)>>,
     load_projects: Callback<()>,
+    /// 限流冷却的结束时间，期间不再发起请求
+    cooldown_until: StoredValue<Option<Timestamp>>,
 }
 
 impl ApiActionRunner {
@@ -49,25 +88,139 @@ impl ApiActionRunner {
         error_prefix: &'static str,
     ) where
         F: FnOnce(VerWatchApi) -> Fut + 'static,
-        Fut: std::future::Future<Output = Result<T, String>> + 'static,
+        Fut: std::future::Future<Output = Result<T, ApiError>> + 'static,
         T: 'static,
     {
-        if let Some(api) = self.auth_state.get().api.clone() {
-            let set_notification = self.set_notification;
-            let load_projects = self.load_projects;
-            spawn_local(async move {
-                match api_call(api).await {
-                    Ok(result) => {
-                        set_notification.set(Some((on_success(result), false)));
-                        load_projects.run(());
-                    }
-                    Err(e) => {
-                        set_notification.set(Some((format!("{}: {}", error_prefix, e), true)))
+        self.run_with(api_call, on_success, error_prefix, |_| ());
+    }
+
+    /// 同 `run`，失败时额外调用 `on_error` 以回滚乐观更新
+    fn run_or_revert<T, F, Fut>(
+        self,
+        api_call: F,
+        on_success: impl FnOnce(T) -> String + 'static,
+        error_prefix: &'static str,
+        on_error: impl FnOnce() + 'static,
+    ) where
+        F: FnOnce(VerWatchApi) -> Fut + 'static,
+        Fut: std::future::Future<Output = Result<T, ApiError>> + 'static,
+        T: 'static,
+    {
+        self.run_with(api_call, on_success, error_prefix, |result| {
+            if result.is_err() {
+                on_error();
+            }
+        });
+    }
+
+    /// 同 `run`，结束后 (含限流冷却中未发起请求) 以结果调用 `on_done`
+    fn run_with<T, F, Fut>(
+        self,
+        api_call: F,
+        on_success: impl FnOnce(T) -> String + 'static,
+        error_prefix: &'static str,
+        on_done: impl FnOnce(Result<(), &ApiError>) + 'static,
+    ) where
+        F: FnOnce(VerWatchApi) -> Fut + 'static,
+        Fut: std::future::Future<Output = Result<T, ApiError>> + 'static,
+        T: 'static,
+    {
+        let Some(api) = self.auth.state.get().api.clone() else {
+            return;
+        };
+
+        let now = Date::now_timestamp();
+        if let Some(until) = self.cooldown_until.get_value() {
+            let remaining = until.signed_diff_secs(now);
+            if remaining > 0 {
+                let message = format!(
+                    "{}: 操作过于频繁，请 {} 后再试",
+                    error_prefix,
+                    DurationSecs::from_secs(remaining as u64).humanize()
+                );
+                self.set_notification.set(Some((message.clone(), true)));
+                on_done(Err(&ApiError::RateLimited(message)));
+                return;
+            }
+        }
+
+        spawn_local(async move {
+            match api_call(api).await {
+                Ok(result) => {
+                    self.set_notification.set(Some((on_success(result), false)));
+                    self.load_projects.run(());
+                    on_done(Ok(()));
+                }
+                Err(e) => {
+                    if matches!(e, ApiError::RateLimited(_)) {
+                        self.cooldown_until
+                            .set_value(Some(Date::now_timestamp() + RATE_LIMIT_COOLDOWN));
                     }
+                    on_done(Err(&e));
+                    report_api_error(self.auth, self.set_notification, error_prefix, &e);
                 }
-            });
+            }
+        });
+    }
+}
+
+/// 检查时间已过但仍在此时长内时显示为“检查中”，超过后显示逾期时长
+const OVERDUE_GRACE_SECS: i64 = 60;
+
+/// 倒计时文本；`delta_secs` 为距下一次检查的带符号秒数，逾期时为负数
+fn countdown_label(delta_secs: i64) -> String {
+    if delta_secs > 0 {
+        DurationSecs::from_secs(delta_secs as u64).humanize()
+    } else if delta_secs > -OVERDUE_GRACE_SECS {
+        "检查中…".to_string()
+    } else {
+        format!(
+            "逾期 {}",
+            DurationSecs::from_secs(delta_secs.unsigned_abs()).humanize()
+        )
+    }
+}
+
+/// 到期触发刷新的首次退避间隔与上限
+const REFRESH_BACKOFF_INITIAL: DurationSecs = DurationSecs::from_secs(2);
+const REFRESH_BACKOFF_MAX: DurationSecs = DurationSecs::from_secs(60);
+
+/// 到期触发刷新的指数退避
+///
+/// 后端执行检查较慢时，项目在刷新后可能仍处于到期状态；退避避免每秒重复刷新。
+/// 每次到期触发的刷新都会使下一次的等待翻倍 (不超过上限)，刷新结果中的调度状态变化时重置。
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RefreshBackoff {
+    next_allowed_at: Timestamp,
+    delay: DurationSecs,
+}
+
+impl RefreshBackoff {
+    fn new() -> Self {
+        Self {
+            next_allowed_at: Timestamp::new(0),
+            delay: REFRESH_BACKOFF_INITIAL,
         }
     }
+
+    fn ready(&self, now: Timestamp) -> bool {
+        now >= self.next_allowed_at
+    }
+
+    /// 记录一次到期触发的刷新，并将下一次的等待翻倍
+    fn record(&mut self, now: Timestamp) {
+        self.next_allowed_at = now + self.delay;
+        self.delay = DurationSecs::from_secs(self.delay.as_secs() * 2).min(REFRESH_BACKOFF_MAX);
+    }
+}
+
+/// 两次列表结果的调度状态是否不同 (新增/删除项目或下一次检查时间变化)
+fn schedule_changed(old: &[ProjectConfig], new: &[ProjectConfig]) -> bool {
+    old.len() != new.len()
+        || old
+            .iter()
+            .zip(new)
+            .any(|(a, b)| a.unique_key != b.unique_key || a.state != b.state)
 }
 
 pub fn use_dashboard_store() -> DashboardStore {
@@ -76,9 +229,11 @@ pub fn use_dashboard_store() -> DashboardStore {
 
 pub fn use_provide_dashboard_store() -> DashboardStore {
     let (projects, set_projects) = signal(Vec::<ProjectConfig>::new());
+    let (versions, set_versions) = signal(HashMap::<String, String>::new());
     let (loading, set_loading) = signal(true);
     let (notification, set_notification) = signal(Option::<(String, bool)>::None);
     let (tick, set_tick) = signal(0u64);
+    let backoff = StoredValue::new(RefreshBackoff::new());
 
     let auth = use_auth();
     let auth_state = auth.state;
@@ -91,29 +246,52 @@ pub fn use_provide_dashboard_store() -> DashboardStore {
             let api = api.clone();
             set_loading.set(true);
             spawn_local(async move {
+                let mut changed = false;
                 match api.get_projects().await {
-                    Ok(data) => set_projects.set(data),
-                    Err(e) => set_notification.set(Some((format!("加载项目失败: {}", e), true))),
+                    Ok(data) => {
+                        changed = projects.with_untracked(|old| schedule_changed(old, &data));
+                        if changed {
+                            backoff.set_value(RefreshBackoff::new());
+                        }
+                        set_projects.set(data)
+                    }
+                    Err(e) => report_api_error(auth, set_notification, "加载项目失败", &e),
                 }
                 set_loading.set(false);
+
+                // 版本只会在检查后变化，而检查必然改变调度状态；
+                // 列表未变化 (含 304) 时跳过，避免每次刷新都让后端逐个查询 Monitor。
+                // 版本列只是补充信息，获取失败时保留上一次的结果
+                if changed && let Ok(items) = api.get_projects_detailed().await {
+                    set_versions.set(
+                        items
+                            .into_iter()
+                            .filter_map(|i| Some((i.config.unique_key, i.current_version?)))
+                            .collect(),
+                    );
+                }
             });
         }
     });
 
     // 创建 runner 实例，封装共享依赖
     let runner = ApiActionRunner {
-        auth_state,
+        auth,
         set_notification,
         load_projects,
+        cooldown_until: StoredValue::new(None),
     };
 
-    let add_project = Callback::new(move |req| {
-        runner.run(
-            |api| async move { api.add_project(req).await },
-            |_| "监控添加成功".to_string(),
-            "添加监控失败",
-        );
-    });
+    let add_project = Callback::new(
+        move |(req, done): (CreateProjectRequest, Callback<Result<(), ApiError>>)| {
+            runner.run_with(
+                |api| async move { api.add_project(req).await },
+                |_| "监控添加成功".to_string(),
+                "添加监控失败",
+                move |result| done.run(result.map_err(Clone::clone)),
+            );
+        },
+    );
 
     let delete_project = Callback::new(move |id: String| {
         runner.run(
@@ -153,6 +331,44 @@ pub fn use_provide_dashboard_store() -> DashboardStore {
         );
     });
 
+    let update_project = Callback::new(move |(id, interval): (String, DurationSecs)| {
+        // 乐观更新：先修改本地列表，失败时恢复原配置
+        let Some(previous) =
+            projects.with_untracked(|list| list.iter().find(|p| p.unique_key == id).cloned())
+        else {
+            return;
+        };
+        let mut time_config = previous.request.time_config.clone();
+        time_config.check_interval = interval;
+        set_projects.update(|list| {
+            if let Some(p) = list.iter_mut().find(|p| p.unique_key == id) {
+                p.request.time_config.check_interval = interval;
+            }
+        });
+
+        let req = UpdateProjectRequest {
+            unique_key: id,
+            time_config: Some(time_config),
+            comparison_mode: None,
+            dispatch_token_secret: None,
+        };
+        runner.run_or_revert(
+            |api| async move { api.update_project(req).await },
+            |_| "检查间隔已更新".to_string(),
+            "更新检查间隔失败",
+            move || {
+                set_projects.update(|list| {
+                    if let Some(p) = list
+                        .iter_mut()
+                        .find(|p| p.unique_key == previous.unique_key)
+                    {
+                        *p = previous;
+                    }
+                });
+            },
+        );
+    });
+
     // --- Timer & Auto Refresh Logic ---
     Effect::new(move |_| {
         if !auth_state.get().is_authenticated {
@@ -175,12 +391,15 @@ pub fn use_provide_dashboard_store() -> DashboardStore {
             let now = Date::now_timestamp();
 
             // Allow refresh if any project is expired
-            let needs_refresh = list.iter().any(|p| {
-                matches!(&p.state, MonitorState::Running { next_check_at } if *next_check_at <= now)
-            });
+            let needs_refresh = list
+                .iter()
+                .any(|p| p.next_check_at().is_some_and(|at| at <= now));
 
-            // Prevent concurrent refreshes
-            if needs_refresh && !loading.get_untracked() {
+            // 避免并发刷新，并按退避间隔限制到期触发的刷新频率
+            // 注意：若请求一直未返回，loading 保持为 true，自动刷新会暂停直到该请求结束
+            // (成功或失败都会复位 loading)，倒计时仍按 1s tick 正常显示
+            if needs_refresh && !loading.get_untracked() && backoff.with_value(|b| b.ready(now)) {
+                backoff.update_value(|b| b.record(now));
                 load_projects.run(());
             }
         });
@@ -211,14 +430,17 @@ pub fn use_provide_dashboard_store() -> DashboardStore {
 
     let store = DashboardStore {
         projects: projects.into(),
+        versions: versions.into(),
         loading: loading.into(),
         tick: tick.into(),
         notification: notification.into(),
+        is_admin: Signal::derive(move || auth_state.get().role == Role::Admin),
         refresh: load_projects,
         add_project,
         delete_project,
         switch_monitor,
         trigger_check,
+        update_project,
     };
 
     provide_context(store.clone());
@@ -284,13 +506,22 @@ fn DashboardNavbar(
         <div class="navbar bg-base-100 rounded-box shadow-xl">
             <div class="flex-1 gap-2">
                 <Radio attr:class="text-primary h-6 w-6 animate-pulse" />
-                <a class="btn btn-ghost text-xl">"VerWatch 控制面板"</a>
+                <Link to=AppRoute::Dashboard.to_path() attr:class="btn btn-ghost text-xl">
+                    "VerWatch 控制面板"
+                </Link>
                 <span class="badge badge-neutral hidden md:inline-flex">
                     "已连接至 " {backend_url}
                 </span>
             </div>
             <div class="flex-none gap-2">
-                <AddProjectDialog on_add=move |req| store.add_project.run(req) />
+                <Show
+                    when=move || store.is_admin.get()
+                    fallback=|| view! {
+                        <span class="badge badge-ghost" title="当前密钥只能查看，不能修改">"只读"</span>
+                    }
+                >
+                    <AddProjectDialog on_add=move |args| store.add_project.run(args) />
+                </Show>
                 <button on:click=move |e| on_logout.run(e) class="btn btn-outline btn-error gap-2">
                     <LogOut attr:class="h-4 w-4" /> "断开连接"
                 </button>
@@ -299,10 +530,56 @@ fn DashboardNavbar(
     }
 }
 
+/// 格式化为相对当前的时间，例如 `3m 前`
+/// 精确到分钟即可，避免每秒跳动
+pub(crate) fn format_ago(at: Timestamp) -> String {
+    let ago = DurationSecs::from(Date::now_timestamp() - at);
+    let ago = DurationSecs::from_secs(ago.as_secs() / 60 * 60);
+    if ago.as_secs() == 0 {
+        "刚刚".to_string()
+    } else {
+        format!("{} 前", ago.humanize())
+    }
+}
+
 #[component]
 fn DashboardStats() -> impl IntoView {
     let store = use_dashboard_store();
     let total_monitors = move || store.projects.with(|p| p.len());
+    let running_count = move || {
+        store
+            .projects
+            .with(|p| p.iter().filter(|c| c.is_running()).count())
+    };
+    let paused_count = move || total_monitors() - running_count();
+
+    // 1 分钟内到期 (含已过期) 的运行中项目数
+    let due_soon_count = move || {
+        let _ = store.tick.get(); // Subscribe to tick
+        let due_before = Date::now_timestamp() + DurationSecs::from_secs(60);
+        store.projects.with(|p| {
+            p.iter()
+                .filter_map(|c| c.next_check_at())
+                .filter(|at| *at <= due_before)
+                .count()
+        })
+    };
+
+    let system_status = move || match (total_monitors(), running_count()) {
+        (0, _) => ("空闲", "text-base-content"),
+        (_, 0) => ("全部暂停", "text-warning"),
+        _ => ("运行中", "text-success"),
+    };
+
+    // 所有项目中最近一次成功触发 Dispatch 的时间
+    let last_dispatch_text = move || {
+        let _ = store.tick.get();
+        store
+            .projects
+            .with(|p| p.iter().filter_map(|c| c.last_dispatch_at).max())
+            .map(format_ago)
+            .unwrap_or_else(|| "--".to_string())
+    };
 
     view! {
         <div class="stats shadow w-full stats-vertical md:stats-horizontal bg-base-100">
@@ -319,24 +596,136 @@ fn DashboardStats() -> impl IntoView {
                     <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" class="inline-block w-8 h-8 stroke-current"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M9 12l2 2 4-4m6 2a9 9 0 11-18 0 9 9 0 0118 0z"></path></svg>
                 </div>
                 <div class="stat-title">"系统状态"</div>
-                <div class="stat-value text-success">"运行中"</div>
+                <div class=move || format!("stat-value {}", system_status().1)>
+                    {move || system_status().0}
+                </div>
+                <div class="stat-desc">
+                    {move || format!("运行 {} / 暂停 {}", running_count(), paused_count())}
+                </div>
             </div>
 
             <div class="stat">
-                    <div class="stat-title">"更新策略"</div>
-                    <div class="stat-value text-secondary text-2xl">"自动 (定时)"</div>
-                    <div class="stat-desc">"Workers 自动调度"</div>
+                    <div class="stat-title">"即将检查"</div>
+                    <div class="stat-value text-secondary">{due_soon_count}</div>
+                    <div class="stat-desc">"1 分钟内到期"</div>
+            </div>
+
+            <div class="stat">
+                    <div class="stat-title">"最近检查"</div>
+                    <div class="stat-value text-2xl">{last_dispatch_text}</div>
+                    <div class="stat-desc">"最近一次触发 Dispatch"</div>
             </div>
         </div>
     }
 }
 
+/// 可排序的列
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum SortColumn {
+    Upstream,
+    NextCheck,
+    Mode,
+}
+
+/// 表格排序状态，点击表头依次切换：升序 -> 降序 -> 默认顺序
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+enum SortKey {
+    #[default]
+    Unsorted,
+    Asc(SortColumn),
+    Desc(SortColumn),
+}
+
+impl SortKey {
+    fn cycle(self, column: SortColumn) -> Self {
+        match self {
+            SortKey::Asc(c) if c == column => SortKey::Desc(column),
+            SortKey::Desc(c) if c == column => SortKey::Unsorted,
+            _ => SortKey::Asc(column),
+        }
+    }
+
+    fn indicator(self, column: SortColumn) -> &'static str {
+        match self {
+            SortKey::Asc(c) if c == column => " ▲",
+            SortKey::Desc(c) if c == column => " ▼",
+            _ => "",
+        }
+    }
+
+    fn sort(self, projects: &mut [ProjectConfig]) {
+        let (column, desc) = match self {
+            SortKey::Unsorted => return,
+            SortKey::Asc(c) => (c, false),
+            SortKey::Desc(c) => (c, true),
+        };
+        let directed = |o: Ordering| if desc { o.reverse() } else { o };
+        let upstream = |p: &ProjectConfig| {
+            let base = &p.request.base_config;
+            format!("{}/{}", base.upstream_owner, base.upstream_repo).to_lowercase()
+        };
+        let mode = |p: &ProjectConfig| format!("{:?}", p.request.comparison_mode);
+
+        projects.sort_by(|a, b| match column {
+            SortColumn::Upstream => directed(upstream(a).cmp(&upstream(b))),
+            SortColumn::Mode => directed(mode(a).cmp(&mode(b))),
+            // 暂停的项目无论升降序都排在最后
+            SortColumn::NextCheck => match (a.next_check_at(), b.next_check_at()) {
+                (Some(x), Some(y)) => directed(x.as_millis_i64().cmp(&y.as_millis_i64())),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+        });
+    }
+}
+
+#[component]
+fn SortableHeader(
+    label: &'static str,
+    column: SortColumn,
+    sort_key: RwSignal<SortKey>,
+    #[prop(optional)] class: &'static str,
+) -> impl IntoView {
+    view! {
+        <th
+            class=format!("cursor-pointer select-none hover:text-base-content {}", class)
+            on:click=move |_| sort_key.update(|k| *k = k.cycle(column))
+        >
+            {label}
+            {move || sort_key.get().indicator(column)}
+        </th>
+    }
+}
+
 #[component]
 fn ProjectsTable() -> impl IntoView {
     let store = use_dashboard_store();
 
     let total_monitors = move || store.projects.with(|p| p.len());
 
+    // 搜索关键字 (纯前端过滤)
+    let search = RwSignal::new(String::new());
+    let sort_key = RwSignal::new(SortKey::default());
+    // 待确认删除的项目
+    let pending_delete = RwSignal::new(None::<DeleteTarget>);
+    let filtered_projects: Signal<Vec<ProjectConfig>> = Signal::derive(move || {
+        let filter = ProjectFilter {
+            q: Some(search.get()),
+            paused: None,
+        };
+        let mut projects: Vec<ProjectConfig> = store.projects.with(|projects| {
+            projects
+                .iter()
+                .filter(|p| filter.matches(p))
+                .cloned()
+                .collect()
+        });
+        sort_key.get().sort(&mut projects);
+        projects
+    });
+    let no_match = move || total_monitors() > 0 && filtered_projects.with(|p| p.is_empty());
+
     view! {
         <div class="card bg-base-100 shadow-xl flex-1 flex flex-col min-h-0">
             <div class="card-body p-0 flex flex-col h-full overflow-hidden">
@@ -345,19 +734,29 @@ fn ProjectsTable() -> impl IntoView {
                         <h3 class="card-title">"活跃监控"</h3>
                         <p class="text-base-content/70 text-sm">"管理您的仓库监控列表。目前共有 " {total_monitors} " 个监控项。"</p>
                     </div>
-                    <button on:click=move |_| store.refresh.run(()) disabled=move || store.loading.get() class="btn btn-ghost btn-circle">
-                        <RefreshCw attr:class=move || if store.loading.get() { "h-5 w-5 animate-spin" } else { "h-5 w-5" } />
-                    </button>
+                    <div class="flex items-center gap-2">
+                        <input
+                            type="search"
+                            placeholder="搜索上游或目标仓库..."
+                            class="input input-bordered input-sm w-48 md:w-64"
+                            prop:value=move || search.get()
+                            on:input=move |ev| search.set(event_target_value(&ev))
+                        />
+                        <button on:click=move |_| store.refresh.run(()) disabled=move || store.loading.get() class="btn btn-ghost btn-circle">
+                            <RefreshCw attr:class=move || if store.loading.get() { "h-5 w-5 animate-spin" } else { "h-5 w-5" } />
+                        </button>
+                    </div>
                 </div>
 
                 <div class="overflow-auto w-full flex-1">
                     <table class="table table-zebra w-full">
                         <thead>
                             <tr>
-                                <th>"上游"</th>
+                                <SortableHeader label="上游" column=SortColumn::Upstream sort_key=sort_key />
                                 <th>"目标"</th>
-                                <th class="hidden md:table-cell">"触发模式"</th>
-                                <th class="hidden md:table-cell">"下次检查"</th>
+                                <SortableHeader label="触发模式" column=SortColumn::Mode sort_key=sort_key class="hidden md:table-cell" />
+                                <SortableHeader label="下次检查" column=SortColumn::NextCheck sort_key=sort_key class="hidden md:table-cell" />
+                                <th class="hidden lg:table-cell">"上次更新"</th>
                                 <th class="hidden lg:table-cell">"密钥"</th>
                                 <th></th>
                             </tr>
@@ -365,40 +764,105 @@ fn ProjectsTable() -> impl IntoView {
                         <tbody>
                             <Show when=move || total_monitors() == 0 && !store.loading.get()>
                                 <tr>
-                                    <td colspan="5" class="text-center py-8 text-base-content/50">
+                                    <td colspan="7" class="text-center py-8 text-base-content/50">
                                         "未配置监控。添加一个以开始。"
                                     </td>
                                 </tr>
                             </Show>
-                                <Show when=move || store.loading.get() && total_monitors() == 0>
+                            <Show when=no_match>
+                                <tr>
+                                    <td colspan="7" class="text-center py-8 text-base-content/50">
+                                        "No matching monitors"
+                                    </td>
+                                </tr>
+                            </Show>
+                            <Show when=move || store.loading.get() && total_monitors() == 0>
                                 <tr>
-                                    <td colspan="5" class="text-center py-8 text-base-content/50">
+                                    <td colspan="7" class="text-center py-8 text-base-content/50">
                                         <span class="loading loading-spinner loading-md"></span> " 加载中..."
                                     </td>
                                 </tr>
                             </Show>
                             <For
-                                each=move || store.projects.get()
+                                each=move || filtered_projects.get()
                                 key=|p| {
-                                    match &p.state {
-                                        MonitorState::Paused => format!("{}|paused", p.unique_key),
-                                        MonitorState::Running { next_check_at } => {
-                                            format!("{}|running|{}", p.unique_key, next_check_at.as_millis_i64())
+                                    let interval = p.request.time_config.check_interval.as_secs();
+                                    let dispatched = p.last_dispatch_at.map(|t| t.as_millis_i64()).unwrap_or(0);
+                                    match p.next_check_at() {
+                                        None => format!("{}|{}|{}|paused", p.unique_key, interval, dispatched),
+                                        Some(next_check_at) => {
+                                            format!("{}|{}|{}|running|{}", p.unique_key, interval, dispatched, next_check_at.as_millis_i64())
                                         }
                                     }
                                 }
                                 children=move |project| {
-                                    view! { <ProjectRow project=project /> }
+                                    view! { <ProjectRow project=project pending_delete=pending_delete /> }
                                 }
                             />
                         </tbody>
                     </table>
                 </div>
             </div>
+            <DeleteConfirmDialog target=pending_delete on_confirm=store.delete_project />
         </div>
     }
 }
 
+/// 行内修改检查间隔的弹出框
+///
+/// 输入格式与 `DurationSecs` 的 `FromStr` 一致，例如 `90m`、`1h30m`、`2d`。
+/// 与添加项目时的数值 + 单位选择不同，这里使用单个文本框：可以直接输入组合时长，
+/// 并以 `humanize()` 的结果预填，保存前无需换算单位
+#[component]
+fn IntervalEditor(id: String, interval: DurationSecs) -> impl IntoView {
+    let store = use_dashboard_store();
+    let details_ref = NodeRef::<leptos::html::Details>::new();
+
+    let input = RwSignal::new(interval.humanize());
+    let parsed = move || {
+        input
+            .with(|s| s.parse::<DurationSecs>())
+            .ok()
+            .filter(|d| d.as_secs() > 0)
+    };
+
+    let on_submit = move |ev: leptos::web_sys::SubmitEvent| {
+        ev.prevent_default();
+        let Some(new_interval) = parsed() else {
+            return;
+        };
+        if new_interval != interval {
+            store.update_project.run((id.clone(), new_interval));
+        }
+        if let Some(details) = details_ref.get() {
+            details.set_open(false);
+        }
+    };
+
+    view! {
+        <details class="dropdown dropdown-end" node_ref=details_ref>
+            <summary class="btn btn-ghost btn-xs font-mono opacity-60" title="修改检查间隔">
+                "每 " {interval.humanize()}
+            </summary>
+            <form
+                on:submit=on_submit
+                class="dropdown-content z-[1] card card-compact bg-base-200 shadow p-3 w-56 space-y-2"
+            >
+                <span class="label-text">"检查间隔"</span>
+                <input type="text" required placeholder="例如 1h30m"
+                    class="input input-bordered input-sm w-full font-mono"
+                    class:input-error=move || parsed().is_none()
+                    prop:value=move || input.get()
+                    on:input=move |ev| input.set(event_target_value(&ev))
+                />
+                <button type="submit" class="btn btn-primary btn-sm w-full" disabled=move || parsed().is_none()>
+                    "保存"
+                </button>
+            </form>
+        </details>
+    }
+}
+
 struct ProjectRowDisplay {
     upstream: String,
     target: String,
@@ -428,28 +892,43 @@ impl From<&ProjectConfig> for ProjectRowDisplay {
 }
 
 #[component]
-fn ProjectRow(project: ProjectConfig) -> impl IntoView {
+fn ProjectRow(
+    project: ProjectConfig,
+    pending_delete: RwSignal<Option<DeleteTarget>>,
+) -> impl IntoView {
     let store = use_dashboard_store();
     let id = project.unique_key.clone();
     let is_paused = project.state.is_paused();
+    let is_dry_run = project.request.dry_run;
     let state_for_countdown = project.state.clone();
     let state_for_badge = project.state.clone();
     let display = ProjectRowDisplay::from(&project);
+    let delete_label = format!("{} -> {}", display.upstream, display.target);
+    let detail_path = AppRoute::ProjectDetail { key: id.clone() }.to_path();
+    let version_key = id.clone();
 
-    // Countdown Text - 调用 JS 格式化函数
+    // Countdown Text
     let countdown_text = move || {
         let _ = store.tick.get(); // Subscribe to tick
         match &state_for_countdown {
             MonitorState::Paused => "--".to_string(),
             MonitorState::Running { next_check_at } => {
-                let now = Date::now_timestamp();
-                let secs = (*next_check_at - now).as_secs() as f64;
-                format_countdown(secs)
+                countdown_label(next_check_at.signed_diff_secs(Date::now_timestamp()))
             }
         }
     };
 
-    let (id_pause, id_check, id_del) = (id.clone(), id.clone(), id.clone());
+    // 上次成功 Dispatch 的相对时间
+    let last_dispatch_at = project.last_dispatch_at;
+    let last_dispatch_text = move || {
+        let _ = store.tick.get();
+        last_dispatch_at
+            .map(format_ago)
+            .unwrap_or_else(|| "--".to_string())
+    };
+
+    let check_interval = project.request.time_config.check_interval;
+    let (id_actions, id_interval) = (id.clone(), id);
 
     view! {
         <tr
@@ -460,12 +939,22 @@ fn ProjectRow(project: ProjectConfig) -> impl IntoView {
             <td>
                 <div class="flex items-center gap-2 font-mono text-sm font-bold">
                     <Github attr:class="h-4 w-4 opacity-50" />
-                    {display.upstream}
+                    <Link to=detail_path attr:class="link link-hover">{display.upstream}</Link>
+                    {move || store.versions.with(|v| v.get(&version_key).cloned()).map(|version| view! {
+                        <span class="badge badge-outline badge-sm font-mono" title="已记录的版本">
+                            {version}
+                        </span>
+                    })}
                     <Show when=move || is_paused>
                         <span class="badge badge-warning badge-sm gap-1">
                             <Pause attr:class="h-3 w-3" /> "已暂停"
                         </span>
                     </Show>
+                    <Show when=move || is_dry_run>
+                        <span class="badge badge-info badge-outline badge-sm" title="仅检测版本，不触发 Dispatch">
+                            "试运行"
+                        </span>
+                    </Show>
                 </div>
             </td>
             <td>
@@ -487,7 +976,7 @@ fn ProjectRow(project: ProjectConfig) -> impl IntoView {
                         MonitorState::Paused => format!("{} badge-ghost", base),
                         MonitorState::Running { next_check_at } => {
                             let now = Date::now_timestamp();
-                            let secs = (*next_check_at - now).as_secs() as i64;
+                            let secs = next_check_at.signed_diff_secs(now);
                             if secs <= 60 {
                                 format!("{} badge-error animate-pulse", base)
                             } else if secs <= 300 {
@@ -501,38 +990,119 @@ fn ProjectRow(project: ProjectConfig) -> impl IntoView {
                     <Clock attr:class="h-3 w-3 mr-1" />
                     {countdown_text}
                 </div>
+                <Show
+                    when=move || store.is_admin.get()
+                    fallback=move || view! {
+                        <span class="text-xs font-mono opacity-60 px-2">"每 " {check_interval.humanize()}</span>
+                    }
+                >
+                    <IntervalEditor id=id_interval.clone() interval=check_interval />
+                </Show>
+            </td>
+            <td class="hidden lg:table-cell font-mono text-xs opacity-70">
+                {last_dispatch_text}
             </td>
             <td class="hidden lg:table-cell font-mono text-xs opacity-50">
                 {display.secret}
             </td>
             <td>
-                <div class="dropdown dropdown-end">
-                    <div tabindex="0" role="button" class="btn btn-ghost btn-sm btn-square">
-                        <MoreHorizontal attr:class="h-4 w-4" />
-                    </div>
-                    <ul tabindex="0" class="dropdown-content z-[1] menu p-2 shadow bg-base-200 rounded-box w-52">
-                        <li>
-                            <a on:click=move |_| store.switch_monitor.run((id_pause.clone(), !is_paused))>
-                                <Show when=move || is_paused
-                                        fallback=|| view! { <Pause attr:class="mr-2 h-4 w-4" /> "暂停监控" }>
-                                        <Play attr:class="mr-2 h-4 w-4" /> "恢复监控"
-                                </Show>
-                            </a>
-                        </li>
-                        <li>
-                            <a on:click=move |_| store.trigger_check.run(id_check.clone())>
-                                <RefreshCw attr:class="mr-2 h-4 w-4" /> "立即触发检查"
-                            </a>
-                        </li>
-                        <li>
-                            <a on:click=move |_| store.delete_project.run(id_del.clone()) class="text-error hover:bg-error/10">
-                                <Trash2 attr:class="mr-2 h-4 w-4" />
-                                "删除"
-                            </a>
-                        </li>
-                    </ul>
-                </div>
+                <Show when=move || store.is_admin.get()>
+                    <RowActions
+                        id=id_actions.clone()
+                        is_paused=is_paused
+                        delete_label=delete_label.clone()
+                        pending_delete=pending_delete
+                    />
+                </Show>
             </td>
         </tr>
     }
 }
+
+/// 行操作菜单 (暂停/恢复、立即检查、删除)，仅对 Admin 展示
+#[component]
+fn RowActions(
+    id: String,
+    is_paused: bool,
+    delete_label: String,
+    pending_delete: RwSignal<Option<DeleteTarget>>,
+) -> impl IntoView {
+    let store = use_dashboard_store();
+    let (id_pause, id_check, id_del) = (id.clone(), id.clone(), id);
+
+    view! {
+        <div class="dropdown dropdown-end">
+            <div tabindex="0" role="button" class="btn btn-ghost btn-sm btn-square">
+                <MoreHorizontal attr:class="h-4 w-4" />
+            </div>
+            <ul tabindex="0" class="dropdown-content z-[1] menu p-2 shadow bg-base-200 rounded-box w-52">
+                <li>
+                    <a on:click=move |_| store.switch_monitor.run((id_pause.clone(), !is_paused))>
+                        <Show when=move || is_paused
+                                fallback=|| view! { <Pause attr:class="mr-2 h-4 w-4" /> "暂停监控" }>
+                                <Play attr:class="mr-2 h-4 w-4" /> "恢复监控"
+                        </Show>
+                    </a>
+                </li>
+                <li>
+                    <a on:click=move |_| store.trigger_check.run(id_check.clone())>
+                        <RefreshCw attr:class="mr-2 h-4 w-4" /> "立即触发检查"
+                    </a>
+                </li>
+                <li>
+                    <a on:click=move |_| pending_delete.set(Some(DeleteTarget { key: id_del.clone(), label: delete_label.clone() })) class="text-error hover:bg-error/10">
+                        <Trash2 attr:class="mr-2 h-4 w-4" />
+                        "删除"
+                    </a>
+                </li>
+            </ul>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_notice_by_status() {
+        assert_eq!(
+            error_notice("删除失败", &ApiError::Unauthorized("Invalid secret".into())),
+            "密钥已失效，请重新登录"
+        );
+        assert!(
+            error_notice("删除失败", &ApiError::RateLimited("Too many".into()))
+                .starts_with("删除失败: 请求过于频繁")
+        );
+        assert_eq!(
+            error_notice("删除失败", &ApiError::NotFound("Project not found".into())),
+            "删除失败: Project not found"
+        );
+    }
+
+    #[test]
+    fn test_refresh_backoff_doubles_up_to_max() {
+        let mut backoff = RefreshBackoff::new();
+        let mut now = Timestamp::new(1_000_000);
+        assert!(backoff.ready(now));
+
+        let mut waits = Vec::new();
+        for _ in 0..7 {
+            backoff.record(now);
+            assert!(!backoff.ready(now));
+            let wait = backoff.next_allowed_at.signed_diff_secs(now);
+            waits.push(wait);
+            now = backoff.next_allowed_at;
+            assert!(backoff.ready(now));
+        }
+        assert_eq!(waits, [2, 4, 8, 16, 32, 60, 60]);
+    }
+
+    #[test]
+    fn test_countdown_label_overdue() {
+        assert_eq!(countdown_label(0), "检查中…");
+        assert_eq!(countdown_label(-30), "检查中…");
+        assert_eq!(countdown_label(-120), "逾期 2m");
+        assert_eq!(countdown_label(90), "1m 30s");
+    }
+}
@@ -0,0 +1,71 @@
+//! 删除确认对话框组件
+//!
+//! 删除会同时丢失 Monitor 中保存的版本状态，因此在真正调用删除前
+//! 需要用户再次确认。
+
+use crate::components::icons::Trash2;
+use leptos::prelude::*;
+
+/// 待删除的目标
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeleteTarget {
+    /// 项目唯一键
+    pub key: String,
+    /// 展示用的 `upstream -> target` 标识
+    pub label: String,
+}
+
+/// 删除确认对话框
+///
+/// `target` 为 `Some` 时打开对话框，确认后以目标键调用 `on_confirm`，
+/// 取消或关闭时清空 `target`
+#[component]
+pub fn DeleteConfirmDialog(
+    target: RwSignal<Option<DeleteTarget>>,
+    #[prop(into)] on_confirm: Callback<String>,
+) -> impl IntoView {
+    let dialog_ref = NodeRef::<leptos::html::Dialog>::new();
+
+    // 模态框同步 Effect
+    Effect::new(move |_| {
+        if let Some(dialog) = dialog_ref.get() {
+            if target.with(Option::is_some) {
+                if !dialog.open() {
+                    let _ = dialog.show_modal();
+                }
+            } else if dialog.open() {
+                dialog.close();
+            }
+        }
+    });
+
+    let on_delete = move |_| {
+        if let Some(t) = target.get_untracked() {
+            on_confirm.run(t.key);
+        }
+        target.set(None);
+    };
+
+    let label = move || target.with(|t| t.as_ref().map(|t| t.label.clone()).unwrap_or_default());
+
+    view! {
+        <dialog class="modal" node_ref=dialog_ref on:close=move |_| target.set(None)>
+            <div class="modal-box">
+                <h3 class="font-bold text-lg">"确认删除监控"</h3>
+                <p class="py-4 text-base-content/70">
+                    "删除后该项目的版本状态将一并丢失，且无法恢复。"
+                </p>
+                <p class="font-mono text-sm bg-base-200 rounded-box px-3 py-2">{label}</p>
+                <div class="modal-action">
+                    <button type="button" class="btn btn-ghost" on:click=move |_| target.set(None)>"取消"</button>
+                    <button type="button" class="btn btn-error gap-2" on:click=on_delete>
+                        <Trash2 attr:class="h-4 w-4" /> "删除"
+                    </button>
+                </div>
+            </div>
+            <form method="dialog" class="modal-backdrop">
+                <button>"close"</button>
+            </form>
+        </dialog>
+    }
+}
@@ -3,22 +3,35 @@
 //! 纯粹的 UI 组件，不直接处理路由逻辑。
 //! 导航由路由服务根据认证状态变化自动处理。
 
+use crate::api::{ApiError, VerWatchApi};
 use crate::auth::{login, use_auth};
 use crate::components::icons::ShieldCheck;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
+/// "测试连接"的结果
+#[derive(Clone, PartialEq)]
+enum PingStatus {
+    Idle,
+    Testing,
+    Ok,
+    Failed(String),
+}
+
 #[component]
 pub fn LoginPage() -> impl IntoView {
     let auth = use_auth();
 
     // 从认证状态获取初始 URL
     let initial_url = auth.state.get_untracked().backend_url;
+    // 最近使用过的后端 URL，用于输入框候选
+    let recent_urls = move || auth.state.get().recent_urls;
 
     let (url, set_url) = signal(initial_url);
     let (secret, set_secret) = signal(String::new());
     let (is_submitting, set_is_submitting) = signal(false);
     let (error_msg, set_error_msg) = signal(Option::<String>::None);
+    let (ping_status, set_ping_status) = signal(PingStatus::Idle);
 
     // 检查加载状态以显示加载指示器
     let is_loading = move || auth.state.get().is_loading;
@@ -54,6 +67,25 @@ pub fn LoginPage() -> impl IntoView {
                     });
                 };
 
+                // 测试连接：只检查后端与密钥，不登录也不跳转
+                let on_ping = move |_| {
+                    if url.get().is_empty() || secret.get().is_empty() {
+                        set_ping_status.set(PingStatus::Failed("请填写所有字段".to_string()));
+                        return;
+                    }
+                    set_ping_status.set(PingStatus::Testing);
+                    let api = VerWatchApi::new(url.get_untracked(), secret.get_untracked());
+                    spawn_local(async move {
+                        let status = match api.ping().await {
+                            Ok(health) if health.ok => PingStatus::Ok,
+                            Ok(_) => PingStatus::Failed("后端可访问，但部分子系统不可用".to_string()),
+                            Err(ApiError::Unauthorized(_)) => PingStatus::Failed("密钥不正确".to_string()),
+                            Err(e) => PingStatus::Failed(e.to_string()),
+                        };
+                        set_ping_status.set(status);
+                    });
+                };
+
                 view! {
                     <div class="hero min-h-screen bg-base-200">
                         <div class="hero-content flex-col w-full max-w-md">
@@ -85,12 +117,23 @@ pub fn LoginPage() -> impl IntoView {
                                         <input
                                             id="url"
                                             type="text"
+                                            list="recent-urls"
                                             placeholder="https://verwatch.workers.dev"
-                                            on:input=move |ev| set_url.set(event_target_value(&ev))
+                                            on:input=move |ev| {
+                                                set_url.set(event_target_value(&ev));
+                                                set_ping_status.set(PingStatus::Idle);
+                                            }
                                             prop:value=url
                                             class="input input-bordered"
                                             required
                                         />
+                                        <datalist id="recent-urls">
+                                            <For
+                                                each=recent_urls
+                                                key=|u| u.clone()
+                                                children=|u| view! { <option value=u></option> }
+                                            />
+                                        </datalist>
                                     </div>
                                     <div class="form-control">
                                         <label class="label" for="secret">
@@ -100,7 +143,10 @@ pub fn LoginPage() -> impl IntoView {
                                             id="secret"
                                             type="password"
                                             placeholder="••••••••"
-                                            on:input=move |ev| set_secret.set(event_target_value(&ev))
+                                            on:input=move |ev| {
+                                                set_secret.set(event_target_value(&ev));
+                                                set_ping_status.set(PingStatus::Idle);
+                                            }
                                             prop:value=secret
                                             class="input input-bordered"
                                             required
@@ -114,6 +160,27 @@ pub fn LoginPage() -> impl IntoView {
                                                 "连接到控制台".into_any()
                                             }}
                                         </button>
+                                        <button
+                                            type="button"
+                                            class="btn btn-ghost btn-sm mt-2"
+                                            disabled=move || ping_status.get() == PingStatus::Testing
+                                            on:click=on_ping
+                                        >
+                                            {move || if ping_status.get() == PingStatus::Testing {
+                                                view! { <span class="loading loading-spinner loading-xs"></span> "测试中..." }.into_any()
+                                            } else {
+                                                "测试连接".into_any()
+                                            }}
+                                        </button>
+                                        {move || match ping_status.get() {
+                                            PingStatus::Ok => Some(view! {
+                                                <span class="text-sm text-success text-center">"连接正常，密钥有效"</span>
+                                            }.into_any()),
+                                            PingStatus::Failed(message) => Some(view! {
+                                                <span class="text-sm text-error text-center">{message}</span>
+                                            }.into_any()),
+                                            _ => None,
+                                        }}
                                     </div>
                                 </form>
                             </div>
@@ -0,0 +1,258 @@
+//! 项目详情页组件
+//!
+//! 展示单个项目的配置、监控状态与最近的检查历史。
+
+use crate::api::ApiError;
+use crate::auth::{logout, use_auth};
+use crate::components::dashboard::format_ago;
+use crate::components::icons::{Clock, GitFork, Github, RefreshCw};
+use crate::web::route::AppRoute;
+use crate::web::router::Link;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use verwatch_shared::{CheckEvent, CheckOutcome, Date, DurationSecs, MonitorState, ProjectConfig};
+
+/// 检查结果对应的文案与 Badge 样式
+fn outcome_badge(outcome: CheckOutcome) -> (&'static str, &'static str) {
+    match outcome {
+        CheckOutcome::Success => ("已触发", "badge badge-success badge-sm"),
+        CheckOutcome::NoChange => ("无变化", "badge badge-ghost badge-sm"),
+        CheckOutcome::Error => ("失败", "badge badge-error badge-sm"),
+    }
+}
+
+#[component]
+pub fn ProjectDetailPage(
+    /// 项目的 unique_key
+    key: String,
+) -> impl IntoView {
+    let auth = use_auth();
+    let (project, set_project) = signal(Option::<ProjectConfig>::None);
+    let (history, set_history) = signal(Vec::<CheckEvent>::new());
+    let (loading, set_loading) = signal(true);
+    let (error_msg, set_error_msg) = signal(Option::<String>::None);
+
+    let key = StoredValue::new(key);
+    let load = move || {
+        let Some(api) = auth.state.get_untracked().api else {
+            return;
+        };
+        set_loading.set(true);
+        set_error_msg.set(None);
+        spawn_local(async move {
+            let key = key.get_value();
+            match api.get_project_one(&key).await {
+                Ok(config) => set_project.set(Some(config)),
+                // 密钥失效时注销，由路由跳转回登录页
+                Err(ApiError::Unauthorized(_)) => return logout(&auth),
+                Err(e) => set_error_msg.set(Some(format!("加载项目失败: {}", e))),
+            }
+            // 历史记录加载失败不影响配置展示
+            match api.get_history(&key).await {
+                Ok(events) => set_history.set(events),
+                Err(e) => set_error_msg.set(Some(format!("加载检查历史失败: {}", e))),
+            }
+            set_loading.set(false);
+        });
+    };
+    load();
+
+    view! {
+        <div class="min-h-screen bg-base-200 p-4 md:p-8 font-sans">
+            <div class="max-w-5xl mx-auto flex flex-col gap-6">
+                <div class="navbar bg-base-100 rounded-box shadow-xl">
+                    <div class="flex-1 gap-2">
+                        <Link to=AppRoute::Dashboard.to_path() attr:class="btn btn-ghost">
+                            "← 返回控制面板"
+                        </Link>
+                        <span class="font-mono text-sm opacity-70 truncate">
+                            {move || {
+                                project
+                                    .get()
+                                    .map(|p| p.display_key())
+                                    .unwrap_or_else(|| key.get_value())
+                            }}
+                        </span>
+                    </div>
+                    <div class="flex-none">
+                        <button
+                            class="btn btn-ghost btn-sm gap-2"
+                            disabled=move || loading.get()
+                            on:click=move |_| load()
+                        >
+                            <RefreshCw attr:class="h-4 w-4" /> "刷新"
+                        </button>
+                    </div>
+                </div>
+
+                <Show when=move || error_msg.get().is_some()>
+                    <div class="alert alert-error">
+                        <span>{move || error_msg.get().unwrap_or_default()}</span>
+                    </div>
+                </Show>
+
+                <Show
+                    when=move || project.get().is_some()
+                    fallback=move || view! {
+                        <Show when=move || loading.get()>
+                            <div class="flex justify-center p-8">
+                                <span class="loading loading-spinner loading-lg text-primary"></span>
+                            </div>
+                        </Show>
+                    }
+                >
+                    {move || project.get().map(|p| view! {
+                        <ProjectConfigCard project=p.clone() />
+                        <ProjectStateCard project=p />
+                    })}
+                </Show>
+
+                <CheckHistoryTable history=history.into() />
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn ProjectConfigCard(project: ProjectConfig) -> impl IntoView {
+    let base = &project.request.base_config;
+    let upstream = format!("{} / {}", base.upstream_owner, base.upstream_repo);
+    let target = format!("{} / {}", base.my_owner, base.my_repo);
+    let request = project.request;
+    let optional = |value: Option<String>| value.unwrap_or_else(|| "--".to_string());
+
+    view! {
+        <div class="card bg-base-100 shadow-xl">
+            <div class="card-body">
+                <h2 class="card-title">"配置"</h2>
+                <div class="grid grid-cols-1 md:grid-cols-2 gap-4 text-sm">
+                    <div class="flex items-center gap-2 font-mono font-bold">
+                        <Github attr:class="h-4 w-4 opacity-50" /> {upstream}
+                    </div>
+                    <div class="flex items-center gap-2 font-mono opacity-70">
+                        <GitFork attr:class="h-4 w-4 opacity-50" /> {target}
+                    </div>
+                    <div>"比较模式: " {format!("{:?}", request.comparison_mode)}</div>
+                    <div>"来源: " {format!("{:?} / {:?}", request.provider, request.source_kind)}</div>
+                    <div>"检查间隔: " {request.time_config.check_interval.humanize()}</div>
+                    <div>"重试间隔: " {request.time_config.retry_interval.humanize()}</div>
+                    <div>"Tag 正则: " <span class="font-mono">{optional(request.tag_pattern)}</span></div>
+                    <div>"版本约束: " <span class="font-mono">{optional(request.version_constraint)}</span></div>
+                    <div>
+                        "Token Secret: "
+                        <span class="font-mono">
+                            {request.dispatch_token_secret.unwrap_or_else(|| "全局".to_string())}
+                        </span>
+                    </div>
+                    <div>"试运行: " {if request.dry_run { "是" } else { "否" }}</div>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn ProjectStateCard(project: ProjectConfig) -> impl IntoView {
+    let schedule = match project.state {
+        MonitorState::Paused => "已暂停".to_string(),
+        MonitorState::Running { next_check_at } => {
+            let secs = next_check_at.signed_diff_secs(Date::now_timestamp());
+            if secs > 0 {
+                format!(
+                    "运行中，{} 后检查",
+                    DurationSecs::from_secs(secs as u64).humanize()
+                )
+            } else {
+                "运行中，等待检查".to_string()
+            }
+        }
+    };
+    let last_outcome = project.last_outcome.map(outcome_badge);
+    let last_dispatch = project
+        .last_dispatch_at
+        .map(format_ago)
+        .unwrap_or_else(|| "--".to_string());
+
+    view! {
+        <div class="card bg-base-100 shadow-xl">
+            <div class="card-body">
+                <h2 class="card-title">"监控状态"</h2>
+                <div class="stats stats-vertical md:stats-horizontal">
+                    <div class="stat">
+                        <div class="stat-title">"调度"</div>
+                        <div class="stat-value text-lg flex items-center gap-2">
+                            <Clock attr:class="h-4 w-4" /> {schedule}
+                        </div>
+                    </div>
+                    <div class="stat">
+                        <div class="stat-title">"最近一次检查"</div>
+                        <div class="stat-value text-lg">
+                            {match last_outcome {
+                                Some((label, class)) => view! { <span class=class>{label}</span> }.into_any(),
+                                None => view! { <span>"--"</span> }.into_any(),
+                            }}
+                        </div>
+                    </div>
+                    <div class="stat">
+                        <div class="stat-title">"上次 Dispatch"</div>
+                        <div class="stat-value text-lg">{last_dispatch}</div>
+                    </div>
+                    <div class="stat">
+                        <div class="stat-title">"累计失败"</div>
+                        <div class="stat-value text-lg">{project.failed_checks}</div>
+                    </div>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn CheckHistoryTable(history: Signal<Vec<CheckEvent>>) -> impl IntoView {
+    // 后端按时间正序返回，展示时最新的在前
+    let rows = move || {
+        let mut events = history.get();
+        events.reverse();
+        events
+    };
+
+    view! {
+        <div class="card bg-base-100 shadow-xl">
+            <div class="card-body">
+                <h2 class="card-title">"检查历史"</h2>
+                <Show
+                    when=move || !history.get().is_empty()
+                    fallback=|| view! { <p class="opacity-60">"暂无检查记录"</p> }
+                >
+                    <div class="overflow-x-auto">
+                        <table class="table table-sm">
+                            <thead>
+                                <tr>
+                                    <th>"时间"</th>
+                                    <th>"结果"</th>
+                                    <th>"详情"</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                <For
+                                    each=rows
+                                    key=|event| (i64::from(event.at), event.detail.clone())
+                                    children=|event| {
+                                        let (label, class) = outcome_badge(event.outcome);
+                                        view! {
+                                            <tr>
+                                                <td class="font-mono text-xs whitespace-nowrap">{format_ago(event.at)}</td>
+                                                <td><span class=class>{label}</span></td>
+                                                <td class="text-sm">{event.detail}</td>
+                                            </tr>
+                                        }
+                                    }
+                                />
+                            </tbody>
+                        </table>
+                    </div>
+                </Show>
+            </div>
+        </div>
+    }
+}
@@ -11,14 +11,17 @@ mod auth;
 mod components {
     mod add_project_dialog;
     pub mod dashboard;
+    mod delete_confirm_dialog;
     mod icons;
     pub mod login;
+    pub mod project_detail;
 }
 mod serde_helper;
 
 use crate::auth::{AuthContext, init_auth};
 use crate::components::dashboard::DashboardPage;
 use crate::components::login::LoginPage;
+use crate::components::project_detail::ProjectDetailPage;
 
 use leptos::prelude::*;
 
@@ -32,13 +35,13 @@ pub(crate) mod web {
     mod storage;
     mod timer;
 
-    pub use http::HttpClient;
-    pub use storage::LocalStorage;
+    pub use http::{HttpClient, HttpResponse};
+    pub use storage::{LocalStorage, push_recent};
     pub use timer::Interval;
 }
 
 use web::route::AppRoute;
-use web::router::{Router, RouterOutlet};
+use web::router::{Link, Router, RouterOutlet};
 
 /// 路由匹配函数
 ///
@@ -47,11 +50,16 @@ fn route_matcher(route: AppRoute) -> AnyView {
     match route {
         AppRoute::Login => view! { <LoginPage /> }.into_any(),
         AppRoute::Dashboard => view! { <DashboardPage /> }.into_any(),
+        AppRoute::ProjectDetail { key } => view! { <ProjectDetailPage key=key /> }.into_any(),
         AppRoute::NotFound => view! {
             <div class="flex items-center justify-center min-h-screen bg-base-200">
                 <div class="text-center">
                     <h1 class="text-6xl font-bold text-error">"404"</h1>
                     <p class="text-xl mt-4">"页面未找到"</p>
+                    // 未登录时守卫停留在登录页，已登录时会继续跳转到控制面板
+                    <Link to=AppRoute::Login.to_path() attr:class="btn btn-primary mt-6">
+                        "返回首页"
+                    </Link>
                 </div>
             </div>
         }
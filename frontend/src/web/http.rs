@@ -12,6 +12,7 @@ use web_sys::{Headers, Request, RequestInit, Response};
 pub enum HttpMethod {
     Get,
     Post,
+    Put,
     Delete,
 }
 
@@ -20,6 +21,7 @@ impl HttpMethod {
         match self {
             HttpMethod::Get => "GET",
             HttpMethod::Post => "POST",
+            HttpMethod::Put => "PUT",
             HttpMethod::Delete => "DELETE",
         }
     }
@@ -62,6 +64,11 @@ impl HttpResponse {
         self.inner.ok()
     }
 
+    /// 获取响应头 (不存在时返回 None)
+    pub fn header(&self, name: &str) -> Option<String> {
+        self.inner.headers().get(name).ok().flatten()
+    }
+
     /// 获取响应体文本
     pub async fn text(self) -> Result<String, HttpError> {
         let promise = self
@@ -159,6 +166,11 @@ impl HttpClient {
         HttpRequestBuilder::new(url.to_string(), HttpMethod::Post)
     }
 
+    /// 创建 PUT 请求
+    pub fn put(url: &str) -> HttpRequestBuilder {
+        HttpRequestBuilder::new(url.to_string(), HttpMethod::Put)
+    }
+
     /// 创建 DELETE 请求
     pub fn delete(url: &str) -> HttpRequestBuilder {
         HttpRequestBuilder::new(url.to_string(), HttpMethod::Delete)
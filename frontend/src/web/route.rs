@@ -5,6 +5,9 @@
 
 use std::fmt::Display;
 
+/// 项目详情页的路径前缀
+const PROJECT_DETAIL_PREFIX: &str = "/project/";
+
 /// 应用路由枚举
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum AppRoute {
@@ -13,32 +16,50 @@ pub enum AppRoute {
     Login,
     /// 控制面板 (需要认证)
     Dashboard,
+    /// 单个项目的详情页 (需要认证)，`key` 为项目的 unique_key
+    ProjectDetail { key: String },
     /// 页面未找到
     NotFound,
 }
 
 impl AppRoute {
     /// 将 URL path 解析为路由枚举
+    ///
+    /// 忽略末尾的 `/`，`/dashboard/` 与 `/dashboard` 解析为同一路由
     pub fn from_path(path: &str) -> Self {
+        let path = match path.trim_end_matches('/') {
+            "" => "/",
+            trimmed => trimmed,
+        };
         match path {
             "/" | "/login" => Self::Login,
             "/dashboard" => Self::Dashboard,
-            _ => Self::NotFound,
+            _ => path
+                .strip_prefix(PROJECT_DETAIL_PREFIX)
+                .filter(|segment| !segment.is_empty() && !segment.contains('/'))
+                .and_then(decode_segment)
+                .map(|key| Self::ProjectDetail { key })
+                .unwrap_or(Self::NotFound),
         }
     }
 
     /// 获取路由对应的 URL path
-    pub fn to_path(&self) -> &'static str {
+    ///
+    /// 项目 key 含有 `/`、`>` 等字符，编码为单个路径段
+    pub fn to_path(&self) -> String {
         match self {
-            Self::Login => "/",
-            Self::Dashboard => "/dashboard",
-            Self::NotFound => "/404",
+            Self::Login => "/".to_string(),
+            Self::Dashboard => "/dashboard".to_string(),
+            Self::ProjectDetail { key } => {
+                format!("{}{}", PROJECT_DETAIL_PREFIX, encode_segment(key))
+            }
+            Self::NotFound => "/404".to_string(),
         }
     }
 
     /// **核心守卫逻辑：定义该路由是否需要认证**
     pub fn requires_auth(&self) -> bool {
-        matches!(self, Self::Dashboard)
+        matches!(self, Self::Dashboard | Self::ProjectDetail { .. })
     }
 
     /// 定义已认证用户是否应该离开此路由（如登录页）
@@ -57,8 +78,139 @@ impl AppRoute {
     }
 }
 
+/// 按 RFC 3986 百分号编码路径段，仅保留非保留字符
+fn encode_segment(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    out
+}
+
+/// 解码百分号编码的路径段，编码无效或结果不是 UTF-8 时返回 `None`
+fn decode_segment(segment: &str) -> Option<String> {
+    let bytes = segment.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = std::str::from_utf8(bytes.get(i + 1..i + 3)?).ok()?;
+            out.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(out).ok()
+}
+
 impl Display for AppRoute {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.to_path())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_detail_round_trip() {
+        let route = AppRoute::ProjectDetail {
+            key: "rust-lang/rust->me/mirror".to_string(),
+        };
+        let path = route.to_path();
+        assert_eq!(path, "/project/rust-lang%2Frust-%3Eme%2Fmirror");
+        assert_eq!(AppRoute::from_path(&path), route);
+
+        // 非 ASCII 与空格同样可以往返
+        let route = AppRoute::ProjectDetail {
+            key: "所有者/仓 库".to_string(),
+        };
+        assert_eq!(AppRoute::from_path(&route.to_path()), route);
+        assert!(route.requires_auth());
+    }
+
+    #[test]
+    fn test_project_detail_from_path() {
+        // 浏览器可能只编码部分字符
+        assert_eq!(
+            AppRoute::from_path("/project/h-0123abcd"),
+            AppRoute::ProjectDetail {
+                key: "h-0123abcd".to_string()
+            }
+        );
+        assert_eq!(
+            AppRoute::from_path("/project/a%2Fb->c%2Fd"),
+            AppRoute::ProjectDetail {
+                key: "a/b->c/d".to_string()
+            }
+        );
+        // 缺少 key、多余的路径段或无效编码
+        assert_eq!(AppRoute::from_path("/project/"), AppRoute::NotFound);
+        assert_eq!(AppRoute::from_path("/project/a/b"), AppRoute::NotFound);
+        assert_eq!(AppRoute::from_path("/project/%zz"), AppRoute::NotFound);
+        assert_eq!(AppRoute::from_path("/project/%E4"), AppRoute::NotFound);
+    }
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(AppRoute::from_path("/"), AppRoute::Login);
+        assert_eq!(AppRoute::from_path("/login"), AppRoute::Login);
+        assert_eq!(AppRoute::from_path("/dashboard"), AppRoute::Dashboard);
+        assert_eq!(AppRoute::from_path("/unknown"), AppRoute::NotFound);
+        assert_eq!(AppRoute::from_path("/dashboard/extra"), AppRoute::NotFound);
+        assert_eq!(AppRoute::from_path(""), AppRoute::Login);
+    }
+
+    #[test]
+    fn test_from_path_ignores_trailing_slash() {
+        assert_eq!(AppRoute::from_path("/dashboard/"), AppRoute::Dashboard);
+        assert_eq!(AppRoute::from_path("/login/"), AppRoute::Login);
+        assert_eq!(AppRoute::from_path("//"), AppRoute::Login);
+        assert_eq!(
+            AppRoute::from_path("/project/abc/"),
+            AppRoute::ProjectDetail {
+                key: "abc".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_guard_helpers() {
+        assert!(!AppRoute::Login.requires_auth());
+        assert!(AppRoute::Dashboard.requires_auth());
+        assert!(!AppRoute::NotFound.requires_auth());
+
+        assert!(AppRoute::Login.should_redirect_when_authenticated());
+        assert!(!AppRoute::Dashboard.should_redirect_when_authenticated());
+        assert!(!AppRoute::NotFound.should_redirect_when_authenticated());
+
+        // 重定向目标本身不能再次触发同方向的重定向，否则会循环
+        let failure = AppRoute::auth_failure_redirect();
+        assert_eq!(failure, AppRoute::Login);
+        assert!(!failure.requires_auth());
+        let success = AppRoute::auth_success_redirect();
+        assert_eq!(success, AppRoute::Dashboard);
+        assert!(!success.should_redirect_when_authenticated());
+    }
+
+    #[test]
+    fn test_display_matches_to_path() {
+        assert_eq!(AppRoute::Dashboard.to_string(), "/dashboard");
+        assert_eq!(AppRoute::Login.to_string(), AppRoute::Login.to_path());
+    }
+
+    #[test]
+    fn test_static_routes_round_trip() {
+        for route in [AppRoute::Login, AppRoute::Dashboard, AppRoute::NotFound] {
+            assert_eq!(AppRoute::from_path(&route.to_path()), route);
+        }
+        assert_eq!(AppRoute::from_path("/login"), AppRoute::Login);
+    }
+}
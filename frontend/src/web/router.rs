@@ -93,9 +93,9 @@ impl RouterService {
             web_sys::console::log_1(&"[Router] Access Denied. Redirecting to Login.".into());
             let redirect = AppRoute::auth_failure_redirect();
             if use_push {
-                push_history_state(redirect.to_path());
+                push_history_state(&redirect.to_path());
             } else {
-                replace_history_state(redirect.to_path());
+                replace_history_state(&redirect.to_path());
             }
             self.set_route.set(redirect);
             return;
@@ -108,9 +108,9 @@ impl RouterService {
             );
             let redirect = AppRoute::auth_success_redirect();
             if use_push {
-                push_history_state(redirect.to_path());
+                push_history_state(&redirect.to_path());
             } else {
-                replace_history_state(redirect.to_path());
+                replace_history_state(&redirect.to_path());
             }
             self.set_route.set(redirect);
             return;
@@ -119,9 +119,9 @@ impl RouterService {
         // --- Step 2: 加载页面 (更新状态) ---
         // 验证通过，推入 History 并更新 UI
         if use_push {
-            push_history_state(target_route.to_path());
+            push_history_state(&target_route.to_path());
         } else {
-            replace_history_state(target_route.to_path());
+            replace_history_state(&target_route.to_path());
         }
         self.set_route.set(target_route);
     }
@@ -140,7 +140,7 @@ impl RouterService {
             if target_route.requires_auth() && !is_auth {
                 // 阻止访问受保护页面
                 let redirect = AppRoute::auth_failure_redirect();
-                replace_history_state(redirect.to_path());
+                replace_history_state(&redirect.to_path());
                 set_route.set(redirect);
             } else {
                 set_route.set(target_route);
@@ -171,7 +171,7 @@ impl RouterService {
                 // 用户刚登录，如果在登录页则重定向到面板
                 if route.should_redirect_when_authenticated() {
                     let redirect = AppRoute::auth_success_redirect();
-                    push_history_state(redirect.to_path());
+                    push_history_state(&redirect.to_path());
                     set_route.set(redirect);
                     web_sys::console::log_1(
                         &"[Router] Auth state changed: logged in, redirecting to dashboard.".into(),
@@ -181,7 +181,7 @@ impl RouterService {
                 // 用户登出，如果在受保护页面则重定向到登录
                 if route.requires_auth() {
                     let redirect = AppRoute::auth_failure_redirect();
-                    push_history_state(redirect.to_path());
+                    push_history_state(&redirect.to_path());
                     set_route.set(redirect);
                     web_sys::console::log_1(
                         &"[Router] Auth state changed: logged out, redirecting to login.".into(),
@@ -255,26 +255,57 @@ pub fn RouterOutlet(
     }
 }
 
-// #[allow(dead_code)]
-// #[component]
-// pub fn Link(
-//     /// 目标路径
-//     #[prop(into)]
-//     to: String,
-//     /// 子内容
-//     children: Children,
-// ) -> impl IntoView {
-//     let router = use_router();
-
-//     let to_clone = to.clone();
-//     let on_click = move |ev: web_sys::MouseEvent| {
-//         ev.prevent_default();
-//         router.navigate(&to_clone);
-//     };
-
-//     view! {
-//         <a href=to on:click=on_click>
-//             {children()}
-//         </a>
-//     }
-// }
+/// 是否由路由接管链接点击
+///
+/// 仅接管未被处理过的左键单击；中键与带 Ctrl/Meta/Shift/Alt 的点击交给浏览器
+/// (新标签页、新窗口、下载等)
+fn should_intercept_click(button: i16, modified: bool, default_prevented: bool) -> bool {
+    button == 0 && !modified && !default_prevented
+}
+
+/// 应用内导航链接
+///
+/// 渲染为带 `href` 的 `<a>`，左键单击时通过 [`RouterService::navigate`] 切换路由而不重新加载页面，
+/// 因此目标路由同样经过守卫 (未认证时访问受保护页面会重定向到登录页)。
+/// 样式等属性可通过 `attr:class` 透传到 `<a>` 上。
+#[component]
+pub fn Link(
+    /// 目标路径
+    #[prop(into)]
+    to: String,
+    /// 子内容
+    children: Children,
+) -> impl IntoView {
+    let router = use_router();
+
+    let target = to.clone();
+    let on_click = move |ev: leptos::ev::MouseEvent| {
+        let modified = ev.ctrl_key() || ev.meta_key() || ev.shift_key() || ev.alt_key();
+        if !should_intercept_click(ev.button(), modified, ev.default_prevented()) {
+            return;
+        }
+        ev.prevent_default();
+        router.navigate(&target);
+    };
+
+    view! {
+        <a href=to on:click=on_click>
+            {children()}
+        </a>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_intercept_click() {
+        // 普通左键单击由路由处理
+        assert!(should_intercept_click(0, false, false));
+        // 中键、修饰键与已被处理的点击保留浏览器默认行为
+        assert!(!should_intercept_click(1, false, false));
+        assert!(!should_intercept_click(0, true, false));
+        assert!(!should_intercept_click(0, false, true));
+    }
+}
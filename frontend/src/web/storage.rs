@@ -2,6 +2,9 @@
 //!
 //! 使用 `web_sys::Storage` 替代 `gloo-storage`，提供简洁的本地存储接口。
 
+use crate::serde_helper::{from_json_string, to_json_string};
+use serde::{Serialize, de::DeserializeOwned};
+
 /// 本地存储操作封装
 ///
 /// 提供静态方法访问浏览器 LocalStorage API。
@@ -37,6 +40,20 @@ impl LocalStorage {
             .is_some()
     }
 
+    /// 获取以 JSON 存储的值
+    ///
+    /// 键不存在或解析失败时返回 `None`
+    pub fn get_json<T: DeserializeOwned>(key: &str) -> Option<T> {
+        from_json_string(&Self::get(key)?).ok()
+    }
+
+    /// 以 JSON 形式存储值
+    pub fn set_json<T: Serialize>(key: &str, value: &T) -> bool {
+        to_json_string(value)
+            .map(|json| Self::set(key, &json))
+            .unwrap_or(false)
+    }
+
     /// 删除存储的键值对
     ///
     /// # 参数
@@ -51,3 +68,28 @@ impl LocalStorage {
             .is_some()
     }
 }
+
+/// 将值放到最近使用列表的最前面
+///
+/// 已存在的相同值会被移除 (去重)，列表长度不超过 `cap`。
+pub fn push_recent(list: &mut Vec<String>, value: &str, cap: usize) {
+    list.retain(|v| v != value);
+    list.insert(0, value.to_string());
+    list.truncate(cap);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_recent_dedup_and_cap() {
+        let mut list = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        push_recent(&mut list, "b", 3);
+        assert_eq!(list, ["b", "a", "c"]);
+
+        push_recent(&mut list, "d", 3);
+        assert_eq!(list, ["d", "b", "a"]);
+    }
+}
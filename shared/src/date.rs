@@ -44,6 +44,20 @@ impl Timestamp {
     pub const fn as_millis_i64(&self) -> i64 {
         self.0
     }
+
+    /// 带符号的毫秒差 `self - other`
+    ///
+    /// 与 `Sub` 不同，结果不会截断为 0，可用于判断逾期等场景
+    #[inline]
+    pub const fn signed_diff_millis(&self, other: Timestamp) -> i64 {
+        self.0 - other.0
+    }
+
+    /// 带符号的秒差 `self - other` (向零取整)
+    #[inline]
+    pub const fn signed_diff_secs(&self, other: Timestamp) -> i64 {
+        self.signed_diff_millis(other) / 1000
+    }
 }
 
 impl From<i64> for Timestamp {
@@ -91,9 +105,11 @@ impl Sub<Timestamp> for Timestamp {
 /// 操作型时间类型，封装 js_sys::Date
 ///
 /// 用于获取当前时间、解析时间字符串等操作
+#[cfg(target_arch = "wasm32")]
 #[derive(Debug, Clone)]
 pub struct Date(js_sys::Date);
 
+#[cfg(target_arch = "wasm32")]
 impl Date {
     /// 获取当前时间
     #[inline]
@@ -150,6 +166,140 @@ impl Date {
     }
 }
 
+/// 非 wasm 目标 (宿主机单元测试等) 下的替代实现
+///
+/// js_sys 在 wasm 之外不可用，这里直接存储毫秒时间戳，
+/// 当前时间取自 `SystemTime`，解析仅支持 RFC 3339 (GitHub API 使用的格式)
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct Date(i64);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Date {
+    /// 获取当前时间
+    #[inline]
+    pub fn now() -> Self {
+        Self(Self::now_timestamp().0)
+    }
+
+    /// 获取当前时间的毫秒时间戳
+    pub fn now_timestamp() -> Timestamp {
+        let ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as i64)
+            .unwrap_or(0);
+        Timestamp(ms)
+    }
+
+    /// 从毫秒时间戳创建
+    #[inline]
+    pub fn from_timestamp(ts: Timestamp) -> Self {
+        Self(ts.0)
+    }
+
+    /// 从 RFC 3339 字符串解析
+    ///
+    /// 返回 None 如果解析失败
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::parse_timestamp(s).map(Self::from_timestamp)
+    }
+
+    /// 解析字符串并直接返回时间戳
+    ///
+    /// 返回 None 如果解析失败
+    pub fn parse_timestamp(s: &str) -> Option<Timestamp> {
+        rfc3339::parse_millis(s).map(Timestamp)
+    }
+
+    /// 转换为时间戳
+    #[inline]
+    pub fn timestamp(&self) -> Timestamp {
+        Timestamp(self.0)
+    }
+
+    /// 获取毫秒值
+    #[inline]
+    pub fn as_millis(&self) -> i64 {
+        self.0
+    }
+}
+
+/// 宿主机下的最小 RFC 3339 解析器
+///
+/// 支持 `YYYY-MM-DD`、`YYYY-MM-DDTHH:MM:SS[.fff][Z|±HH:MM]`
+#[cfg(not(target_arch = "wasm32"))]
+mod rfc3339 {
+    fn num(s: &str) -> Option<i64> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        s.parse().ok()
+    }
+
+    /// 公历日期转换为自 1970-01-01 起的天数
+    fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+        let y = if m <= 2 { y - 1 } else { y };
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = y - era * 400;
+        let mp = (m + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + d - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    pub(super) fn parse_millis(s: &str) -> Option<i64> {
+        let s = s.trim();
+        let (date, time) = match s.find(['T', 't', ' ']) {
+            Some(i) => (&s[..i], Some(&s[i + 1..])),
+            None => (s, None),
+        };
+
+        let mut parts = date.splitn(3, '-');
+        let year = num(parts.next()?)?;
+        let month = num(parts.next()?)?;
+        let day = num(parts.next()?)?;
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return None;
+        }
+        let days = days_from_civil(year, month, day);
+
+        let Some(time) = time else {
+            return Some(days * 86_400_000);
+        };
+
+        // 拆分时区后缀
+        let (clock, offset_secs) = if let Some(clock) = time.strip_suffix(['Z', 'z']) {
+            (clock, 0)
+        } else if let Some(i) = time.rfind(['+', '-']) {
+            let sign = if &time[i..=i] == "-" { -1 } else { 1 };
+            let (h, m) = time[i + 1..].split_once(':')?;
+            (&time[..i], sign * (num(h)? * 3600 + num(m)? * 60))
+        } else {
+            (time, 0)
+        };
+
+        let (clock, frac_ms) = match clock.split_once('.') {
+            Some((c, f)) => {
+                // 只保留毫秒精度
+                let digits: String = f.chars().chain("000".chars()).take(3).collect();
+                (c, num(&digits)?)
+            }
+            None => (clock, 0),
+        };
+
+        let mut hms = clock.splitn(3, ':');
+        let hour = num(hms.next()?)?;
+        let minute = num(hms.next()?)?;
+        let second = hms.next().map(num).unwrap_or(Some(0))?;
+        if hour > 23 || minute > 59 || second > 60 {
+            return None;
+        }
+
+        let secs = days * 86_400 + hour * 3600 + minute * 60 + second - offset_secs;
+        Some(secs * 1000 + frac_ms)
+    }
+}
+
 impl From<Timestamp> for Date {
     fn from(ts: Timestamp) -> Self {
         Self::from_timestamp(ts)
@@ -167,3 +317,62 @@ impl From<&Date> for Timestamp {
         date.timestamp()
     }
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signed_diff_keeps_negative_values() {
+        let due = Timestamp::new(10_000);
+        let now = Timestamp::new(75_500);
+        assert_eq!(due.signed_diff_millis(now), -65_500);
+        assert_eq!(due.signed_diff_secs(now), -65);
+        // 饱和减法仍然截断为 0
+        assert_eq!(due - now, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_signed_diff_positive_and_zero() {
+        let due = Timestamp::new(75_500);
+        let now = Timestamp::new(10_000);
+        assert_eq!(due.signed_diff_millis(now), 65_500);
+        assert_eq!(due.signed_diff_secs(now), 65);
+        assert_eq!(due.signed_diff_millis(now), (due - now).as_millis() as i64);
+
+        assert_eq!(now.signed_diff_millis(now), 0);
+        assert_eq!(now.signed_diff_secs(now), 0);
+        // 不足一秒时向零取整
+        assert_eq!(Timestamp::new(999).signed_diff_secs(Timestamp::new(0)), 0);
+        assert_eq!(Timestamp::new(0).signed_diff_secs(Timestamp::new(999)), 0);
+    }
+
+    #[test]
+    fn test_parse_rfc3339_utc() {
+        let ts = Date::parse_timestamp("2024-01-15T10:30:00Z").unwrap();
+        assert_eq!(ts.as_millis(), 1_705_314_600_000);
+    }
+
+    #[test]
+    fn test_parse_rfc3339_offset_and_fraction() {
+        let utc = Date::parse_timestamp("2024-01-15T10:30:00.250Z").unwrap();
+        let shifted = Date::parse_timestamp("2024-01-15T18:30:00.250+08:00").unwrap();
+        assert_eq!(utc, shifted);
+        assert_eq!(utc.as_millis() % 1000, 250);
+    }
+
+    #[test]
+    fn test_parse_date_only_and_invalid() {
+        assert_eq!(
+            Date::parse_timestamp("1970-01-02").unwrap().as_millis(),
+            86_400_000
+        );
+        assert!(Date::parse_timestamp("not a date").is_none());
+        assert!(Date::parse_timestamp("2024-13-01T00:00:00Z").is_none());
+    }
+
+    #[test]
+    fn test_now_is_after_epoch() {
+        assert!(Date::now_timestamp().as_millis() > 1_600_000_000_000);
+    }
+}
@@ -13,12 +13,20 @@ pub use date::{Date, Timestamp};
 // =========================================================
 
 pub mod protocol;
+pub mod validate;
 
 pub const PREFIX_VERSION: &str = "v:";
 pub const HEADER_AUTH_KEY: &str = "X-Auth-Key";
 pub const CHECK_INTERVAL: DurationSecs = DurationSecs::from_hours(1);
 pub const RETRY_INTERVAL: DurationSecs = DurationSecs::from_secs(10);
 
+/// 64 位 FNV-1a 哈希，用于调度抖动与 ETag 等非加密场景
+pub fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325u64, |h, &b| {
+        (h ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
 // =========================================================
 // DurationSecs - 避免 flt2dec 的秒数类型
 // =========================================================
@@ -36,11 +44,21 @@ impl DurationSecs {
         Self(secs)
     }
 
+    #[inline]
+    pub const fn from_minutes(minutes: u64) -> Self {
+        Self(minutes * 60)
+    }
+
     #[inline]
     pub const fn from_hours(hours: u64) -> Self {
         Self(hours * 3600)
     }
 
+    #[inline]
+    pub const fn from_days(days: u64) -> Self {
+        Self(days * 86400)
+    }
+
     #[inline]
     pub const fn as_secs(&self) -> u64 {
         self.0
@@ -52,6 +70,108 @@ impl DurationSecs {
     }
 }
 
+impl std::ops::Add for DurationSecs {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl DurationSecs {
+    /// 格式化为人类可读的字符串，例如 `1h 30m`、`2d 4h`、`45s`
+    ///
+    /// 省略为 0 的单位，整体为 0 时返回 `0s`
+    pub fn humanize(&self) -> String {
+        if self.0 == 0 {
+            return "0s".to_string();
+        }
+        let parts = [
+            (self.0 / 86400, 'd'),
+            (self.0 % 86400 / 3600, 'h'),
+            (self.0 % 3600 / 60, 'm'),
+            (self.0 % 60, 's'),
+        ];
+        parts
+            .iter()
+            .filter(|(n, _)| *n > 0)
+            .map(|(n, unit)| format!("{}{}", n, unit))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl std::fmt::Display for DurationSecs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.humanize())
+    }
+}
+
+/// 解析 `DurationSecs` 字符串失败
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDurationError(String);
+
+impl std::fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid duration: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+impl std::str::FromStr for DurationSecs {
+    type Err = ParseDurationError;
+
+    /// 解析 `90m`、`1h30m`、`1h 30m`、`2d`、`45s` 等格式
+    ///
+    /// 单位为 `d`/`h`/`m`/`s`，不带单位的纯数字按秒处理
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseDurationError(s.to_string());
+        let input = s.trim();
+        if input.is_empty() {
+            return Err(err());
+        }
+
+        let mut total: u64 = 0;
+        let mut number: Option<u64> = None;
+        for c in input.chars() {
+            if let Some(d) = c.to_digit(10) {
+                let n = number.unwrap_or(0);
+                number = Some(
+                    n.checked_mul(10)
+                        .and_then(|n| n.checked_add(d as u64))
+                        .ok_or_else(err)?,
+                );
+                continue;
+            }
+            if c.is_whitespace() {
+                continue;
+            }
+            let multiplier = match c.to_ascii_lowercase() {
+                'd' => 86400,
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return Err(err()),
+            };
+            let n = number.take().ok_or_else(err)?;
+            total = n
+                .checked_mul(multiplier)
+                .and_then(|v| total.checked_add(v))
+                .ok_or_else(err)?;
+        }
+
+        // 末尾不带单位的数字按秒计算，但仅允许出现在纯数字输入中
+        if let Some(n) = number {
+            if total != 0 || !input.chars().all(|c| c.is_ascii_digit()) {
+                return Err(err());
+            }
+            total = n;
+        }
+        Ok(Self(total))
+    }
+}
+
 impl From<Duration> for DurationSecs {
     fn from(d: Duration) -> Self {
         Self(d.as_secs())
@@ -73,6 +193,8 @@ impl From<DurationSecs> for Duration {
 pub enum ComparisonMode {
     PublishedAt,
     UpdatedAt,
+    /// Release 创建时间，不受之后编辑 Release 的影响
+    CreatedAt,
 }
 
 impl Default for ComparisonMode {
@@ -81,6 +203,43 @@ impl Default for ComparisonMode {
     }
 }
 
+impl ComparisonMode {
+    /// 与序列化结果一致的名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ComparisonMode::PublishedAt => "published_at",
+            ComparisonMode::UpdatedAt => "updated_at",
+            ComparisonMode::CreatedAt => "created_at",
+        }
+    }
+}
+
+/// 上游版本来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceKind {
+    /// GitHub Releases (`/releases/latest`)
+    #[default]
+    Releases,
+    /// Git Tags (`/tags`)，适用于只打 Tag 不发 Release 的仓库
+    Tags,
+}
+
+/// 上游代码托管平台
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Provider {
+    /// GitHub (`api.github.com`)
+    #[default]
+    #[serde(rename = "github")]
+    GitHub,
+    /// GitLab (`gitlab.com`)，仅支持 Releases 来源
+    #[serde(rename = "gitlab")]
+    GitLab,
+    /// 自托管 Gitea / Forgejo，`base_url` 为实例地址 (如 `https://codeberg.org`)，仅支持 Releases 来源
+    #[serde(rename = "gitea")]
+    Gitea { base_url: String },
+}
+
 /// 监控状态：暂停或运行中（附带下一次检查时间）
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "status", rename_all = "snake_case")]
@@ -117,7 +276,7 @@ impl MonitorState {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct BaseConfig {
     pub upstream_owner: String,
     pub upstream_repo: String,
@@ -146,6 +305,9 @@ impl BaseConfig {
 pub struct TimeConfig {
     pub check_interval: DurationSecs,
     pub retry_interval: DurationSecs,
+    /// 调度抖动上限，每次调度会额外延迟 `[0, jitter_secs)` 秒，为 0 时不抖动
+    #[serde(default)]
+    pub jitter_secs: DurationSecs,
 }
 
 impl Default for TimeConfig {
@@ -153,11 +315,44 @@ impl Default for TimeConfig {
         Self {
             check_interval: CHECK_INTERVAL,
             retry_interval: RETRY_INTERVAL,
+            jitter_secs: DurationSecs::from_secs(0),
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+impl TimeConfig {
+    /// 计算指定项目的调度抖动偏移，范围为 `[0, jitter_secs)`
+    ///
+    /// 以 unique_key 的 FNV-1a 哈希为种子，同一项目的偏移保持稳定，
+    /// 不同项目的偏移相互错开，从而分散同一间隔的 Alarm
+    pub fn jitter_for(&self, unique_key: &str) -> DurationSecs {
+        let jitter = self.jitter_secs.as_secs();
+        if jitter == 0 {
+            return DurationSecs::from_secs(0);
+        }
+        DurationSecs::from_secs(fnv1a_64(unique_key.as_bytes()) % jitter)
+    }
+}
+
+/// 通用出站 Webhook 配置
+///
+/// `template` 为 JSON 模板，支持 `{repo}`、`{version}`、`{old_version}` 占位符
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WebhookConfig {
+    pub url: String,
+    pub template: String,
+}
+
+/// Telegram Bot 通知配置
+///
+/// 只保存 Bot Token 所在的 Secret 名称，而不是 Token 本身
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TelegramConfig {
+    pub bot_token_secret: String,
+    pub chat_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct CreateProjectRequest {
     pub base_config: BaseConfig,
 
@@ -170,6 +365,82 @@ pub struct CreateProjectRequest {
     pub dispatch_token_secret: Option<String>,
 
     pub comparison_mode: ComparisonMode,
+
+    /// 上游托管平台，默认为 GitHub (Dispatch 始终发往 GitHub)
+    #[serde(default)]
+    pub provider: Provider,
+
+    /// 版本来源，默认为 Releases
+    #[serde(default)]
+    pub source_kind: SourceKind,
+
+    /// 可选的 Tag 名称正则，仅当最新版本的 tag_name 匹配时才触发
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag_pattern: Option<String>,
+
+    /// 可选的 semver 版本约束 (如 `>=1.0, <2.0`)，仅跟踪满足约束的版本
+    ///
+    /// Tag 去掉开头的 `v` 后按 semver 解析，无法解析的 Tag 视为不满足
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_constraint: Option<String>,
+
+    /// Dispatch 载荷中的 `version` 去掉 Tag 开头的 `v`/`V` (如 `v1.2.3` -> `1.2.3`)，
+    /// 版本记录与比较仍使用原始 Tag
+    #[serde(default)]
+    pub strip_tag_prefix: bool,
+
+    /// 可选的 Discord Webhook 地址，成功触发 Dispatch 后发送通知
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify_webhook: Option<String>,
+
+    /// 可选的 Slack Incoming Webhook 地址，成功触发 Dispatch 后发送通知
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_webhook: Option<String>,
+
+    /// 可选的 Telegram Bot 通知，成功触发 Dispatch 后发送消息
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub telegram: Option<TelegramConfig>,
+
+    /// 可选的通用 Webhook，成功触发 Dispatch 后按模板发送 JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<WebhookConfig>,
+
+    /// 试运行：照常检测新版本并记录，但不触发 Dispatch 与通知
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// 以首次检查到的版本作为基线：首次检查只记录版本不触发 Dispatch，之后的新版本才触发
+    #[serde(default)]
+    pub initialize_baseline: bool,
+
+    /// 可选的静默时段 `(start, end)`，UTC 小时，范围为 `[start, end)`；
+    /// `start > end` 时跨越午夜 (如 `(22, 6)`)。时段内检测到的新版本推迟到时段结束后再 Dispatch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quiet_hours: Option<(u8, u8)>,
+}
+
+const MILLIS_PER_HOUR: i64 = 3_600_000;
+
+impl CreateProjectRequest {
+    /// `now` 处于静默时段内时，返回距时段结束的时长；不在时段内或未配置时返回 `None`
+    pub fn quiet_hours_remaining(&self, now: Timestamp) -> Option<DurationSecs> {
+        let (start, end) = self.quiet_hours?;
+        let (start, end) = (i64::from(start), i64::from(end));
+        let hour = now.as_millis().div_euclid(MILLIS_PER_HOUR).rem_euclid(24);
+        let quiet = if start <= end {
+            start <= hour && hour < end
+        } else {
+            hour >= start || hour < end
+        };
+        if !quiet {
+            return None;
+        }
+        let hours_left = (end - hour).rem_euclid(24);
+        let millis_left =
+            hours_left * MILLIS_PER_HOUR - now.as_millis().rem_euclid(MILLIS_PER_HOUR);
+        // 向上取整，确保唤醒时已离开静默时段
+        Some(DurationSecs::from_secs((millis_left as u64).div_ceil(1000)))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -177,15 +448,35 @@ pub struct ProjectConfig {
     pub unique_key: String,
     /// 监控状态：暂停或运行中（附带下一次检查时间）
     pub state: MonitorState,
+    /// 是否启用：禁用的项目保留配置，但不再调度检查，也不计入列表
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 上一次成功触发 Dispatch 的时间，从未触发时为 `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_dispatch_at: Option<Timestamp>,
+    /// 最近一次检查的结果分类，从未检查时为 `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_outcome: Option<CheckOutcome>,
+    /// 检查 (定时与手动触发) 累计失败的次数 (删除项目后清零)
+    #[serde(default)]
+    pub failed_checks: u64,
     #[serde(flatten)]
     pub request: CreateProjectRequest,
 }
 
+fn default_enabled() -> bool {
+    true
+}
+
 impl ProjectConfig {
     pub fn new(request: CreateProjectRequest) -> Self {
         let mut config = ProjectConfig {
             unique_key: String::new(),
             state: MonitorState::Paused, // 初始状态为暂停，setup 时会更新
+            enabled: true,
+            last_dispatch_at: None,
+            last_outcome: None,
+            failed_checks: 0,
             request,
         };
         config.unique_key = config.generate_unique_key();
@@ -201,9 +492,433 @@ impl ProjectConfig {
     pub fn generate_unique_key(&self) -> String {
         self.request.base_config.generate_unique_key()
     }
+
+    /// 可读的项目标识 `owner/repo->owner/repo`
+    ///
+    /// 后端开启哈希 key 时 `unique_key` 是不可读的短 key，展示时应使用此方法
+    #[inline]
+    pub fn display_key(&self) -> String {
+        self.generate_unique_key()
+    }
+
+    /// 是否处于运行状态 (未暂停)，不考虑 `enabled`
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        !self.state.is_paused()
+    }
+
+    /// 下一次检查时间，暂停时为 `None`
+    #[inline]
+    pub fn next_check_at(&self) -> Option<Timestamp> {
+        self.state.next_check_at()
+    }
+
+    /// 比较可修改的字段 (检查/重试间隔、调度抖动、比较模式、Token Secret 与启用状态)，
+    /// 返回 `self` 到 `other` 之间发生变化的字段，顺序固定
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let (old, new) = (&self.request, &other.request);
+        let secret = |r: &CreateProjectRequest| {
+            r.dispatch_token_secret
+                .clone()
+                .unwrap_or_else(|| "(default)".to_string())
+        };
+        let fields = [
+            (
+                "check_interval",
+                old.time_config.check_interval.to_string(),
+                new.time_config.check_interval.to_string(),
+            ),
+            (
+                "retry_interval",
+                old.time_config.retry_interval.to_string(),
+                new.time_config.retry_interval.to_string(),
+            ),
+            (
+                "jitter_secs",
+                old.time_config.jitter_secs.to_string(),
+                new.time_config.jitter_secs.to_string(),
+            ),
+            (
+                "comparison_mode",
+                old.comparison_mode.as_str().to_string(),
+                new.comparison_mode.as_str().to_string(),
+            ),
+            ("dispatch_token_secret", secret(old), secret(new)),
+            (
+                "enabled",
+                self.enabled.to_string(),
+                other.enabled.to_string(),
+            ),
+        ];
+        fields
+            .into_iter()
+            .filter(|(_, old, new)| old != new)
+            .map(|(field, old, new)| FieldChange {
+                field: field.to_string(),
+                old,
+                new,
+            })
+            .collect()
+    }
+}
+
+/// 配置中一个字段的变化，值为便于阅读的文本 (间隔如 `1h 30m`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: String,
+    pub new: String,
+}
+
+/// 单次检查的结果分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckOutcome {
+    /// 发现新版本并成功触发 Dispatch
+    Success,
+    /// 上游无新版本
+    NoChange,
+    /// 检查失败
+    Error,
+}
+
+/// 检查历史记录中的一条事件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckEvent {
+    pub at: Timestamp,
+    pub outcome: CheckOutcome,
+    pub detail: String,
+}
+
+impl CheckEvent {
+    /// 以当前时间创建事件
+    pub fn now(outcome: CheckOutcome, detail: impl Into<String>) -> Self {
+        Self {
+            at: Date::now_timestamp(),
+            outcome,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// 管理操作审计日志中的一条记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub at: Timestamp,
+    /// 执行操作的鉴权密钥标签
+    pub actor: String,
+    /// 操作名称，如 `create`、`delete`、`switch`
+    pub action: String,
+    /// 操作对象，通常为项目的 unique_key
+    pub target: String,
+    /// 更新操作中实际发生变化的字段
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<FieldChange>,
+}
+
+/// 注册失败 (Monitor setup 出错) 的项目记录，用于排查
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadLetter {
+    pub at: Timestamp,
+    pub unique_key: String,
+    /// 完整的错误信息 (含错误链)
+    pub error: String,
+}
+
+/// 项目列表过滤条件
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProjectFilter {
+    /// 子串匹配 `upstream_owner/upstream_repo` 或 `my_owner/my_repo` (不区分大小写)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    /// 按暂停状态过滤
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paused: Option<bool>,
+}
+
+impl ProjectFilter {
+    /// 判断项目是否满足过滤条件
+    pub fn matches(&self, config: &ProjectConfig) -> bool {
+        if let Some(paused) = self.paused
+            && config.state.is_paused() != paused
+        {
+            return false;
+        }
+
+        match self.q.as_deref().map(str::trim) {
+            Some(q) if !q.is_empty() => {
+                let q = q.to_lowercase();
+                let base = &config.request.base_config;
+                let upstream = format!("{}/{}", base.upstream_owner, base.upstream_repo);
+                let target = format!("{}/{}", base.my_owner, base.my_repo);
+                upstream.to_lowercase().contains(&q) || target.to_lowercase().contains(&q)
+            }
+            _ => true,
+        }
+    }
+}
+
+/// 分页查询的一页结果
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ProjectPage {
+    pub items: Vec<ProjectConfig>,
+    /// 下一页的游标，`None` 表示已到最后一页
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+    /// 本页中读取配置失败 (例如存储数据损坏) 的 unique_key
+    #[serde(default)]
+    pub failed_keys: Vec<String>,
+}
+
+/// 宽松列表查询的结果：读取失败的项目不会让整个列表失败，而是记录在 `failed_keys` 中
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ProjectListing {
+    pub projects: Vec<ProjectConfig>,
+    /// 读取配置失败 (例如存储数据损坏) 的 unique_key
+    #[serde(default)]
+    pub failed_keys: Vec<String>,
+}
+
+/// 项目配置及其版本状态，由详细列表接口返回
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProjectWithState {
+    pub config: ProjectConfig,
+    /// 最近一次记录的版本 Tag，尚未记录时为 `None`
+    #[serde(default)]
+    pub current_version: Option<String>,
+    /// 最近一次检查的时间，从未检查时为 `None`
+    #[serde(default)]
+    pub checked_at: Option<Timestamp>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct DeleteTarget {
     pub id: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a_64_known_values() {
+        assert_eq!(fnv1a_64(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a_64(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn test_jitter_disabled_by_default() {
+        let time_config = TimeConfig::default();
+        assert_eq!(
+            time_config.jitter_for("a/b->c/d"),
+            DurationSecs::from_secs(0)
+        );
+    }
+
+    #[test]
+    fn test_jitter_is_deterministic_and_bounded() {
+        let time_config = TimeConfig {
+            jitter_secs: DurationSecs::from_secs(300),
+            ..TimeConfig::default()
+        };
+
+        let a = time_config.jitter_for("owner/repo->me/fork-a");
+        let b = time_config.jitter_for("owner/repo->me/fork-b");
+
+        assert_eq!(a, time_config.jitter_for("owner/repo->me/fork-a"));
+        assert_ne!(a, b);
+        assert!(a < time_config.jitter_secs);
+        assert!(b < time_config.jitter_secs);
+    }
+
+    #[test]
+    fn test_duration_constructors() {
+        assert_eq!(DurationSecs::from_minutes(90).as_secs(), 5400);
+        assert_eq!(DurationSecs::from_days(2), DurationSecs::from_hours(48));
+    }
+
+    #[test]
+    fn test_duration_humanize() {
+        assert_eq!(DurationSecs::from_secs(0).humanize(), "0s");
+        assert_eq!(DurationSecs::from_secs(45).humanize(), "45s");
+        assert_eq!(DurationSecs::from_minutes(90).humanize(), "1h 30m");
+        assert_eq!(DurationSecs::from_hours(1).humanize(), "1h");
+        assert_eq!(DurationSecs::from_secs(86400 + 61).humanize(), "1d 1m 1s");
+        assert_eq!(DurationSecs::from_minutes(90).to_string(), "1h 30m");
+    }
+
+    #[test]
+    fn test_duration_from_str() {
+        let parse = |s: &str| s.parse::<DurationSecs>();
+        assert_eq!(parse("90m"), Ok(DurationSecs::from_minutes(90)));
+        assert_eq!(parse("1h30m"), Ok(DurationSecs::from_minutes(90)));
+        assert_eq!(parse(" 1h 30m "), Ok(DurationSecs::from_minutes(90)));
+        assert_eq!(parse("2d"), Ok(DurationSecs::from_days(2)));
+        assert_eq!(parse("0s"), Ok(DurationSecs::from_secs(0)));
+        assert_eq!(parse("0"), Ok(DurationSecs::from_secs(0)));
+        assert_eq!(parse("120"), Ok(DurationSecs::from_secs(120)));
+    }
+
+    #[test]
+    fn test_duration_from_str_rejects_invalid() {
+        for input in ["", "  ", "h", "1x", "1h30", "m5", "99999999999999999999s"] {
+            assert!(input.parse::<DurationSecs>().is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_duration_humanize_round_trips() {
+        for secs in [0, 1, 59, 60, 3600, 5400, 86400, 90061] {
+            let d = DurationSecs::from_secs(secs);
+            assert_eq!(d.humanize().parse::<DurationSecs>(), Ok(d));
+        }
+    }
+
+    #[test]
+    fn test_project_with_state_round_trip() {
+        let mut config = ProjectConfig::new(CreateProjectRequest {
+            base_config: BaseConfig {
+                upstream_owner: "owner".into(),
+                upstream_repo: "repo".into(),
+                my_owner: "me".into(),
+                my_repo: "fork".into(),
+            },
+            ..Default::default()
+        });
+        config.state = MonitorState::running(Timestamp::new(1_000));
+
+        for (current_version, checked_at) in [
+            (Some("v1.2.3".to_string()), Some(Timestamp::new(500))),
+            (None, None),
+        ] {
+            let item = ProjectWithState {
+                config: config.clone(),
+                current_version,
+                checked_at,
+            };
+            let json = serde_json::to_string(&item).unwrap();
+            let back: ProjectWithState = serde_json::from_str(&json).unwrap();
+            assert_eq!(back.config.unique_key, "owner/repo->me/fork");
+            assert_eq!(back.config.state, config.state);
+            assert_eq!(back.current_version, item.current_version);
+            assert_eq!(back.checked_at, item.checked_at);
+            assert_eq!(serde_json::to_string(&back).unwrap(), json);
+        }
+
+        // 缺省的版本状态字段按 None 处理
+        let json = serde_json::json!({ "config": serde_json::to_value(&config).unwrap() });
+        let item: ProjectWithState = serde_json::from_value(json).unwrap();
+        assert_eq!(item.current_version, None);
+        assert_eq!(item.checked_at, None);
+    }
+
+    #[test]
+    fn test_time_config_without_jitter_deserializes() {
+        let time_config: TimeConfig =
+            serde_json::from_str(r#"{"check_interval":3600,"retry_interval":10}"#).unwrap();
+        assert_eq!(time_config.jitter_secs, DurationSecs::from_secs(0));
+    }
+
+    fn at_hour(hour: i64, minute: i64) -> Timestamp {
+        // 2024-01-15 00:00:00 UTC
+        Timestamp::new(1_705_276_800_000 + hour * MILLIS_PER_HOUR + minute * 60_000)
+    }
+
+    #[test]
+    fn test_quiet_hours_same_day_window() {
+        let request = CreateProjectRequest {
+            quiet_hours: Some((9, 17)),
+            ..Default::default()
+        };
+        assert_eq!(request.quiet_hours_remaining(at_hour(8, 59)), None);
+        assert_eq!(
+            request.quiet_hours_remaining(at_hour(9, 0)),
+            Some(DurationSecs::from_hours(8))
+        );
+        assert_eq!(
+            request.quiet_hours_remaining(at_hour(16, 30)),
+            Some(DurationSecs::from_minutes(30))
+        );
+        assert_eq!(request.quiet_hours_remaining(at_hour(17, 0)), None);
+    }
+
+    #[test]
+    fn test_quiet_hours_wrap_around_midnight() {
+        let request = CreateProjectRequest {
+            quiet_hours: Some((22, 6)),
+            ..Default::default()
+        };
+        assert_eq!(
+            request.quiet_hours_remaining(at_hour(23, 0)),
+            Some(DurationSecs::from_hours(7))
+        );
+        assert_eq!(
+            request.quiet_hours_remaining(at_hour(2, 15)),
+            Some(DurationSecs::from_secs(3 * 3600 + 45 * 60))
+        );
+        assert_eq!(request.quiet_hours_remaining(at_hour(6, 0)), None);
+        assert_eq!(request.quiet_hours_remaining(at_hour(12, 0)), None);
+
+        // 未配置或起止相同时没有静默时段
+        assert_eq!(
+            CreateProjectRequest::default().quiet_hours_remaining(at_hour(23, 0)),
+            None
+        );
+        let empty = CreateProjectRequest {
+            quiet_hours: Some((3, 3)),
+            ..Default::default()
+        };
+        assert_eq!(empty.quiet_hours_remaining(at_hour(3, 0)), None);
+    }
+
+    #[test]
+    fn test_project_config_state_helpers() {
+        let mut config = ProjectConfig::new(CreateProjectRequest::default());
+        assert!(!config.is_running());
+        assert_eq!(config.next_check_at(), None);
+
+        config.state = MonitorState::running(Timestamp::new(42));
+        assert!(config.is_running());
+        assert_eq!(config.next_check_at(), Some(Timestamp::new(42)));
+
+        // enabled 不影响运行状态
+        config.enabled = false;
+        assert!(config.is_running());
+    }
+
+    #[test]
+    fn test_diff_detects_changed_interval() {
+        let old = ProjectConfig::new(CreateProjectRequest::default());
+        let mut new = old.clone();
+        new.request.time_config.check_interval = DurationSecs::from_hours(6);
+
+        assert_eq!(
+            old.diff(&new),
+            vec![FieldChange {
+                field: "check_interval".into(),
+                old: CHECK_INTERVAL.to_string(),
+                new: "6h".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diff_ignores_unchanged_fields() {
+        let old = ProjectConfig::new(CreateProjectRequest::default());
+        assert!(old.diff(&old.clone()).is_empty());
+
+        // 设置为相同的值也不算变化，状态等不可修改的字段不参与比较
+        let mut new = old.clone();
+        new.request.comparison_mode = ComparisonMode::PublishedAt;
+        new.state = MonitorState::running(Timestamp::new(42));
+        new.failed_checks = 3;
+        assert!(old.diff(&new).is_empty());
+
+        new.request.dispatch_token_secret = Some("OTHER_PAT".into());
+        let changes = old.diff(&new);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "dispatch_token_secret");
+        assert_eq!(changes[0].new, "OTHER_PAT");
+    }
+}
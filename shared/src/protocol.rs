@@ -1,4 +1,7 @@
-use crate::{CreateProjectRequest, DeleteTarget, ProjectConfig};
+use crate::{
+    AuditEvent, CheckEvent, ComparisonMode, CreateProjectRequest, DeadLetter, DeleteTarget,
+    FieldChange, ProjectConfig, ProjectWithState, TimeConfig, Timestamp,
+};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 /// HTTP Methods for API Requests
@@ -43,6 +46,38 @@ impl ApiRequest for CreateProjectRequest {
     const METHOD: HttpMethod = HttpMethod::Post;
 }
 
+/// Update the mutable fields of an existing project
+///
+/// Fields left as `None` keep their current value. The stored version state
+/// of the monitor is preserved.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateProjectRequest {
+    pub unique_key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_config: Option<TimeConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comparison_mode: Option<ComparisonMode>,
+    /// An empty string clears the override and falls back to the default PAT secret.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dispatch_token_secret: Option<String>,
+}
+
+/// Response of [`UpdateProjectRequest`]: the updated config (flattened) and the fields
+/// that actually changed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateProjectResponse {
+    #[serde(flatten)]
+    pub config: ProjectConfig,
+    #[serde(default)]
+    pub changes: Vec<FieldChange>,
+}
+
+impl ApiRequest for UpdateProjectRequest {
+    type Response = UpdateProjectResponse;
+    const PATH: &'static str = "/api/projects";
+    const METHOD: HttpMethod = HttpMethod::Put;
+}
+
 /// Delete a project
 /// We create a specific request struct for better clarity,
 /// but the backend currently expects DeleteTarget.
@@ -84,6 +119,104 @@ impl ApiRequest for SwitchMonitorRequest {
     const METHOD: HttpMethod = HttpMethod::Post;
 }
 
+/// Pause or resume every registered project at once
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SwitchAllRequest {
+    pub paused: bool,
+}
+
+/// Outcome of a [`SwitchAllRequest`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SwitchAllReport {
+    /// `(unique_key, applied)` pairs; `applied` is `false` when the project was
+    /// already in the requested state
+    pub results: Vec<(String, bool)>,
+    /// Projects whose state could not be read or switched
+    #[serde(default)]
+    pub failed_keys: Vec<String>,
+}
+
+impl ApiRequest for SwitchAllRequest {
+    type Response = SwitchAllReport;
+    const PATH: &'static str = "/api/projects/switch-all";
+    const METHOD: HttpMethod = HttpMethod::Post;
+}
+
+/// Confirmation token required by [`DeleteAllRequest`]
+pub const DELETE_ALL_CONFIRMATION: &str = "DELETE-ALL";
+
+/// Delete every registered project, including disabled ones
+///
+/// `confirm` must equal [`DELETE_ALL_CONFIRMATION`]; responds with the number
+/// of projects that were deleted.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeleteAllRequest {
+    #[serde(default)]
+    pub confirm: String,
+}
+
+impl ApiRequest for DeleteAllRequest {
+    type Response = usize;
+    const PATH: &'static str = "/api/projects/all";
+    const METHOD: HttpMethod = HttpMethod::Delete;
+}
+
+/// Current format version of [`ConfigBackup`]
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// Full configuration backup of the monitor fleet
+///
+/// Returned by `GET /api/export` and accepted by `POST /api/import`.
+/// Disabled projects are included; stored version state is not.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigBackup {
+    pub version: u32,
+    pub projects: Vec<ProjectConfig>,
+}
+
+/// Export all projects as a [`ConfigBackup`]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportRequest;
+
+impl ApiRequest for ExportRequest {
+    type Response = ConfigBackup;
+    const PATH: &'static str = "/api/export";
+    const METHOD: HttpMethod = HttpMethod::Get;
+}
+
+impl ApiRequest for ConfigBackup {
+    type Response = ImportReport;
+    const PATH: &'static str = "/api/import";
+    const METHOD: HttpMethod = HttpMethod::Post;
+}
+
+/// Outcome of an import, keyed by `unique_key`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportReport {
+    /// Projects that were created
+    pub imported: Vec<String>,
+    /// Projects skipped because the key already exists
+    pub conflicts: Vec<String>,
+    /// Projects that failed to import, with the error message
+    pub failed: Vec<(String, String)>,
+}
+
+/// Enable or disable a project
+///
+/// Disabled projects keep their config but are no longer scheduled and are
+/// left out of the project list.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetEnabledRequest {
+    pub unique_key: String,
+    pub enabled: bool,
+}
+
+impl ApiRequest for SetEnabledRequest {
+    type Response = bool;
+    const PATH: &'static str = "/api/projects/enabled";
+    const METHOD: HttpMethod = HttpMethod::Post;
+}
+
 /// Trigger a check manually
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TriggerCheckRequest {
@@ -95,3 +228,242 @@ impl ApiRequest for TriggerCheckRequest {
     const PATH: &'static str = "/api/projects/trigger";
     const METHOD: HttpMethod = HttpMethod::Post;
 }
+
+/// Dispatch the latest upstream release right away, skipping the version comparison
+///
+/// The stored version is left untouched. Responds with the dispatched tag;
+/// unknown projects get a 404.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ForceDispatchRequest {
+    pub unique_key: String,
+}
+
+impl ApiRequest for ForceDispatchRequest {
+    type Response = String;
+    const PATH: &'static str = "/api/projects/force-dispatch";
+    const METHOD: HttpMethod = HttpMethod::Post;
+}
+
+/// Move the next check of a running project to `next_check_at`
+///
+/// The check interval is unchanged. Past timestamps trigger the check right away;
+/// paused projects are rejected. Returns `false` when the project is not registered.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RescheduleRequest {
+    pub unique_key: String,
+    pub next_check_at: Timestamp,
+}
+
+impl ApiRequest for RescheduleRequest {
+    type Response = bool;
+    const PATH: &'static str = "/api/projects/reschedule";
+    const METHOD: HttpMethod = HttpMethod::Post;
+}
+
+/// Re-arm lost alarms: every running project without a scheduled check gets one again
+///
+/// Responds with the unique keys that were re-armed (sorted).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditRequest;
+
+impl ApiRequest for AuditRequest {
+    type Response = Vec<String>;
+    const PATH: &'static str = "/api/projects/audit";
+    const METHOD: HttpMethod = HttpMethod::Post;
+}
+
+/// Get the recent check history of a project (newest last)
+///
+/// Sent as `GET /api/projects/history?unique_key=...`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetHistoryRequest {
+    pub unique_key: String,
+}
+
+impl ApiRequest for GetHistoryRequest {
+    type Response = Vec<CheckEvent>;
+    const PATH: &'static str = "/api/projects/history";
+    const METHOD: HttpMethod = HttpMethod::Get;
+}
+
+/// List enabled projects together with their recorded version state
+///
+/// Sent as `GET /api/projects/detailed`; sorted by unique key. Unlike
+/// [`ListProjectsRequest`] this queries every project's monitor, so it is slower.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListDetailedProjectsRequest;
+
+impl ApiRequest for ListDetailedProjectsRequest {
+    type Response = Vec<ProjectWithState>;
+    const PATH: &'static str = "/api/projects/detailed";
+    const METHOD: HttpMethod = HttpMethod::Get;
+}
+
+/// Get a single project by its unique key
+///
+/// Sent as `GET /api/projects/one?id=...`; responds 404 when the key is unknown.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetProjectRequest {
+    pub id: String,
+}
+
+impl ApiRequest for GetProjectRequest {
+    type Response = ProjectConfig;
+    const PATH: &'static str = "/api/projects/one";
+    const METHOD: HttpMethod = HttpMethod::Get;
+}
+
+/// Get the audit trail of admin mutations (oldest first)
+///
+/// Sent as `GET /api/audit`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetAuditLogRequest;
+
+impl ApiRequest for GetAuditLogRequest {
+    type Response = Vec<AuditEvent>;
+    const PATH: &'static str = "/api/audit";
+    const METHOD: HttpMethod = HttpMethod::Get;
+}
+
+/// Get the registrations that failed during monitor setup (oldest first)
+///
+/// Sent as `GET /api/deadletter`. Only the most recent entries are kept.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetDeadLetterRequest;
+
+impl ApiRequest for GetDeadLetterRequest {
+    type Response = Vec<DeadLetter>;
+    const PATH: &'static str = "/api/deadletter";
+    const METHOD: HttpMethod = HttpMethod::Get;
+}
+
+/// Aggregate counters over all enabled projects
+///
+/// Sent as `GET /api/projects/stats`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectStatsRequest;
+
+/// Aggregate counters returned by [`ProjectStatsRequest`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectStats {
+    pub total: usize,
+    pub running: usize,
+    pub paused: usize,
+    /// Running projects whose next check is within 5 minutes (overdue ones included)
+    pub due_within_5m: usize,
+    /// Projects whose most recent check failed
+    pub last_error_count: usize,
+}
+
+impl ApiRequest for ProjectStatsRequest {
+    type Response = ProjectStats;
+    const PATH: &'static str = "/api/projects/stats";
+    const METHOD: HttpMethod = HttpMethod::Get;
+}
+
+/// Latest known version of every enabled project, for external consumers
+///
+/// Sent as `GET /api/feed`. Accepts the feed token (`FEED_SECRET`) as well as admin keys.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetFeedRequest;
+
+/// One entry of the version feed returned by [`GetFeedRequest`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedEntry {
+    /// Upstream repository as `owner/repo`
+    pub upstream: String,
+    /// Tag of the last recorded version, `None` until a version has been recorded
+    pub current_version: Option<String>,
+    /// Time of the most recent check, `None` if the project was never checked
+    pub checked_at: Option<Timestamp>,
+}
+
+impl From<ProjectWithState> for FeedEntry {
+    fn from(item: ProjectWithState) -> Self {
+        let base = &item.config.request.base_config;
+        Self {
+            upstream: format!("{}/{}", base.upstream_owner, base.upstream_repo),
+            current_version: item.current_version,
+            checked_at: item.checked_at,
+        }
+    }
+}
+
+impl ApiRequest for GetFeedRequest {
+    type Response = Vec<FeedEntry>;
+    const PATH: &'static str = "/api/feed";
+    const METHOD: HttpMethod = HttpMethod::Get;
+}
+
+/// Access level granted by the presented key
+///
+/// Defaults to the least-privileged role when unknown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// Admin key: full access, including mutations
+    Admin,
+    /// Viewer key (`VIEWER_SECRET`): read-only access to the project list and feed
+    #[default]
+    Viewer,
+}
+
+/// Report the role of the presented key
+///
+/// Sent as `GET /api/whoami`; accepts admin and viewer keys.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WhoAmIRequest;
+
+/// Response of [`WhoAmIRequest`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionInfo {
+    pub role: Role,
+}
+
+impl ApiRequest for WhoAmIRequest {
+    type Response = SessionInfo;
+    const PATH: &'static str = "/api/whoami";
+    const METHOD: HttpMethod = HttpMethod::Get;
+}
+
+/// Health / readiness check (unauthenticated)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HealthCheckRequest;
+
+/// Health check result: which subsystems are reachable
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthStatus {
+    pub ok: bool,
+    /// Whether the registry Durable Object binding responded
+    pub registry: bool,
+    pub checked_at: Timestamp,
+}
+
+impl ApiRequest for HealthCheckRequest {
+    type Response = HealthStatus;
+    const PATH: &'static str = "/api/health";
+    const METHOD: HttpMethod = HttpMethod::Get;
+}
+
+/// Build information of the deployed Worker (unauthenticated)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VersionRequest;
+
+/// Response of [`VersionRequest`], fixed at compile time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildInfo {
+    /// Cargo package name
+    pub name: String,
+    /// Cargo package version
+    pub version: String,
+    /// Git commit injected at build time (`VERWATCH_GIT_SHA`)
+    pub git_sha: Option<String>,
+    /// Build time injected at build time (`VERWATCH_BUILT_AT`)
+    pub built_at: Option<String>,
+}
+
+impl ApiRequest for VersionRequest {
+    type Response = BuildInfo;
+    const PATH: &'static str = "/api/version";
+    const METHOD: HttpMethod = HttpMethod::Get;
+}
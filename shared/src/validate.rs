@@ -0,0 +1,220 @@
+//! 创建请求的配置校验
+//!
+//! 前端在提交前调用以展示行内错误，后端在创建/更新时调用以拒绝非法配置，
+//! 两端共用同一套规则。
+
+use crate::{CreateProjectRequest, Provider};
+
+/// 单个字段的校验错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// 出错的字段名，与 `CreateProjectRequest` 中的字段一致
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl ValidationError {
+    fn new(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// 校验创建请求，返回全部校验错误 (而不是遇到第一个就停止)
+pub fn validate_create_request(req: &CreateProjectRequest) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+    let base = &req.base_config;
+
+    for (field, value) in [
+        ("upstream_owner", &base.upstream_owner),
+        ("upstream_repo", &base.upstream_repo),
+        ("my_owner", &base.my_owner),
+        ("my_repo", &base.my_repo),
+    ] {
+        if value.trim().is_empty() {
+            errors.push(ValidationError::new(field, "cannot be empty"));
+        }
+    }
+
+    // 上游来自其他实例时，同名仓库并不是同一个仓库
+    if req.provider == Provider::GitHub
+        && !base.upstream_repo.trim().is_empty()
+        && base
+            .upstream_owner
+            .trim()
+            .eq_ignore_ascii_case(base.my_owner.trim())
+        && base
+            .upstream_repo
+            .trim()
+            .eq_ignore_ascii_case(base.my_repo.trim())
+    {
+        errors.push(ValidationError::new(
+            "my_repo",
+            "target repository must differ from the upstream repository",
+        ));
+    }
+
+    if let Some(pattern) = &req.tag_pattern
+        && let Err(e) = regex_lite::Regex::new(pattern)
+    {
+        errors.push(ValidationError::new(
+            "tag_pattern",
+            format!("invalid regex '{}': {}", pattern, e),
+        ));
+    }
+
+    if let Some(constraint) = &req.version_constraint
+        && let Err(e) = semver::VersionReq::parse(constraint)
+    {
+        errors.push(ValidationError::new(
+            "version_constraint",
+            format!("invalid semver requirement '{}': {}", constraint, e),
+        ));
+    }
+
+    if let Provider::Gitea { base_url } = &req.provider
+        && !(base_url.starts_with("https://") || base_url.starts_with("http://"))
+    {
+        errors.push(ValidationError::new(
+            "provider",
+            format!(
+                "invalid Gitea base_url '{}': must start with http:// or https://",
+                base_url
+            ),
+        ));
+    }
+
+    if let Some((start, end)) = req.quiet_hours
+        && (start > 23 || end > 23)
+    {
+        errors.push(ValidationError::new(
+            "quiet_hours",
+            "hours must be between 0 and 23",
+        ));
+    }
+
+    let time = &req.time_config;
+    if time.check_interval.as_secs() == 0 {
+        errors.push(ValidationError::new(
+            "check_interval",
+            "must be greater than 0",
+        ));
+    }
+    if time.retry_interval.as_secs() == 0 {
+        errors.push(ValidationError::new(
+            "retry_interval",
+            "must be greater than 0",
+        ));
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BaseConfig, DurationSecs};
+
+    fn valid_request() -> CreateProjectRequest {
+        CreateProjectRequest {
+            base_config: BaseConfig {
+                upstream_owner: "rust-lang".into(),
+                upstream_repo: "rust".into(),
+                my_owner: "me".into(),
+                my_repo: "mirror".into(),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn failed_fields(req: &CreateProjectRequest) -> Vec<&'static str> {
+        validate_create_request(req)
+            .unwrap_err()
+            .into_iter()
+            .map(|e| e.field)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_request_passes() {
+        assert_eq!(validate_create_request(&valid_request()), Ok(()));
+    }
+
+    #[test]
+    fn test_empty_owner_and_repo() {
+        let mut req = valid_request();
+        req.base_config.upstream_owner = " ".into();
+        req.base_config.my_repo = String::new();
+        assert_eq!(failed_fields(&req), ["upstream_owner", "my_repo"]);
+    }
+
+    #[test]
+    fn test_identical_upstream_and_target() {
+        let mut req = valid_request();
+        req.base_config.my_owner = "Rust-Lang".into();
+        req.base_config.my_repo = "rust".into();
+        assert_eq!(failed_fields(&req), ["my_repo"]);
+
+        // 其他实例上的同名仓库不是同一个仓库
+        req.provider = Provider::Gitea {
+            base_url: "https://codeberg.org".into(),
+        };
+        assert!(validate_create_request(&req).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_tag_pattern() {
+        let mut req = valid_request();
+        req.tag_pattern = Some("^v(\\d+".into());
+        assert_eq!(failed_fields(&req), ["tag_pattern"]);
+    }
+
+    #[test]
+    fn test_version_constraint() {
+        let mut req = valid_request();
+        req.version_constraint = Some(">=1.0, <2.0".into());
+        assert!(validate_create_request(&req).is_ok());
+
+        req.version_constraint = Some(">=one".into());
+        assert_eq!(failed_fields(&req), ["version_constraint"]);
+    }
+
+    #[test]
+    fn test_invalid_gitea_base_url() {
+        let mut req = valid_request();
+        req.provider = Provider::Gitea {
+            base_url: "codeberg.org".into(),
+        };
+        assert_eq!(failed_fields(&req), ["provider"]);
+    }
+
+    #[test]
+    fn test_quiet_hours_range() {
+        let mut req = valid_request();
+        req.quiet_hours = Some((22, 6));
+        assert!(validate_create_request(&req).is_ok());
+
+        req.quiet_hours = Some((22, 24));
+        assert_eq!(failed_fields(&req), ["quiet_hours"]);
+    }
+
+    #[test]
+    fn test_zero_intervals() {
+        let mut req = valid_request();
+        req.time_config.check_interval = DurationSecs::from_secs(0);
+        req.time_config.retry_interval = DurationSecs::from_secs(0);
+        assert_eq!(failed_fields(&req), ["check_interval", "retry_interval"]);
+    }
+}